@@ -0,0 +1,38 @@
+//! Dithering throughput on a full-width 80mm logo (384×500 RGBA).
+//!
+//! Run with `cargo bench --bench dither`. Uses a plain timing loop so it
+//! works without extra dev-dependencies.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use thermoprint::dither::{dither_rgba, DitherMethod};
+
+const WIDTH: u32 = 384;
+const HEIGHT: u32 = 500;
+const ITERATIONS: u32 = 50;
+
+fn main() {
+    // Diagonal gradient with some texture so error diffusion has work to do
+    let rgba: Vec<u8> = (0..HEIGHT)
+        .flat_map(|y| (0..WIDTH).map(move |x| ((x + y) % 256) as u8 ^ ((x * y) % 7) as u8))
+        .flat_map(|v| [v, v, v, 255])
+        .collect();
+
+    for method in [
+        DitherMethod::Threshold,
+        DitherMethod::FloydSteinberg,
+        DitherMethod::SierraLite,
+        DitherMethod::Jjn,
+    ] {
+        // Warm-up
+        black_box(dither_rgba(&rgba, WIDTH, HEIGHT, 384, method));
+
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            black_box(dither_rgba(black_box(&rgba), WIDTH, HEIGHT, 384, method));
+        }
+        let per_iter = start.elapsed() / ITERATIONS;
+        println!("{:?} {}x{}: {:?}/iter", method, WIDTH, HEIGHT, per_iter);
+    }
+}
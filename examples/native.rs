@@ -12,6 +12,9 @@ use rust_decimal::prelude::*;
 ///
 /// Send to a real printer (Linux/macOS):
 ///   cat receipt.bin > /dev/usb/lp0
+///
+/// Or print directly by passing the device path as a second argument:
+///   cargo run --example native --features native -- fr /dev/usb/lp0
 use std::fs;
 use thermoprint::{Language, PrintWidth, ReceiptBuilder, TaxEntry};
 
@@ -54,6 +57,12 @@ fn main() {
         .cut()
         .build();
 
+    if let Some(device) = std::env::args().nth(2) {
+        thermoprint::io::write_to_device(&device, &bytes).expect("failed to write to printer");
+        println!("✅  Sent {} bytes to {device}", bytes.len());
+        return;
+    }
+
     let out = "receipt.bin";
     fs::write(out, &bytes).expect("failed to write receipt.bin");
     println!("✅  Written {out} ({} bytes)", bytes.len());
@@ -656,7 +656,7 @@ function generateTemplate(cfg) {
 
   elements.push({ type: 'divider', char: '=' });
 
-  if (cfg.optBarcode && cfg.orderRef) elements.push({ type: 'barcode_code128', value: cfg.orderRef });
+  if (cfg.optBarcode && cfg.orderRef) elements.push({ type: 'barcode', symbology: 'code128', value: cfg.orderRef });
   if (cfg.optQr && cfg.qrData) elements.push({ type: 'qr_code', data: cfg.qrData, size: 6 });
   if (cfg.servedBy) elements.push({ type: 'served_by', name: cfg.servedBy });
   if (cfg.optThanks && cfg.shopName) elements.push({ type: 'thank_you', shop_name: cfg.shopName });
@@ -832,7 +832,7 @@ function setupLogo() {
         try {
           logoRasterBytes = dither_image(
             new Uint8Array(imageData.data.buffer),
-            w, h, 384, 'floyd_steinberg'
+            w, h, 384
           );
           logoDataUrl = reader.result;
 
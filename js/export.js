@@ -412,6 +412,7 @@ export class ReceiptExporter {
           push('Thank you for your purchase!', { align: 'center' });
           push(`See you soon at ${el.shop_name}`, { align: 'center' });
           break;
+        case 'barcode':
         case 'barcode_code128':
         case 'barcode_ean13':
           lines.push({ type: 'barcode', value: el.value, bold: false, big: false });
@@ -419,6 +420,10 @@ export class ReceiptExporter {
         case 'qr_code':
           lines.push({ type: 'qr', data: el.data, bold: false, big: false });
           break;
+        case 'qr_captioned':
+          lines.push({ type: 'qr', data: el.data, bold: false, big: false });
+          push(el.caption, { align: 'center' });
+          break;
         case 'feed':
           for (let i = 0; i < (el.lines || 3); i++) push('');
           break;
@@ -1,20 +1,24 @@
-use rust_decimal::prelude::Zero;
+use std::borrow::Borrow;
+use std::collections::BTreeMap;
+
+use rust_decimal::prelude::{ToPrimitive, Zero};
 use rust_decimal::Decimal;
 
-use crate::commands::{self, LF};
-use crate::encoding::{center, encode_cp858, right_align, truncate, two_col};
+use crate::commands::{self, FF};
+use crate::debug::Token;
+use crate::dialect::{Dialect, Symbology};
+use crate::encoding::{
+    center_dir, encode_cp858, justify, right_align_dir, truncate, truncate_cols, two_col_dir,
+    two_col_lines, wrap,
+};
 use crate::error::ThermoprintError;
 use crate::i18n::{Language, ReceiptLabels, LABELS_FR};
-use crate::types::{Align, PrintWidth, TaxEntry};
-
-// ── Money formatting ──────────────────────────────────────────────────────────
-
-/// Format a `Decimal` as a whole-unit currency string.
-/// The currency symbol is intentionally left generic — callers can override
-/// by building the string themselves and calling `.text_line()`.
-fn fmt_amount(amount: Decimal, currency: &str) -> String {
-    format!("{} {}", amount.round(), currency)
-}
+use crate::template::{ReceiptTemplate, TemplateError};
+use crate::types::{
+    Align, CurrencyPlacement, Direction, Feature, HriFont, HriPosition, ItemInput, ItemStyle,
+    LineEnding, MoneyFormat, PrintWidth, QrErrorCorrection, TaxEntry, UnderlineStyle,
+};
+use crate::words;
 
 // ── Core builder ──────────────────────────────────────────────────────────────
 
@@ -39,7 +43,46 @@ pub struct ReceiptBuilder {
     data: Vec<u8>,
     width: PrintWidth,
     currency: String,
+    currency_placement: CurrencyPlacement,
+    /// Set by [`item_in`](Self::item_in) and friends while `currency` is
+    /// not the receipt's own.
+    foreign_currency: bool,
+    money: MoneyFormat,
+    /// Character width multiplier of the active text size (1 or 2).
+    width_scale: u8,
+    direction: Direction,
+    /// Taxable base accumulated per VAT rate by [`item_vat`](Self::item_vat).
+    vat_bases: BTreeMap<Decimal, Decimal>,
     labels: ReceiptLabels,
+    /// Language of [`total_in_words`](Self::total_in_words).
+    language: Language,
+    hri_position: HriPosition,
+    hri_font: HriFont,
+    /// Feed a line after each barcode / QR code (default `true`).
+    code_lf: bool,
+    dialect: Dialect,
+    /// Identical copies emitted by [`build`](Self::build) (default 1).
+    copies: u8,
+    /// Features whose commands were skipped for this paper width.
+    skipped: Vec<Feature>,
+    /// Length of `data` after the last cut, moved forward by commands that
+    /// print nothing (e.g. a drawer kick). Equal to `data.len()` when
+    /// nothing has printed since the cut.
+    cut_end: Option<usize>,
+    item_style: ItemStyle,
+    /// Lines fed before [`cut`](Self::cut) / [`cut_full`](Self::cut_full).
+    cut_feed: u8,
+    line_ending: LineEnding,
+    /// Open [`indent`](Self::indent) levels, in spaces, innermost last.
+    indents: Vec<usize>,
+    /// Layout columns set by [`content_width`](Self::content_width).
+    content_cols: Option<usize>,
+    /// [`raster_strategy`](Self::raster_strategy) override; `None` follows
+    /// [`PrintWidth::default_raster_mode`].
+    #[cfg(feature = "native")]
+    raster_mode: Option<crate::types::RasterMode>,
+    #[cfg(feature = "shift-jis")]
+    multibyte: Option<crate::encoding::MultibyteEncoding>,
 }
 
 impl ReceiptBuilder {
@@ -50,10 +93,153 @@ impl ReceiptBuilder {
             data: Vec::new(),
             width,
             currency: "FCFA".to_owned(),
+            currency_placement: CurrencyPlacement::Always,
+            foreign_currency: false,
+            money: MoneyFormat::default(),
+            width_scale: 1,
+            direction: Direction::Ltr,
+            vat_bases: BTreeMap::new(),
             labels: LABELS_FR,
+            language: Language::Fr,
+            hri_position: HriPosition::Below,
+            hri_font: HriFont::A,
+            code_lf: true,
+            dialect: Dialect::Epson,
+            copies: 1,
+            skipped: Vec::new(),
+            cut_end: None,
+            item_style: ItemStyle::Detailed,
+            cut_feed: 0,
+            line_ending: LineEnding::Lf,
+            indents: Vec::new(),
+            content_cols: None,
+            #[cfg(feature = "native")]
+            raster_mode: None,
+            #[cfg(feature = "shift-jis")]
+            multibyte: None,
         }
     }
 
+    /// Start a builder from a parsed [`ReceiptTemplate`]: its width,
+    /// currency, language, dialect and direction, then its elements. Keep
+    /// chaining to add what the template can't express before
+    /// [`build`](Self::build).
+    ///
+    /// ```rust
+    /// use thermoprint::{ReceiptBuilder, ReceiptTemplate};
+    /// let template = ReceiptTemplate::from_json(
+    ///     r#"{ "width": "58mm", "elements": [{ "type": "text_line", "text": "MA BOUTIQUE" }] }"#,
+    /// )?;
+    /// let bytes = ReceiptBuilder::from_template(&template)?
+    ///     .datetime_str("12/06/2024 14:30")
+    ///     .cut()
+    ///     .build();
+    /// # Ok::<(), thermoprint::template::TemplateError>(())
+    /// ```
+    pub fn from_template(template: &ReceiptTemplate) -> Result<Self, TemplateError> {
+        let builder = template.configured_builder()?;
+        template.apply_elements(builder)
+    }
+
+    /// Apply a template's elements onto this builder without building, so
+    /// a JSON layout can be mixed with runtime-only additions.
+    ///
+    /// Only the elements are used: the template's width, currency, language,
+    /// dialect and direction are ignored in favour of this builder's own.
+    /// Failures are reported as [`TemplateError::ElementError`].
+    ///
+    /// ```rust
+    /// use thermoprint::{PrintWidth, ReceiptBuilder, ReceiptTemplate};
+    /// let footer = ReceiptTemplate::from_json(
+    ///     r#"{ "elements": [{ "type": "thank_you", "shop_name": "MA BOUTIQUE" }] }"#,
+    /// )?;
+    /// let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+    ///     .text_line("Commande #42")
+    ///     .apply_template(&footer)?
+    ///     .feed(2)
+    ///     .cut()
+    ///     .build();
+    /// # Ok::<(), thermoprint::template::TemplateError>(())
+    /// ```
+    pub fn apply_template(self, template: &ReceiptTemplate) -> Result<Self, TemplateError> {
+        template.apply_elements(self)
+    }
+
+    /// A ready-to-print self-test page for installation and troubleshooting.
+    ///
+    /// Shows the paper width and column count, a column ruler, each
+    /// alignment, the text styles, accented characters, a CODE128 barcode
+    /// and a QR code, then feeds and cuts.
+    ///
+    /// ```rust
+    /// use thermoprint::{ReceiptBuilder, PrintWidth};
+    /// let bytes = ReceiptBuilder::test_page(PrintWidth::Mm58).build();
+    /// assert!(!bytes.is_empty());
+    /// ```
+    pub fn test_page(width: PrintWidth) -> Self {
+        let paper = match width {
+            PrintWidth::Mm58 => "58mm",
+            PrintWidth::Mm80 => "80mm",
+            PrintWidth::A4 => "A4",
+        };
+        let cols = width.cols();
+        // `....+....1....+....2` — tens digit every 10 columns
+        let ruler: String = (1..=cols)
+            .map(|i| {
+                if i % 10 == 0 {
+                    char::from(b'0' + (i / 10 % 10) as u8)
+                } else if i % 5 == 0 {
+                    '+'
+                } else {
+                    '.'
+                }
+            })
+            .collect();
+
+        Self::new(width)
+            .init()
+            .align_center()
+            .bold(true)
+            .double_size(true)
+            .text_line("TEST PAGE")
+            .double_size(false)
+            .bold(false)
+            .text_line(&format!("thermoprint {}", env!("CARGO_PKG_VERSION")))
+            .text_line(&format!("Paper: {} / {} columns", paper, cols))
+            .align_left()
+            .divider('=')
+            .text_line(&ruler)
+            .divider('=')
+            .align_left()
+            .text_line("Left aligned")
+            .align_center()
+            .text_line("Centre aligned")
+            .align_right()
+            .text_line("Right aligned")
+            .align_left()
+            .divider('-')
+            .bold(true)
+            .text_line("Bold text")
+            .bold(false)
+            .underline(true)
+            .text_line("Underlined text")
+            .underline(false)
+            .double_height(true)
+            .text_line("Double height")
+            .double_height(false)
+            .double_size(true)
+            .text_line("Double size")
+            .normal_size()
+            .text_line("Accents: àâçéèêëîïôùûü ñ €")
+            .divider('-')
+            .align_center()
+            .barcode_code128("THERMOPRINT")
+            .qr_code("https://github.com/mouhamed1296/thermoprint", 4)
+            .align_left()
+            .feed(3)
+            .cut()
+    }
+
     /// Set the currency symbol used in all money formatting.
     ///
     /// ```rust
@@ -65,6 +251,42 @@ impl ReceiptBuilder {
         self
     }
 
+    /// Choose which lines print the currency symbol. With
+    /// [`CurrencyPlacement::TotalsOnly`], item lines show bare numbers so
+    /// dense receipts keep room for product names, while subtotal, discount,
+    /// tax, total and payment lines still carry the symbol.
+    ///
+    /// ```rust
+    /// use rust_decimal::Decimal;
+    /// use thermoprint::{CurrencyPlacement, PrintWidth, ReceiptBuilder};
+    /// let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+    ///     .currency_on(CurrencyPlacement::TotalsOnly)
+    ///     .item("Pain", 2, Decimal::from(250), None)
+    ///     .total(Decimal::from(500))
+    ///     .build();
+    /// let text = String::from_utf8_lossy(&bytes);
+    /// assert!(text.contains("2 x 250\n"));
+    /// assert!(text.contains("500 FCFA"));
+    /// ```
+    pub fn currency_on(mut self, placement: CurrencyPlacement) -> Self {
+        self.currency_placement = placement;
+        self
+    }
+
+    /// Set how amounts are rendered (decimal places, separators, symbol
+    /// position). Defaults to whole units with the symbol after the amount.
+    ///
+    /// ```rust
+    /// use thermoprint::{MoneyFormat, PrintWidth, ReceiptBuilder};
+    /// let b = ReceiptBuilder::new(PrintWidth::Mm80)
+    ///     .currency("€")
+    ///     .money_format(MoneyFormat::new(2, Some(' '), ',', false));
+    /// ```
+    pub fn money_format(mut self, format: MoneyFormat) -> Self {
+        self.money = format;
+        self
+    }
+
     /// Set the receipt language for all high-level labels.
     ///
     /// Defaults to [`Language::Fr`] (French). Available languages:
@@ -76,6 +298,79 @@ impl ReceiptBuilder {
     /// ```
     pub fn language(mut self, lang: Language) -> Self {
         self.labels = lang.labels();
+        self.language = lang;
+        self
+    }
+
+    /// Set the reading direction of the layout.
+    ///
+    /// [`Direction::Rtl`] mirrors two-column rows (value left, label right),
+    /// aligns [`right`](Self::right) lines to the left edge, and makes the
+    /// right edge the default alignment that [`init`](Self::init) and the
+    /// receipt sections return to. When the direction changes, emits the
+    /// matching alignment command; setting the current direction again
+    /// sends nothing and keeps the alignment already chosen.
+    ///
+    /// ```rust
+    /// use thermoprint::{Direction, Language, PrintWidth, ReceiptBuilder};
+    /// let b = ReceiptBuilder::new(PrintWidth::Mm80)
+    ///     .language(Language::Ar)
+    ///     .direction(Direction::Rtl);
+    /// ```
+    pub fn direction(mut self, dir: Direction) -> Self {
+        if self.direction == dir {
+            return self;
+        }
+        self.direction = dir;
+        self.align_start()
+    }
+
+    /// Choose the layout of the item lines that follow. With
+    /// [`ItemStyle::Compact`], [`item`](Self::item), [`items`](Self::items),
+    /// [`item_vat`](Self::item_vat) and [`item_weighted`](Self::item_weighted)
+    /// print one line each, like [`item_compact`](Self::item_compact).
+    pub fn item_style(mut self, style: ItemStyle) -> Self {
+        self.item_style = style;
+        self
+    }
+
+    /// Choose the bytes that end each line. Defaults to [`LineEnding::Lf`];
+    /// use [`LineEnding::CrLf`] or [`LineEnding::Cr`] for impact and page
+    /// printers (e.g. on [`PrintWidth::A4`]) that need a carriage return.
+    ///
+    /// Applies to lines pushed after the call, so set it before
+    /// [`init`](Self::init).
+    ///
+    /// ```rust
+    /// use thermoprint::{LineEnding, PrintWidth, ReceiptBuilder};
+    /// let bytes = ReceiptBuilder::new(PrintWidth::A4)
+    ///     .line_ending(LineEnding::CrLf)
+    ///     .text_line("Facture")
+    ///     .build();
+    /// assert_eq!(bytes, b"Facture\r\n");
+    /// ```
+    pub fn line_ending(mut self, ending: LineEnding) -> Self {
+        self.line_ending = ending;
+        self
+    }
+
+    /// Select the printer command set. Defaults to [`Dialect::Epson`];
+    /// [`Dialect::Star`] sends Star Line Mode sequences for code page
+    /// selection, bold, alignment, cuts, barcodes and QR codes. Call before
+    /// [`init`](Self::init).
+    ///
+    /// ```rust
+    /// use thermoprint::{Dialect, PrintWidth, ReceiptBuilder};
+    /// let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+    ///     .dialect(Dialect::Star)
+    ///     .init()
+    ///     .text_line("Hello")
+    ///     .cut()
+    ///     .build();
+    /// assert!(bytes.ends_with(&[0x1B, b'd', 3]));
+    /// ```
+    pub fn dialect(mut self, dialect: Dialect) -> Self {
+        self.dialect = dialect;
         self
     }
 
@@ -85,15 +380,149 @@ impl ReceiptBuilder {
         self
     }
 
+    /// Print `n` identical copies, e.g. 2 for a merchant and a customer
+    /// copy. Applied by [`build`](Self::build): a partial cut is inserted
+    /// between copies unless nothing has printed since the receipt's last
+    /// cut (a drawer kick or style change after it is fine). `0` and `1`
+    /// print a single copy.
+    ///
+    /// ```rust
+    /// use thermoprint::{ReceiptBuilder, PrintWidth};
+    /// let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+    ///     .text_line("TOTAL 5000")
+    ///     .cut()
+    ///     .repeat(2)
+    ///     .build();
+    /// ```
+    pub fn repeat(mut self, n: u8) -> Self {
+        self.copies = n.max(1);
+        self
+    }
+
+    /// The bytes built so far, without consuming the builder. Always a
+    /// single copy, whatever [`repeat`](Self::repeat) is set to.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Columns available at the current text size: the paper's column count
+    /// divided by the character width, so half of it after
+    /// [`double_size(true)`](Self::double_size), minus the current
+    /// [`indent`](Self::indent).
+    ///
+    /// [`centered`](Self::centered), [`row`](Self::row),
+    /// [`divider`](Self::divider) and the other layout methods all lay out
+    /// text to this width.
+    ///
+    /// ```rust
+    /// use thermoprint::{ReceiptBuilder, PrintWidth};
+    /// let b = ReceiptBuilder::new(PrintWidth::Mm80);
+    /// assert_eq!(b.effective_cols(), 48);
+    /// assert_eq!(b.double_size(true).effective_cols(), 24);
+    /// ```
+    pub fn effective_cols(&self) -> usize {
+        (self.layout_cols() / usize::from(self.width_scale)).saturating_sub(self.indent_width())
+    }
+
     /// Consume the builder and return the raw ESC/POS byte stream.
+    ///
+    /// A stream without a cut is valid: the paper stays presented at the
+    /// print head, e.g. for a report that continues in the next job.
     pub fn build(self) -> Vec<u8> {
-        self.data
+        if self.copies == 1 {
+            return self.data;
+        }
+        let sep = if self.ends_in_cut() {
+            Vec::new()
+        } else if self.width.supports(Feature::Cut) {
+            self.cut_bytes(false)
+        } else if self.data.ends_with(&[FF]) {
+            Vec::new()
+        } else {
+            // Page printers: start each copy on a fresh page
+            commands::form_feed().to_vec()
+        };
+        repeat_copies(&self.data, self.copies, &sep)
+    }
+
+    /// Append a cut, or on A4 a page eject unless the page was just ejected.
+    fn push_cut(&mut self, cut: &[u8]) {
+        if self.width.supports(Feature::Cut) {
+            self.push(cut);
+        } else if !self.data.ends_with(&[FF]) {
+            self.push(commands::form_feed());
+        }
+        self.cut_end = Some(self.data.len());
+    }
+
+    /// `true` if nothing has printed since the last cut.
+    fn ends_in_cut(&self) -> bool {
+        self.cut_end == Some(self.data.len())
+    }
+
+    /// Append a command that prints nothing, such as a style change or a
+    /// drawer kick, keeping a preceding cut the last thing on the paper.
+    fn push_control(&mut self, bytes: &[u8]) {
+        let after_cut = self.ends_in_cut();
+        self.push(bytes);
+        if after_cut {
+            self.cut_end = Some(self.data.len());
+        }
+    }
+
+    /// Append `bytes` only if the paper width supports `feature`; see
+    /// [`PrintWidth::supports`]. Otherwise record the skip.
+    fn push_if(&mut self, feature: Feature, bytes: &[u8]) {
+        if self.width.supports(feature) {
+            self.push(bytes);
+        } else {
+            self.skip(feature);
+        }
+    }
+
+    /// Record that a `feature` command was left out.
+    fn skip(&mut self, feature: Feature) {
+        if !self.skipped.contains(&feature) {
+            self.skipped.push(feature);
+        }
+    }
+
+    /// Features whose commands were left out because the paper width
+    /// doesn't support them (see [`PrintWidth::supports`]), in the order
+    /// first met. Empty when everything requested was sent.
+    ///
+    /// ```rust
+    /// use thermoprint::{Feature, PrintWidth, ReceiptBuilder};
+    /// let b = ReceiptBuilder::new(PrintWidth::A4)
+    ///     .text_line("Facture")
+    ///     .open_cash_drawer();
+    /// assert_eq!(b.skipped(), &[Feature::CashDrawer]);
+    /// ```
+    pub fn skipped(&self) -> &[Feature] {
+        &self.skipped
     }
 
     // ── Helpers ───────────────────────────────────────────────────────────────
 
+    /// Normal-size columns left of the line after the current indent.
     fn cols(&self) -> usize {
-        self.width.cols()
+        self.layout_cols().saturating_sub(self.indent_width())
+    }
+
+    /// Normal-size columns of the layout: the paper's, unless narrowed by
+    /// [`content_width`](Self::content_width).
+    fn layout_cols(&self) -> usize {
+        let paper = self.width.cols();
+        self.content_cols.map_or(paper, |cols| cols.min(paper))
+    }
+
+    fn indent_width(&self) -> usize {
+        self.indents.iter().sum()
+    }
+
+    fn push_indent(&mut self) {
+        let n = self.indent_width();
+        self.data.extend(std::iter::repeat_n(b' ', n));
     }
 
     fn push(&mut self, bytes: &[u8]) {
@@ -101,37 +530,160 @@ impl ReceiptBuilder {
     }
 
     fn push_lf(&mut self) {
-        self.data.push(LF);
+        self.data.extend_from_slice(self.line_ending.bytes());
     }
 
     fn push_text(&mut self, text: &str) {
+        #[cfg(feature = "shift-jis")]
+        if let Some(encoding) = self.multibyte {
+            let bytes = crate::encoding::encode_multibyte(text, encoding);
+            self.data.extend_from_slice(&bytes);
+            return;
+        }
         self.data.extend_from_slice(&encode_cp858(text));
     }
 
     fn push_text_line(&mut self, text: &str) {
+        self.push_indent();
         self.push_text(text);
         self.push_lf();
     }
 
+    /// Push a [`two_col_lines`] row: label at the start of the line, value
+    /// at the end, on a second line if it leaves no room for the label.
+    fn push_row(&mut self, left: &str, right: &str, cols: usize) {
+        for line in two_col_lines(left, right, cols, self.direction) {
+            self.push_text_line(&line);
+        }
+    }
+
     fn fmt(&self, amount: Decimal) -> String {
-        fmt_amount(amount, &self.currency)
+        self.money.format(amount, &self.currency)
+    }
+
+    /// Format an amount without any currency symbol.
+    fn fmt_bare(&self, amount: Decimal) -> String {
+        self.money.format(amount, "").trim().to_owned()
+    }
+
+    /// Format an amount on an item line, without the currency symbol under
+    /// [`CurrencyPlacement::TotalsOnly`]. Lines in another currency
+    /// ([`item_in`](Self::item_in)) keep theirs, or they would read as the
+    /// receipt's own currency.
+    fn fmt_item(&self, amount: Decimal) -> String {
+        match self.currency_placement {
+            CurrencyPlacement::TotalsOnly if !self.foreign_currency => self.fmt_bare(amount),
+            _ => self.fmt(amount),
+        }
+    }
+
+    /// Barcode command, or on A4 the human-readable value as a text line
+    /// (nothing if HRI is off, as with [`barcode_with_text`](Self::barcode_with_text)).
+    fn push_barcode(&mut self, symbology: Symbology, value: &str, width: u8, height: u8) {
+        if !self.width.supports(Feature::Barcode) {
+            if self.hri_position != HriPosition::None {
+                self.push_text_line(value);
+            }
+            return;
+        }
+        let cmd = self.dialect.barcode(
+            symbology,
+            value,
+            width,
+            height,
+            self.hri_position,
+            self.hri_font,
+        );
+        self.push(&cmd);
+    }
+
+    /// Line feed after a barcode or QR code, unless disabled with
+    /// [`code_line_feed`](Self::code_line_feed).
+    fn push_code_lf(&mut self) {
+        if self.code_lf && self.width.supports(Feature::Barcode) {
+            self.push_lf();
+        }
+    }
+
+    /// Line feed after a logo; skipped with the logo itself on A4.
+    fn push_logo_lf(&mut self) {
+        if self.width.supports(Feature::Logo) {
+            self.push_lf();
+        }
     }
 
     // ── Initialisation ────────────────────────────────────────────────────────
 
     /// Send `ESC @` (printer reset) plus code page 858 and sane defaults.
     /// Always call this first.
-    pub fn init(mut self) -> Self {
-        // Double reset to clear residual state on stubborn printers
-        self.push(commands::init());
-        self.push_lf();
-        self.push(commands::init());
-        self.push_lf();
-        self.push(commands::code_page_858());
-        self.push(commands::align_left());
+    ///
+    /// The reset is sent twice to clear residual state on stubborn printers;
+    /// use [`init_once`](Self::init_once) if that causes an extra feed.
+    pub fn init(self) -> Self {
+        self.init_with(2)
+    }
+
+    /// Like [`init`](Self::init), but with a single `ESC @` reset.
+    pub fn init_once(self) -> Self {
+        self.init_with(1)
+    }
+
+    /// Like [`init`](Self::init), sending `ESC @` (each followed by a line
+    /// feed) `resets` times before the code page and style defaults.
+    pub fn init_with(mut self, resets: u8) -> Self {
+        for _ in 0..resets {
+            self.push(commands::init());
+            self.push_lf();
+        }
+        self.push(self.dialect.code_page_858());
+        self.push(self.start_align());
         self.push(commands::normal_size());
-        self.push(commands::bold_off());
+        self.push(self.dialect.bold(false));
         self.push_lf();
+        self.width_scale = 1;
+        self
+    }
+
+    /// Send [`init`](Self::init)'s code page and style defaults, plus
+    /// underline off, without `ESC @` or a line feed: undoes whatever text
+    /// state the previous receipt in a batch left behind.
+    pub(crate) fn reset_text_state(mut self) -> Self {
+        self.push(self.dialect.code_page_858());
+        self.push(self.start_align());
+        self.push(commands::normal_size());
+        self.push(self.dialect.bold(false));
+        self.push(commands::underline_off());
+        self.width_scale = 1;
+        self
+    }
+
+    /// Select the international character set (`ESC R n`, 0 = USA,
+    /// 1 = France, 2 = Germany, ...; see [`commands::intl_charset`]).
+    ///
+    /// [`init`](Self::init) leaves the printer on the USA set whatever the
+    /// [`Language`]: accented letters already come from code page 858, and
+    /// the national sets replace `#` and `@`, which receipts use for order
+    /// numbers and e-mail addresses. Call this after `init` for printers whose
+    /// firmware expects a specific set.
+    pub fn intl_charset(mut self, n: u8) -> Self {
+        self.push_control(&commands::intl_charset(n));
+        self
+    }
+
+    /// Switch all following text to a multibyte code page (e.g. Shift-JIS
+    /// for Japanese printers) and send the printer the matching setup.
+    ///
+    /// Call this after [`init`](Self::init), which resets the code page.
+    /// Requires the `shift-jis` feature.
+    #[cfg(feature = "shift-jis")]
+    pub fn multibyte(mut self, encoding: crate::encoding::MultibyteEncoding) -> Self {
+        match encoding {
+            crate::encoding::MultibyteEncoding::ShiftJis => {
+                self.push(commands::code_page_katakana());
+                self.push(commands::kanji_code_system_shift_jis());
+            }
+        }
+        self.multibyte = Some(encoding);
         self
     }
 
@@ -139,14 +691,25 @@ impl ReceiptBuilder {
 
     /// Set text alignment (left, center, or right).
     pub fn align(mut self, a: Align) -> Self {
-        match a {
-            Align::Left => self.push(commands::align_left()),
-            Align::Center => self.push(commands::align_center()),
-            Align::Right => self.push(commands::align_right()),
-        }
+        self.push_control(self.dialect.align(a));
+        self
+    }
+
+    /// Align to the start of the line: left, or right in
+    /// [`Direction::Rtl`] mode.
+    fn align_start(mut self) -> Self {
+        self.push_control(self.start_align());
         self
     }
 
+    /// Alignment command for the start of the line.
+    fn start_align(&self) -> &'static [u8] {
+        self.dialect.align(match self.direction {
+            Direction::Ltr => Align::Left,
+            Direction::Rtl => Align::Right,
+        })
+    }
+
     /// Shorthand for left alignment.
     pub fn align_left(self) -> Self {
         self.align(Align::Left)
@@ -160,54 +723,151 @@ impl ReceiptBuilder {
         self.align(Align::Right)
     }
 
+    // ── Position ──────────────────────────────────────────────────────────────
+
+    /// Set the left margin in **dots** (e.g. 8 dots per character column in
+    /// Font A on most 203 dpi printers). Applies from the next line; pass
+    /// `0` to reset.
+    pub fn left_margin(mut self, dots: u16) -> Self {
+        self.push_control(&commands::left_margin(dots));
+        self
+    }
+
+    /// Lay out text to `cols` columns instead of the paper's full width,
+    /// e.g. 40 columns on 80mm paper for a narrower, branded look. Dividers,
+    /// [`centered`](Self::centered), [`row`](Self::row) and the other layout
+    /// methods use it through [`effective_cols`](Self::effective_cols); the
+    /// physical [`PrintWidth`] (images, features) is unchanged. Clamped to
+    /// the paper's columns. Lines start at the left edge — add
+    /// [`left_margin`](Self::left_margin) to centre the block.
+    ///
+    /// ```rust
+    /// use thermoprint::{ReceiptBuilder, PrintWidth};
+    /// let b = ReceiptBuilder::new(PrintWidth::Mm80).content_width(40);
+    /// assert_eq!(b.effective_cols(), 40);
+    /// ```
+    pub fn content_width(mut self, cols: usize) -> Self {
+        self.content_cols = Some(cols);
+        self
+    }
+
+    /// Move the print position to `dots` from the start of the current line
+    /// (**dots**, not characters). Follow with [`text`](Self::text) to print
+    /// at that spot.
+    pub fn at_position(mut self, dots: u16) -> Self {
+        self.push(&commands::absolute_position(dots));
+        self
+    }
+
+    /// Indent following lines by `n` more spaces until the matching
+    /// [`dedent`](Self::dedent), e.g. for sub-totals nested under a category.
+    ///
+    /// Every line the builder lays out starts with the indent, and
+    /// [`effective_cols`](Self::effective_cols) shrinks by it, so
+    /// [`row`](Self::row), [`centered`](Self::centered) and dividers still
+    /// end at the paper edge. Indents nest and add up. Text appended with
+    /// [`text`](Self::text) is not indented. Unlike
+    /// [`left_margin`](Self::left_margin), nothing is sent to the printer
+    /// but spaces.
+    ///
+    /// ```rust
+    /// use thermoprint::{ReceiptBuilder, PrintWidth};
+    /// let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+    ///     .indent(2)
+    ///     .row("Boissons", "1500")
+    ///     .dedent()
+    ///     .build();
+    /// assert_eq!(bytes, b"  Boissons                  1500\n");
+    /// ```
+    pub fn indent(mut self, n: usize) -> Self {
+        self.indents.push(n);
+        self
+    }
+
+    /// Close the innermost [`indent`](Self::indent). Does nothing when no
+    /// indent is open.
+    pub fn dedent(mut self) -> Self {
+        self.indents.pop();
+        self
+    }
+
     // ── Text style ────────────────────────────────────────────────────────────
 
     /// Toggle bold text.
     pub fn bold(mut self, on: bool) -> Self {
-        self.push(if on {
-            commands::bold_on()
-        } else {
-            commands::bold_off()
-        });
+        self.push_control(self.dialect.bold(on));
         self
     }
 
     /// Toggle double-width and double-height text.
     pub fn double_size(mut self, on: bool) -> Self {
-        self.push(if on {
+        self.push_control(if on {
             commands::double_size_on()
         } else {
             commands::normal_size()
         });
+        self.width_scale = if on { 2 } else { 1 };
         self
     }
 
     /// Toggle double-height text (normal width).
     pub fn double_height(mut self, on: bool) -> Self {
-        self.push(if on {
+        self.push_control(if on {
             commands::double_height_on()
         } else {
             commands::normal_size()
         });
+        self.width_scale = 1;
         self
     }
 
     /// Reset text size to normal (single width and height).
     pub fn normal_size(mut self) -> Self {
-        self.push(commands::normal_size());
+        self.push_control(commands::normal_size());
+        self.width_scale = 1;
         self
     }
 
-    /// Toggle underline.
-    pub fn underline(mut self, on: bool) -> Self {
-        self.push(if on {
-            commands::underline_on()
-        } else {
-            commands::underline_off()
+    /// Set the underline: an [`UnderlineStyle`], or `true` / `false` for a
+    /// single underline or none.
+    ///
+    /// ```rust
+    /// use thermoprint::{ReceiptBuilder, PrintWidth, UnderlineStyle};
+    /// let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+    ///     .underline(UnderlineStyle::Double)
+    ///     .text_line("MENU")
+    ///     .underline(false)
+    ///     .build();
+    /// assert_eq!(bytes, b"\x1B-\x02MENU\n\x1B-\x00");
+    /// ```
+    pub fn underline(mut self, style: impl Into<UnderlineStyle>) -> Self {
+        self.push_control(match style.into() {
+            UnderlineStyle::None => commands::underline_off(),
+            UnderlineStyle::Single => commands::underline_on(),
+            UnderlineStyle::Double => commands::underline_double(),
         });
         self
     }
 
+    /// Set print density / heat from `-6` (lightest) to `6` (darkest).
+    ///
+    /// Helps with faint prints on worn thermal heads. The command is
+    /// Epson-style and may be ignored by other vendors' printers.
+    pub fn density(mut self, level: i8) -> Self {
+        self.push_control(&commands::print_density(level));
+        self
+    }
+
+    /// Set the print speed from `1` (slowest, best quality) to `9`
+    /// (fastest); `0` restores the printer's configured speed.
+    ///
+    /// Faster speeds suit long reports but print lighter. The command is
+    /// Epson-style; see [`commands::print_speed`] for vendor differences.
+    pub fn print_speed(mut self, level: u8) -> Self {
+        self.push_control(&commands::print_speed(level));
+        self
+    }
+
     // ── Text output ───────────────────────────────────────────────────────────
 
     /// Append encoded text **without** a trailing line feed.
@@ -228,64 +888,307 @@ impl ReceiptBuilder {
         self
     }
 
-    /// Append a horizontal divider repeated to the full column width.
+    /// Append a horizontal divider repeated to the full column width (half
+    /// of it while [`double_size`](Self::double_size) is on).
     ///
     /// ```rust
     /// use thermoprint::{ReceiptBuilder, PrintWidth};
     /// let b = ReceiptBuilder::new(PrintWidth::Mm80).divider('=');
     /// ```
-    pub fn divider(mut self, ch: char) -> Self {
-        let line = ch.to_string().repeat(self.cols());
-        self.data.extend_from_slice(line.as_bytes());
-        self.push_lf();
+    pub fn divider(self, ch: char) -> Self {
+        let cols = self.effective_cols();
+        self.divider_width(ch, cols)
+    }
+
+    /// Append a horizontal rule of exactly `cols` repetitions of `ch`, e.g.
+    /// when a smaller font fits more characters than the paper width implies.
+    pub fn divider_width(mut self, ch: char, cols: usize) -> Self {
+        self.push_text_line(&ch.to_string().repeat(cols));
+        self
+    }
+
+    /// Append a rule split at column boundaries, e.g.
+    /// `----------+------------+-----` for `widths` of `[10, 12, 5]` with
+    /// `ch = '-'` and `junction = '+'`, so separators line up with the
+    /// columns of a table.
+    ///
+    /// The rule is always exactly [`effective_cols`](Self::effective_cols)
+    /// wide: if `widths` plus one junction between each pair fall short, the
+    /// last segment is extended; if they overflow, the rule is cut off at
+    /// the paper edge. Empty `widths` prints a plain divider.
+    ///
+    /// ```rust
+    /// use thermoprint::{ReceiptBuilder, PrintWidth};
+    /// let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+    ///     .column_divider(&[4, 20, 6], '-', '+')
+    ///     .build();
+    /// assert_eq!(bytes, b"----+--------------------+------\n");
+    /// ```
+    pub fn column_divider(mut self, widths: &[usize], ch: char, junction: char) -> Self {
+        let cols = self.effective_cols();
+        let mut line: Vec<char> = Vec::with_capacity(cols);
+        for (i, &w) in widths.iter().enumerate() {
+            if i > 0 {
+                line.push(junction);
+            }
+            line.extend(std::iter::repeat_n(ch, w));
+        }
+        line.resize(cols, ch);
+        self.push_text_line(&line.into_iter().collect::<String>());
+        self
+    }
+
+    /// Append a rating line of `stars` filled and `out_of - stars` empty
+    /// marks, e.g. `****-` for 4 out of 5. CP858 has no star glyph, so `*`
+    /// and `-` stand in; `stars` is clamped to `out_of`, and the line to
+    /// [`effective_cols`](Self::effective_cols).
+    ///
+    /// ```rust
+    /// use thermoprint::{ReceiptBuilder, PrintWidth};
+    /// let bytes = ReceiptBuilder::new(PrintWidth::Mm58).rating(4, 5).build();
+    /// assert_eq!(bytes, b"****-\n");
+    /// ```
+    pub fn rating(mut self, stars: u8, out_of: u8) -> Self {
+        let out_of = (out_of as usize).min(self.effective_cols());
+        let stars = (stars as usize).min(out_of);
+        self.push_text_line(&("*".repeat(stars) + &"-".repeat(out_of - stars)));
+        self
+    }
+
+    /// Append a horizontal bar `value / max` of `width` columns long, in
+    /// quarter-column steps: full blocks (`█`) then one shade (`░`, `▒` or
+    /// `▓`) for the remainder, e.g. for a sales sparkline on a report.
+    ///
+    /// `width` is clamped to [`effective_cols`](Self::effective_cols);
+    /// values above `max` fill the bar, while zero or negative values and
+    /// `max <= 0` print an empty line.
+    ///
+    /// ```rust
+    /// use rust_decimal::Decimal;
+    /// use thermoprint::{ReceiptBuilder, PrintWidth};
+    /// let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+    ///     .bar(Decimal::from(45), Decimal::from(100), 10)
+    ///     .build();
+    /// assert_eq!(bytes, b"\xDB\xDB\xDB\xDB\xB1\n"); // 4.5 of 10 columns
+    /// ```
+    pub fn bar(mut self, value: Decimal, max: Decimal, width: usize) -> Self {
+        let width = width.min(self.effective_cols());
+        let quarters = if max > Decimal::ZERO && value > Decimal::ZERO {
+            let ratio = value.min(max) / max;
+            (ratio * Decimal::from(width * 4))
+                .round()
+                .to_usize()
+                .unwrap_or(0)
+        } else {
+            0
+        };
+        let mut line = "█".repeat(quarters / 4);
+        match quarters % 4 {
+            1 => line.push('░'),
+            2 => line.push('▒'),
+            3 => line.push('▓'),
+            _ => {}
+        }
+        self.push_text_line(&line);
+        self
+    }
+
+    /// Append a section separator: `title` centred in a full-width run of
+    /// `ch`, e.g. `------ PAIEMENT ------`. An odd fill puts the extra `ch`
+    /// on the right; a title too long for the rule prints on its own.
+    ///
+    /// ```rust
+    /// use thermoprint::{ReceiptBuilder, PrintWidth};
+    /// let b = ReceiptBuilder::new(PrintWidth::Mm58).section("PAIEMENT", '-');
+    /// ```
+    pub fn section(mut self, title: &str, ch: char) -> Self {
+        let cols = self.effective_cols();
+        let len = title.chars().count();
+        // One space either side of the title, at least one `ch` per side
+        let line = match cols.checked_sub(len + 2) {
+            _ if title.is_empty() => ch.to_string().repeat(cols),
+            Some(fill) if fill >= 2 => {
+                let left = fill / 2;
+                format!(
+                    "{} {} {}",
+                    ch.to_string().repeat(left),
+                    title,
+                    ch.to_string().repeat(fill - left)
+                )
+            }
+            _ => title.to_owned(),
+        };
+        self.push_text_line(&line);
         self
     }
 
     /// Append a centred text line.
     pub fn centered(mut self, text: &str) -> Self {
-        let cols = self.cols();
-        let s = center(text, cols);
+        let cols = self.effective_cols();
+        let s = center_dir(text, cols, self.direction);
         self.push_text_line(&s);
         self
     }
 
     /// Append a right-aligned text line.
     pub fn right(mut self, text: &str) -> Self {
-        let cols = self.cols();
-        let s = right_align(text, cols);
+        let cols = self.effective_cols();
+        let s = right_align_dir(text, cols, self.direction);
         self.push_text_line(&s);
         self
     }
 
     /// Append a two-column row (label left, value right).
     pub fn row(mut self, left: &str, right: &str) -> Self {
-        let cols = self.cols();
-        let s = two_col(left, right, cols);
-        self.push_text_line(&s);
+        let cols = self.effective_cols();
+        self.push_row(left, right, cols);
         self
     }
 
-    // ── Paper movement ────────────────────────────────────────────────────────
-
-    /// Feed `n` blank lines.
-    pub fn feed(mut self, n: u8) -> Self {
-        self.push(&commands::feed_lines(n));
+    /// Print a paragraph word-wrapped and justified to the full column
+    /// width (e.g. terms & conditions). The last line stays left-aligned.
+    pub fn paragraph(mut self, text: &str) -> Self {
+        for line in justify(text, self.effective_cols()) {
+            self.push_text_line(&line);
+        }
+        self
+    }
+
+    /// Print `lines` centred inside a box drawn with CP858 box-drawing
+    /// characters (`┌─┐`, `│`, `└─┘`), e.g. for a coupon code or a warning.
+    ///
+    /// The box is sized to the longest line plus a space on each side and
+    /// centred on the paper; lines that don't fit in
+    /// [`effective_cols`](Self::effective_cols) are truncated.
+    ///
+    /// ```rust
+    /// use thermoprint::{ReceiptBuilder, PrintWidth};
+    /// let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+    ///     .boxed(&["CODE PROMO", "TABASKI25"])
+    ///     .build();
+    /// assert!(bytes.windows(2).any(|w| w == [0xDA, 0xC4])); // "┌─" in CP858
+    /// ```
+    pub fn boxed(mut self, lines: &[&str]) -> Self {
+        let cols = self.effective_cols();
+        let longest = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        let inner = (longest + 2).min(cols.saturating_sub(2));
+        let rule = "─".repeat(inner);
+
+        let top = center_dir(&format!("┌{}┐", rule), cols, self.direction);
+        self.push_text_line(&top);
+        for line in lines {
+            let text = center_dir(
+                &truncate(line, inner.saturating_sub(2)),
+                inner,
+                Direction::Ltr,
+            );
+            let row = format!("│{:<width$}│", text, width = inner);
+            self.push_text_line(&center_dir(&row, cols, self.direction));
+        }
+        let bottom = center_dir(&format!("└{}┘", rule), cols, self.direction);
+        self.push_text_line(&bottom);
+        self
+    }
+
+    // ── Paper movement ────────────────────────────────────────────────────────
+
+    /// Feed `n` blank lines (`ESC d n`, or `ESC a n` on
+    /// [`Dialect::Star`], where `ESC d` cuts).
+    pub fn feed(mut self, n: u8) -> Self {
+        let feed = self.dialect.feed_lines(n);
+        self.push(&feed);
+        self
+    }
+
+    /// Print and feed `n` dots (`ESC J n`), for vertical spacing finer
+    /// than [`feed`](Self::feed), e.g. to line up with a pre-printed form.
+    /// A dot is typically 1/203 inch (0.125 mm), so 8 dots ≈ 1 mm.
+    ///
+    /// ```rust
+    /// use thermoprint::{ReceiptBuilder, PrintWidth};
+    /// let bytes = ReceiptBuilder::new(PrintWidth::Mm80).feed_dots(16).build();
+    /// assert_eq!(bytes, [0x1B, b'J', 16]);
+    /// ```
+    pub fn feed_dots(mut self, n: u8) -> Self {
+        let feed = self.dialect.feed_dots(n);
+        self.push(&feed);
         self
     }
 
     /// Cut the paper (partial cut — safest for most printers).
+    ///
+    /// No extra feed is sent before the cut; if your cutter slices through
+    /// the last printed line, use [`cut_with_feed`](Self::cut_with_feed).
+    ///
+    /// Like every cut method, this ejects the page with a form feed on
+    /// [`PrintWidth::A4`], unless the page was just ejected.
     pub fn cut(mut self) -> Self {
-        self.push(commands::cut_partial());
+        let cut = self.cut_bytes(false);
+        self.push_cut(&cut);
+        self
+    }
+
+    /// Feed `lines` blank lines, then partial cut, so the tear-off clears
+    /// the print head. Ignores [`cut_feed`](Self::cut_feed).
+    pub fn feed_and_cut(mut self, lines: u8) -> Self {
+        let cut = self.dialect.cut(false, None);
+        self = self.feed(lines);
+        self.push_cut(&cut);
+        self
+    }
+
+    /// Feed `lines` blank lines before every [`cut`](Self::cut) and
+    /// [`cut_full`](Self::cut_full), for printers whose cutter sits below
+    /// the print head and would otherwise slice through the last lines.
+    /// Default 0 (no extra feed).
+    ///
+    /// ```rust
+    /// use thermoprint::{ReceiptBuilder, PrintWidth};
+    /// let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+    ///     .cut_feed(3)
+    ///     .text_line("Merci")
+    ///     .cut()
+    ///     .build();
+    /// assert!(bytes.ends_with(b"Merci\n\n\n\n\x1DVB\0"));
+    /// ```
+    pub fn cut_feed(mut self, lines: u8) -> Self {
+        self.cut_feed = lines;
+        self
+    }
+
+    /// The configured [`cut_feed`](Self::cut_feed), then a partial or full cut.
+    fn cut_bytes(&self, full: bool) -> Vec<u8> {
+        // Plain line endings: `ESC d n` is a feed on Epson but a cut on Star
+        let mut bytes = self.line_ending.bytes().repeat(usize::from(self.cut_feed));
+        bytes.extend(self.dialect.cut(full, None));
+        bytes
+    }
+
+    /// Partial cut after feeding `units` motion units past the cutter
+    /// (`GS V 66 n`); on Epson printers [`cut`](Self::cut) is `cut_with_feed(0)`.
+    pub fn cut_with_feed(mut self, units: u8) -> Self {
+        let cut = self.dialect.cut(false, Some(units));
+        self.push_cut(&cut);
         self
     }
 
     /// Full cut.
     pub fn cut_full(mut self) -> Self {
-        self.push(commands::cut_full());
+        let cut = self.cut_bytes(true);
+        self.push_cut(&cut);
+        self
+    }
+
+    /// Full cut after feeding `units` motion units past the cutter
+    /// (`GS V 65 n`).
+    pub fn cut_full_with_feed(mut self, units: u8) -> Self {
+        let cut = self.dialect.cut(true, Some(units));
+        self.push_cut(&cut);
         self
     }
 
-    /// Form feed (page eject — use for A4 / impact printers).
+    /// Form feed (page eject — use for A4 / impact printers). Sent on every
+    /// width; thermal rolls usually [`cut`](Self::cut) instead.
     pub fn form_feed(mut self) -> Self {
         self.push(commands::form_feed());
         self
@@ -293,92 +1196,655 @@ impl ReceiptBuilder {
 
     // ── Barcodes & QR ─────────────────────────────────────────────────────────
 
+    /// Set where and in which font barcode HRI text is printed for all
+    /// following barcodes. Defaults to [`HriPosition::Below`] in [`HriFont::A`].
+    pub fn barcode_hri(mut self, position: HriPosition, font: HriFont) -> Self {
+        self.hri_position = position;
+        self.hri_font = font;
+        self
+    }
+
+    /// Paper width the builder lays out for.
+    pub(crate) fn paper_width(&self) -> PrintWidth {
+        self.width
+    }
+
+    /// Current HRI position and font, so a single barcode can override them.
+    pub(crate) fn hri_setting(&self) -> (HriPosition, HriFont) {
+        (self.hri_position, self.hri_font)
+    }
+
+    /// Whether barcodes and QR codes are followed by a line feed.
+    ///
+    /// On by default. Turn it off to stack a code tightly against the next
+    /// line, e.g. a caption under a QR code.
+    ///
+    /// ```rust
+    /// use thermoprint::{ReceiptBuilder, PrintWidth};
+    /// let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+    ///     .code_line_feed(false)
+    ///     .qr_with_caption("https://example.com", 4, "Scan me")
+    ///     .build();
+    /// ```
+    pub fn code_line_feed(mut self, on: bool) -> Self {
+        self.code_lf = on;
+        self
+    }
+
     /// Print a CODE128 barcode.
     ///
     /// `bar_width` — module width in dots (1–6, default 2)
     /// `bar_height` — height in dots (default 60)
     pub fn barcode_code128(mut self, value: &str) -> Self {
-        self.push(&commands::barcode_width(2));
-        self.push(&commands::barcode_height(60));
-        self.push(&commands::barcode_hri_position(2));
-        self.push(&commands::barcode_hri_font(0));
-        self.push(&commands::barcode_code128(value));
-        self.push_lf();
+        self.push_barcode(Symbology::Code128, value, 2, 60);
+        self.push_code_lf();
         self
     }
 
     /// Print a CODE128 barcode with custom dimensions.
     pub fn barcode_code128_custom(mut self, value: &str, bar_width: u8, bar_height: u8) -> Self {
-        self.push(&commands::barcode_width(bar_width));
-        self.push(&commands::barcode_height(bar_height));
-        self.push(&commands::barcode_hri_position(2));
-        self.push(&commands::barcode_hri_font(0));
-        self.push(&commands::barcode_code128(value));
-        self.push_lf();
+        self.push_barcode(Symbology::Code128, value, bar_width, bar_height);
+        self.push_code_lf();
         self
     }
 
     /// Print an EAN-13 barcode. `value` must be 12 digits.
     pub fn barcode_ean13(mut self, value: &str) -> Self {
-        self.push(&commands::barcode_width(2));
-        self.push(&commands::barcode_height(60));
-        self.push(&commands::barcode_hri_position(2));
-        self.push(&commands::barcode_ean13(value));
-        self.push_lf();
+        self.push_barcode(Symbology::Ean13, value, 2, 60);
+        self.push_code_lf();
         self
     }
 
+    /// Print an EAN-8 barcode. `value` must be exactly 7 digits (the printer
+    /// adds the check digit); anything else returns
+    /// [`ThermoprintError::InvalidBarcode`].
+    pub fn barcode_ean8(mut self, value: &str) -> Result<Self, ThermoprintError> {
+        Self::check_barcode(Symbology::Ean8, value)?;
+        self.push_barcode(Symbology::Ean8, value, 2, 60);
+        self.push_code_lf();
+        Ok(self)
+    }
+
+    /// Print a price-embedded EAN-13, as on scale-printed labels for
+    /// weighed goods: `2x` prefix, 5-digit item code, then the price as 5
+    /// zero-padded digits in the smallest currency unit of the
+    /// [`money_format`](Self::money_format) (cents with 2 decimals, whole
+    /// francs with the FCFA default). The printer adds the check digit.
+    ///
+    /// `item_code` is either the 5-digit code, printed with the `20` prefix,
+    /// or 7 digits already starting with the `2x` prefix. Returns
+    /// [`ThermoprintError::InvalidBarcode`] for any other code, a negative
+    /// price, a price with more decimals than the money format, or one that
+    /// doesn't fit in 5 digits.
+    ///
+    /// ```rust
+    /// use thermoprint::{ReceiptBuilder, PrintWidth, MoneyFormat};
+    /// use rust_decimal::prelude::*;
+    /// let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+    ///     .money_format(MoneyFormat::new(2, None, ',', false))
+    ///     .barcode_price_ean13("12345", dec!(12.34))?
+    ///     .build();
+    /// assert!(bytes.windows(12).any(|w| w == b"201234501234"));
+    /// # Ok::<(), thermoprint::ThermoprintError>(())
+    /// ```
+    pub fn barcode_price_ean13(
+        mut self,
+        item_code: &str,
+        price: Decimal,
+    ) -> Result<Self, ThermoprintError> {
+        let value = Self::price_ean13_payload(item_code, price, self.money.decimals)?;
+        self.push_barcode(Symbology::Ean13, &value, 2, 60);
+        self.push_code_lf();
+        Ok(self)
+    }
+
+    /// Assemble the 12 digits of a price-embedded EAN-13.
+    fn price_ean13_payload(
+        item_code: &str,
+        price: Decimal,
+        decimals: u32,
+    ) -> Result<String, ThermoprintError> {
+        let invalid = |reason: &str| ThermoprintError::InvalidBarcode {
+            value: format!("{} @ {}", item_code, price),
+            reason: reason.to_owned(),
+        };
+        if !item_code.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid("item code must be digits"));
+        }
+        let prefixed = match item_code.len() {
+            5 => format!("20{}", item_code),
+            7 if item_code.starts_with('2') => item_code.to_owned(),
+            _ => {
+                return Err(invalid(
+                    "item code must be 5 digits, or 7 digits starting with 2",
+                ))
+            }
+        };
+        if price.is_sign_negative() || price.normalize().scale() > decimals {
+            return Err(invalid(
+                "price must be positive with at most the money format's decimals",
+            ));
+        }
+        let units = 10u64
+            .checked_pow(decimals)
+            .and_then(|unit| price.checked_mul(Decimal::from(unit)))
+            .and_then(|units| units.to_u32());
+        match units {
+            Some(units) if units < 100_000 => Ok(format!("{}{:05}", prefixed, units)),
+            _ => Err(invalid("price does not fit in 5 digits")),
+        }
+    }
+
+    /// Print a barcode in any [`Symbology`] with the given module width and
+    /// height in dots, validating `value` as in
+    /// [`labeled_barcode`](Self::labeled_barcode). Returns
+    /// [`ThermoprintError::InvalidBarcode`] without printing anything if the
+    /// symbology can't encode it.
+    ///
+    /// ```rust
+    /// use thermoprint::{ReceiptBuilder, PrintWidth, Symbology};
+    /// let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+    ///     .barcode(Symbology::Ean8, "9638507", 3, 80)?
+    ///     .build();
+    /// # Ok::<(), thermoprint::ThermoprintError>(())
+    /// ```
+    pub fn barcode(
+        mut self,
+        symbology: Symbology,
+        value: &str,
+        bar_width: u8,
+        bar_height: u8,
+    ) -> Result<Self, ThermoprintError> {
+        Self::check_barcode(symbology, value)?;
+        self.push_barcode(symbology, value, bar_width, bar_height);
+        self.push_code_lf();
+        Ok(self)
+    }
+
+    /// Print a barcode, choosing the symbology from the value.
+    ///
+    /// Exactly 12 digits prints as EAN-13 (check digit added by the printer);
+    /// anything else prints as CODE128. Returns
+    /// [`ThermoprintError::InvalidBarcode`] if the value is empty, encodes to
+    /// more than 255 bytes, or contains characters outside printable ASCII.
+    pub fn auto_barcode(self, value: &str) -> Result<Self, ThermoprintError> {
+        if value.len() == 12 && value.bytes().all(|b| b.is_ascii_digit()) {
+            return Ok(self.barcode_ean13(value));
+        }
+        Self::check_code128(value)?;
+        Ok(self.barcode_code128(value))
+    }
+
+    /// Print a shelf-label style block: `label` centred, then a centred
+    /// barcode of `value` in the given symbology, then the start alignment
+    /// is restored so a price line can follow.
+    ///
+    /// `value` is validated first — CODE128 as in
+    /// [`auto_barcode`](Self::auto_barcode), EAN-13 as 12 or 13 digits and
+    /// EAN-8 as 7 digits — returning [`ThermoprintError::InvalidBarcode`]
+    /// without printing anything.
+    ///
+    /// ```rust
+    /// use thermoprint::{ReceiptBuilder, PrintWidth, Symbology};
+    /// let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+    ///     .labeled_barcode("Riz parfumé 5kg", "590123412345", Symbology::Ean13)?
+    ///     .centered("4 500 FCFA")
+    ///     .build();
+    /// # Ok::<(), thermoprint::ThermoprintError>(())
+    /// ```
+    pub fn labeled_barcode(
+        mut self,
+        label: &str,
+        value: &str,
+        symbology: Symbology,
+    ) -> Result<Self, ThermoprintError> {
+        Self::check_barcode(symbology, value)?;
+        self = self.align_center().text_line(label);
+        self.push_barcode(symbology, value, 2, 60);
+        self.push_code_lf();
+        Ok(self.align_start())
+    }
+
+    /// Print a centred barcode with the printer's HRI text turned off,
+    /// then `value` as a centred text line in `font`, for legible text that
+    /// looks the same on every printer model. The HRI setting and start
+    /// alignment are restored afterwards.
+    ///
+    /// `value` is validated as in [`labeled_barcode`](Self::labeled_barcode).
+    ///
+    /// ```rust
+    /// use thermoprint::{HriFont, PrintWidth, ReceiptBuilder, Symbology};
+    /// let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+    ///     .barcode_with_text("590123412345", Symbology::Ean13, HriFont::B)?
+    ///     .build();
+    /// assert!(bytes.windows(3).any(|w| w == [0x1D, b'H', 0]));
+    /// # Ok::<(), thermoprint::ThermoprintError>(())
+    /// ```
+    pub fn barcode_with_text(
+        mut self,
+        value: &str,
+        symbology: Symbology,
+        font: HriFont,
+    ) -> Result<Self, ThermoprintError> {
+        Self::check_barcode(symbology, value)?;
+        let hri = self.hri_position;
+        self.hri_position = HriPosition::None;
+        self = self.align_center();
+        self.push_barcode(symbology, value, 2, 60);
+        self.push_code_lf();
+        self.hri_position = hri;
+
+        self.push(match font {
+            HriFont::A => commands::font_a(),
+            HriFont::B => commands::font_b(),
+        });
+        self.push_text_line(value);
+        self.push(commands::font_a());
+        Ok(self.align_start())
+    }
+
+    /// Reject values the symbology can't encode.
+    fn check_barcode(symbology: Symbology, value: &str) -> Result<(), ThermoprintError> {
+        let digits = value.bytes().all(|b| b.is_ascii_digit());
+        let reason = match symbology {
+            Symbology::Code128 => return Self::check_code128(value),
+            Symbology::Ean13 if !digits || !(12..=13).contains(&value.len()) => {
+                "EAN-13 requires 12 or 13 digits"
+            }
+            Symbology::Ean8 if !digits || value.len() != 7 => "EAN-8 requires exactly 7 digits",
+            _ => return Ok(()),
+        };
+        Err(ThermoprintError::InvalidBarcode {
+            value: value.to_owned(),
+            reason: reason.to_owned(),
+        })
+    }
+
+    /// Reject values CODE128 can't print: empty, non-printable ASCII, or
+    /// more than 255 bytes once encoded.
+    fn check_code128(value: &str) -> Result<(), ThermoprintError> {
+        let reason = if value.is_empty() {
+            "value is empty"
+        } else if !value.bytes().all(|b| (0x20..=0x7E).contains(&b)) {
+            "CODE128 accepts printable ASCII only"
+        } else if commands::code128_data(value.as_bytes()).len() > 255 {
+            "encoded CODE128 data exceeds 255 bytes"
+        } else {
+            return Ok(());
+        };
+        Err(ThermoprintError::InvalidBarcode {
+            value: value.to_owned(),
+            reason: reason.to_owned(),
+        })
+    }
+
+    /// Print an order number centred and bold with a CODE128 barcode of the
+    /// same value below it, then restore the start alignment.
+    ///
+    /// With `check_digit`, a Luhn (mod 10) check digit is appended to both
+    /// the text and the barcode; this requires an all-digit `number`.
+    /// Returns [`ThermoprintError::InvalidBarcode`] for values CODE128 can't
+    /// encode (see [`auto_barcode`](Self::auto_barcode)).
+    ///
+    /// ```rust
+    /// use thermoprint::{ReceiptBuilder, PrintWidth};
+    /// let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+    ///     .order_reference("2024001", true)?
+    ///     .build();
+    /// assert!(String::from_utf8_lossy(&bytes).contains("20240016"));
+    /// # Ok::<(), thermoprint::ThermoprintError>(())
+    /// ```
+    pub fn order_reference(
+        self,
+        number: &str,
+        check_digit: bool,
+    ) -> Result<Self, ThermoprintError> {
+        let value = if check_digit {
+            let digit = commands::luhn_check_digit(number).ok_or_else(|| {
+                ThermoprintError::InvalidBarcode {
+                    value: number.to_owned(),
+                    reason: "check digit requires a numeric value".to_owned(),
+                }
+            })?;
+            format!("{}{}", number, digit)
+        } else {
+            number.to_owned()
+        };
+        Self::check_code128(&value)?;
+        Ok(self
+            .align_center()
+            .bold(true)
+            .text_line(&value)
+            .bold(false)
+            .barcode_code128(&value)
+            .align_start())
+    }
+
     /// Print a QR code. `size` controls the module size (1–8).
     pub fn qr_code(mut self, data: &str, size: u8) -> Self {
-        self.push(&commands::qr_code(data, size));
-        self.push_lf();
+        let qr = self
+            .dialect
+            .qr_code(data.as_bytes(), size, QrErrorCorrection::M);
+        self.push_if(Feature::Barcode, &qr);
+        self.push_code_lf();
+        self
+    }
+
+    /// Print a QR code from raw bytes, stored exactly as given (no UTF-8
+    /// assumption) — for binary or byte-exact payloads such as EMV
+    /// merchant-presented codes used by mobile-money wallets.
+    ///
+    /// ```rust
+    /// use thermoprint::{ReceiptBuilder, PrintWidth};
+    /// let payload: &[u8] = b"000201010212\x00\xFF";
+    /// let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+    ///     .qr_code_bytes(payload, 5)
+    ///     .build();
+    /// assert!(bytes.windows(payload.len()).any(|w| w == payload));
+    /// ```
+    pub fn qr_code_bytes(mut self, data: &[u8], size: u8) -> Self {
+        let qr = self.dialect.qr_code(data, size, QrErrorCorrection::M);
+        self.push_if(Feature::Barcode, &qr);
+        self.push_code_lf();
+        self
+    }
+
+    /// Print a Data Matrix symbol, e.g. a GS1 pharmaceutical code. `size`
+    /// is the module size in dots (2–16). Epson dialect only; see
+    /// [`commands::data_matrix`].
+    ///
+    /// ```rust
+    /// use thermoprint::{ReceiptBuilder, PrintWidth};
+    /// let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+    ///     .data_matrix("LOT 4411 EXP 2027-03", 4)
+    ///     .build();
+    /// assert!(bytes.windows(6).any(|w| w == [0x1D, b'(', b'k', 5, 0, 54]));
+    /// ```
+    pub fn data_matrix(mut self, data: &str, size: u8) -> Self {
+        self.push_if(Feature::Barcode, &commands::data_matrix(data, size));
+        self.push_code_lf();
         self
     }
 
+    /// Print a QR code, or return [`ThermoprintError::InvalidBarcode`] if
+    /// `data` is empty or longer than [`commands::QR_MAX_BYTES`].
+    pub fn qr_code_checked(self, data: &str, size: u8) -> Result<Self, ThermoprintError> {
+        let reason = if data.is_empty() {
+            "value is empty".to_owned()
+        } else if data.len() > commands::QR_MAX_BYTES {
+            format!(
+                "{} bytes exceeds the QR capacity of {} bytes",
+                data.len(),
+                commands::QR_MAX_BYTES
+            )
+        } else {
+            return Ok(self.qr_code(data, size));
+        };
+        Err(ThermoprintError::InvalidBarcode {
+            value: data.to_owned(),
+            reason,
+        })
+    }
+
+    /// Print a centred QR code with a centred caption underneath
+    /// (e.g. "Scannez pour votre facture"), then restore the start alignment.
+    pub fn qr_with_caption(mut self, data: &str, size: u8, caption: &str) -> Self {
+        if !self.width.supports(Feature::Barcode) {
+            // A caption with nothing to scan would only confuse on A4
+            self.skip(Feature::Barcode);
+            return self;
+        }
+        self = self.align_center();
+        let qr = self
+            .dialect
+            .qr_code(data.as_bytes(), size, QrErrorCorrection::M);
+        self.push(&qr);
+        self.push_code_lf();
+        self.push_text_line(caption);
+        self.align_start()
+    }
+
+    /// Print a centred mobile money payment QR (Wave, Orange Money, ...)
+    /// with a localized "pay with your phone" caption.
+    ///
+    /// The QR encodes `base_url` with `amount` and `reference` query
+    /// parameters appended and percent-encoded (see
+    /// [`qr::payment_url`](crate::qr::payment_url)). The amount is rounded to
+    /// the [`money_format`](Self::money_format) decimals and written with a
+    /// `.` and no thousands separator or currency, as payment links expect.
+    ///
+    /// ```rust
+    /// use thermoprint::{ReceiptBuilder, PrintWidth};
+    /// use rust_decimal::prelude::*;
+    /// let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+    ///     .payment_qr("https://pay.wave.com/m/M_42", dec!(62540), "CMD-1042")
+    ///     .build();
+    /// let text = String::from_utf8_lossy(&bytes);
+    /// assert!(text.contains("https://pay.wave.com/m/M_42?amount=62540&reference=CMD-1042"));
+    /// assert!(text.contains("Payez avec votre telephone"));
+    /// ```
+    pub fn payment_qr(self, base_url: &str, amount: Decimal, reference: &str) -> Self {
+        let mut amount = amount.round_dp(self.money.decimals);
+        amount.rescale(self.money.decimals);
+        let url = crate::qr::payment_url(base_url, &amount.to_string(), reference);
+        let caption = self.labels.pay_by_phone;
+        self.qr_with_caption(&url, 4, caption)
+    }
+
+    /// Print a QR code that adds a contact (vCard) when scanned.
+    /// Empty `phone` or `email` fields are left out.
+    pub fn qr_vcard(self, name: &str, phone: &str, email: &str) -> Self {
+        self.qr_code(&crate::qr::vcard(name, phone, email), 4)
+    }
+
+    /// Print a QR code that joins a Wi-Fi network when scanned.
+    pub fn qr_wifi(
+        self,
+        ssid: &str,
+        password: &str,
+        encryption: crate::qr::WifiEncryption,
+    ) -> Self {
+        self.qr_code(&crate::qr::wifi(ssid, password, encryption), 4)
+    }
+
     // ── Cash drawer ───────────────────────────────────────────────────────────
 
-    /// Emit a cash drawer kick pulse.
+    /// Emit a cash drawer kick pulse. Does nothing on [`PrintWidth::A4`]:
+    /// drawers hang off thermal receipt printers.
     pub fn open_cash_drawer(mut self) -> Self {
-        self.push(&commands::cash_drawer_kick());
+        if self.width.supports(Feature::CashDrawer) {
+            self.push_control(&commands::cash_drawer_kick());
+        } else {
+            self.skip(Feature::CashDrawer);
+        }
+        self
+    }
+
+    /// Emit a cash drawer kick pulse on a single pin with custom timing.
+    ///
+    /// `pin` is `2` or `5`; `on_time` / `off_time` are in 2 ms units.
+    /// Use this when kicking both pins double-fires or the drawer needs a
+    /// longer pulse.
+    pub fn open_cash_drawer_custom(mut self, pin: u8, on_time: u8, off_time: u8) -> Self {
+        if self.width.supports(Feature::CashDrawer) {
+            self.push_control(&commands::cash_drawer_kick_pin(pin, on_time, off_time));
+        } else {
+            self.skip(Feature::CashDrawer);
+        }
         self
     }
 
     // ── Logo ──────────────────────────────────────────────────────────────────
 
+    /// Override the [`RasterMode`](crate::types::RasterMode) used by
+    /// [`logo`](Self::logo), [`logo_rotated`](Self::logo_rotated) and
+    /// [`logo_sized`](Self::logo_sized). Without it the paper width picks:
+    /// single-density `ESC *` on 58mm, `GS v 0` elsewhere — see
+    /// [`PrintWidth::default_raster_mode`]. Use this when a 58mm printer
+    /// handles `GS v 0` fine, or an 80mm one smears dense logos.
+    ///
+    /// Available only when the `native` feature is enabled.
+    ///
+    /// ```rust
+    /// use thermoprint::{PrintWidth, RasterMode, ReceiptBuilder};
+    /// let b = ReceiptBuilder::new(PrintWidth::Mm58).raster_strategy(RasterMode::GsV0);
+    /// ```
+    #[cfg(feature = "native")]
+    pub fn raster_strategy(mut self, mode: crate::types::RasterMode) -> Self {
+        self.raster_mode = Some(mode);
+        self
+    }
+
+    /// Raster mode for logos: the override, else the width's default.
+    #[cfg(feature = "native")]
+    fn raster_mode(&self) -> crate::types::RasterMode {
+        self.raster_mode
+            .unwrap_or_else(|| self.width.default_raster_mode())
+    }
+
     /// Render a logo image to raster ESC/POS bytes and append them.
     ///
     /// Available only when the `native` feature is enabled.
-    /// The image is resized to fit the print width automatically.
+    /// The image is resized to fit the print width automatically, in the
+    /// width's [`raster_strategy`](Self::raster_strategy).
     #[cfg(feature = "native")]
     pub fn logo(mut self, path: &str) -> Result<Self, ThermoprintError> {
+        let max_px = self.width.max_image_px();
+        let raster = crate::image::load_and_rasterise_as(
+            path,
+            max_px,
+            crate::image::Rotation::None,
+            self.raster_mode(),
+        )?;
+        self.push_if(Feature::Logo, &raster);
+        self.push_logo_lf();
+        Ok(self)
+    }
+
+    /// Like [`logo`](Self::logo), but rotates the image before rasterising.
+    ///
+    /// Available only when the `native` feature is enabled.
+    #[cfg(feature = "native")]
+    pub fn logo_rotated(
+        mut self,
+        path: &str,
+        rotation: crate::image::Rotation,
+    ) -> Result<Self, ThermoprintError> {
+        let max_px = self.width.max_image_px();
+        let raster =
+            crate::image::load_and_rasterise_as(path, max_px, rotation, self.raster_mode())?;
+        self.push_if(Feature::Logo, &raster);
+        self.push_logo_lf();
+        Ok(self)
+    }
+
+    /// Like [`logo`](Self::logo), but emits the image in the given
+    /// [`RasterMode`](crate::types::RasterMode) — use `RasterMode::EscStar`
+    /// for printers without `GS v 0` support.
+    ///
+    /// Available only when the `native` feature is enabled.
+    #[cfg(feature = "native")]
+    pub fn logo_with_mode(
+        mut self,
+        path: &str,
+        mode: crate::types::RasterMode,
+    ) -> Result<Self, ThermoprintError> {
+        let max_px = self.width.max_image_px();
+        let raster =
+            crate::image::load_and_rasterise_as(path, max_px, crate::image::Rotation::None, mode)?;
+        self.push_if(Feature::Logo, &raster);
+        self.push_logo_lf();
+        Ok(self)
+    }
+
+    /// Like [`logo`](Self::logo), but pads the image so it sits centred on
+    /// the page. Padding is whole bytes, so centring is accurate to 8 px.
+    /// Always emits `GS v 0`, whatever the [`raster_strategy`](Self::raster_strategy).
+    ///
+    /// Available only when the `native` feature is enabled.
+    #[cfg(feature = "native")]
+    pub fn logo_centered(mut self, path: &str) -> Result<Self, ThermoprintError> {
         let max_px = self.width.max_image_px();
         let raster = crate::image::load_and_rasterise(path, max_px)?;
-        self.data.extend_from_slice(&raster);
-        self.push_lf();
+        self.push_if(Feature::Logo, &commands::center_raster(&raster, max_px));
+        self.push_logo_lf();
+        Ok(self)
+    }
+
+    /// Like [`logo`](Self::logo), but scales the image to exactly
+    /// `target_px` wide (clamped to [`PrintWidth::max_image_px`]), up or
+    /// down, e.g. a 100 px icon instead of a full-width banner. For a centred
+    /// icon, pass [`image::load_and_rasterise_sized`](crate::image::load_and_rasterise_sized)
+    /// to [`logo_raw_centered`](Self::logo_raw_centered).
+    ///
+    /// Available only when the `native` feature is enabled.
+    #[cfg(feature = "native")]
+    pub fn logo_sized(mut self, path: &str, target_px: u32) -> Result<Self, ThermoprintError> {
+        let target_px = target_px.min(self.width.max_image_px());
+        let raster = crate::image::load_and_rasterise_sized(path, target_px, self.raster_mode())?;
+        self.push_if(Feature::Logo, &raster);
+        self.push_logo_lf();
         Ok(self)
     }
 
     /// Append pre-rasterised image bytes directly (use when you have already
     /// converted the image outside the library, e.g. in WASM context).
     pub fn logo_raw(mut self, raster_bytes: &[u8]) -> Self {
-        self.data.extend_from_slice(raster_bytes);
-        self.push_lf();
+        self.push_if(Feature::Logo, raster_bytes);
+        self.push_logo_lf();
+        self
+    }
+
+    /// Like [`logo_raw`](Self::logo_raw), but re-pads a `GS v 0` command so
+    /// the image sits centred on the page (accurate to 8 px).
+    pub fn logo_raw_centered(mut self, raster_bytes: &[u8]) -> Self {
+        let max_px = self.width.max_image_px();
+        self.push_if(
+            Feature::Logo,
+            &commands::center_raster(raster_bytes, max_px),
+        );
+        self.push_logo_lf();
+        self
+    }
+
+    /// Print a logo already stored in the printer's NV memory (`FS p`).
+    ///
+    /// Much cheaper than re-sending raster data on every receipt. `n` is the
+    /// 1-based image number; `mode` is 0 normal, 1 double width, 2 double
+    /// height, 3 quadruple. Store the image once with
+    /// [`commands::define_nv_logo`].
+    pub fn nv_logo(mut self, n: u8, mode: u8) -> Self {
+        self.push_if(Feature::Logo, &commands::print_nv_logo(n, mode));
+        self.push_logo_lf();
+        self
+    }
+
+    /// Print NV graphics stored under `key` with
+    /// [`commands::define_nv_graphic`] (or `image::define_nv_graphic` on
+    /// native builds). Only 11 bytes per receipt.
+    pub fn nv_graphic(mut self, key: [u8; 2]) -> Self {
+        self.push_if(Feature::Logo, &commands::print_nv_graphic(key));
+        self.push_logo_lf();
         self
     }
 
     // ── High-level receipt helpers ────────────────────────────────────────────
 
     /// Print a shop header block (name, phone, address) centred and bold.
+    ///
+    /// The name is printed in double size, so it is truncated to half the
+    /// columns instead of wrapping onto a second line.
     pub fn shop_header(self, name: &str, phone: &str, address: &str) -> Self {
+        let name = truncate_cols(name, self.cols(), 2);
         self.align_center()
             .bold(true)
             .double_size(true)
-            .text_line(name)
+            .text_line(&name)
             .bold(false)
             .normal_size()
             .text_line(phone)
             .text_line(address)
-            .align_left()
+            .align_start()
     }
 
     /// Print a single line item: name, quantity, unit price, line total.
@@ -386,46 +1852,187 @@ impl ReceiptBuilder {
     /// If `discount` is `Some`, show the original total, the discount, and
     /// the final price after discount.
     pub fn item(
+        self,
+        name: &str,
+        qty: i32,
+        unit_price: Decimal,
+        discount: Option<Decimal>,
+    ) -> Self {
+        let line_total = unit_price * Decimal::from(qty);
+        self.item_line(name, &qty.to_string(), unit_price, line_total, discount)
+    }
+
+    /// Like [`item`](Self::item), but with amounts shown in `currency`
+    /// instead of the builder's [`currency`](Self::currency), e.g. a USD line
+    /// on an FCFA receipt. The default currency is unchanged afterwards.
+    ///
+    /// ```rust
+    /// use rust_decimal::Decimal;
+    /// use thermoprint::{ReceiptBuilder, PrintWidth};
+    /// let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+    ///     .item_in("Carte SIM internationale", 1, Decimal::from(5), None, "USD")
+    ///     .build();
+    /// assert!(String::from_utf8_lossy(&bytes).contains("5 USD"));
+    /// ```
+    pub fn item_in(
+        self,
+        name: &str,
+        qty: i32,
+        unit_price: Decimal,
+        discount: Option<Decimal>,
+        currency: &str,
+    ) -> Self {
+        self.with_currency(currency, |b| b.item(name, qty, unit_price, discount))
+    }
+
+    /// Run `f` with `currency` in place of the builder's, then restore it.
+    fn with_currency(mut self, currency: &str, f: impl FnOnce(Self) -> Self) -> Self {
+        let saved = std::mem::replace(&mut self.currency, currency.to_owned());
+        let saved_foreign = std::mem::replace(&mut self.foreign_currency, true);
+        let mut b = f(self);
+        b.currency = saved;
+        b.foreign_currency = saved_foreign;
+        b
+    }
+
+    /// Like [`item`](Self::item), and record the line total (after discount)
+    /// as taxable base at `vat_rate` percent for
+    /// [`auto_vat_summary`](Self::auto_vat_summary). Prices exclude VAT.
+    pub fn item_vat(
         mut self,
         name: &str,
         qty: i32,
         unit_price: Decimal,
         discount: Option<Decimal>,
+        vat_rate: Decimal,
+    ) -> Self {
+        let base = unit_price * Decimal::from(qty) - discount.unwrap_or_default();
+        *self.vat_bases.entry(vat_rate.normalize()).or_default() += base;
+        self.item(name, qty, unit_price, discount)
+    }
+
+    /// Print a line item on a single line: the name on the left (truncated
+    /// to fit) and `qty x price = total` on the right without the currency,
+    /// e.g. `Polo shirt           2x15000=30000`. A discount shows as
+    /// `2x15000-2000=28000`. If the amounts leave no room for the name, the
+    /// name gets a line of its own above them.
+    ///
+    /// ```rust
+    /// use thermoprint::{ReceiptBuilder, PrintWidth};
+    /// use rust_decimal::prelude::*;
+    /// let b = ReceiptBuilder::new(PrintWidth::Mm58)
+    ///     .item_compact("Polo shirt", 2, dec!(15000), None);
+    /// ```
+    pub fn item_compact(
+        self,
+        name: &str,
+        qty: i32,
+        unit_price: Decimal,
+        discount: Option<Decimal>,
     ) -> Self {
-        let cols = self.cols();
         let line_total = unit_price * Decimal::from(qty);
+        self.compact_line(name, &qty.to_string(), unit_price, line_total, discount)
+    }
+
+    fn compact_line(
+        mut self,
+        name: &str,
+        qty_label: &str,
+        unit_price: Decimal,
+        line_total: Decimal,
+        discount: Option<Decimal>,
+    ) -> Self {
+        let cols = self.effective_cols();
+        let amounts = match discount {
+            Some(disc) if disc > Decimal::zero() => format!(
+                "{}x{}-{}={}",
+                qty_label,
+                self.fmt_bare(unit_price),
+                self.fmt_bare(disc),
+                self.fmt_bare(line_total - disc)
+            ),
+            _ => format!(
+                "{}x{}={}",
+                qty_label,
+                self.fmt_bare(unit_price),
+                self.fmt_bare(line_total)
+            ),
+        };
+
+        // Keep at least a few characters of the name beside the amounts
+        let name_width = cols.saturating_sub(amounts.chars().count() + 1);
+        let line = if name_width >= 4 {
+            two_col_dir(&truncate(name, name_width), &amounts, cols, self.direction)
+        } else {
+            self.push_text_line(&truncate(name, cols));
+            right_align_dir(&amounts, cols, self.direction)
+        };
+        self.push_text_line(&line);
+        self
+    }
+
+    /// Print a line item sold by weight or volume, e.g. `1.250 kg x 3500 FCFA`.
+    ///
+    /// `qty` is printed exactly as given (`dec!(1.250)` stays `1.250`); the
+    /// line total is computed at full precision and only rounded by the
+    /// configured [`MoneyFormat`] when printed.
+    pub fn item_weighted(
+        self,
+        name: &str,
+        qty: Decimal,
+        unit: &str,
+        unit_price: Decimal,
+        discount: Option<Decimal>,
+    ) -> Self {
+        let line_total = unit_price * qty;
+        let qty_label = format!("{} {}", qty, unit);
+        self.item_line(name, &qty_label, unit_price, line_total, discount)
+    }
+
+    fn item_line(
+        mut self,
+        name: &str,
+        qty_label: &str,
+        unit_price: Decimal,
+        line_total: Decimal,
+        discount: Option<Decimal>,
+    ) -> Self {
+        if self.item_style == ItemStyle::Compact {
+            return self.compact_line(name, qty_label, unit_price, line_total, discount);
+        }
+        let cols = self.effective_cols();
 
         // Item name (bold, truncated to fit)
         self = self.bold(true);
-        self.push_text_line(&truncate(name, cols - 2));
+        self.push_text_line(&truncate(name, cols.saturating_sub(2)));
         self = self.bold(false);
 
         // Quantity × unit price
-        let qty_line = format!("{} x {}", qty, self.fmt(unit_price));
+        let qty_line = format!("{} x {}", qty_label, self.fmt_item(unit_price));
         self.push_text_line(&qty_line);
 
         match discount {
             Some(disc) if disc > Decimal::zero() => {
                 // Original total (right-aligned)
-                let original = self.fmt(line_total);
-                let orig_line = right_align(&original, cols);
+                let original = self.fmt_item(line_total);
+                let orig_line = right_align_dir(&original, cols, self.direction);
                 self.push_text_line(&orig_line);
 
                 // Discount
-                let disc_line = format!("  {} -{}", self.labels.item_discount, self.fmt(disc));
+                let disc_line = format!("  {} -{}", self.labels.item_discount, self.fmt_item(disc));
                 self.push_text_line(&disc_line);
 
                 // Final price (bold, right-aligned)
                 let after = line_total - disc;
-                let after_str = self.fmt(after);
+                let after_str = self.fmt_item(after);
                 self = self.bold(true);
-                let final_line = right_align(&after_str, cols);
+                let final_line = right_align_dir(&after_str, cols, self.direction);
                 self.push_text_line(&final_line);
                 self = self.bold(false);
             }
             _ => {
-                let total_str = self.fmt(line_total);
-                let total_line = right_align(&total_str, cols);
+                let total_str = self.fmt_item(line_total);
+                let total_line = right_align_dir(&total_str, cols, self.direction);
                 self.push_text_line(&total_line);
             }
         }
@@ -434,15 +2041,120 @@ impl ReceiptBuilder {
         self
     }
 
+    /// Print an indented add-on or modifier row, e.g.
+    /// `  + Extra cheese     +500 FCFA`, after an [`item`](Self::item).
+    ///
+    /// The value is signed and right-aligned to the page edge; the name is
+    /// truncated to fit the width left after the indent and the value.
+    pub fn sub_item(mut self, name: &str, price_delta: Option<Decimal>) -> Self {
+        const INDENT: &str = "  ";
+        let width = self.effective_cols().saturating_sub(INDENT.len());
+        let value = match price_delta {
+            Some(d) if d.is_sign_negative() => self.fmt_item(d),
+            Some(d) => format!("+{}", self.fmt_item(d)),
+            None => String::new(),
+        };
+        let max_name = width.saturating_sub(value.chars().count() + 1);
+        let label = truncate(&format!("+ {}", name), max_name);
+        let rows = if value.is_empty() {
+            vec![label]
+        } else {
+            two_col_lines(&label, &value, width, self.direction)
+        };
+        for row in rows {
+            let line = match self.direction {
+                Direction::Ltr => format!("{}{}", INDENT, row),
+                Direction::Rtl => {
+                    format!("{}{}", right_align_dir(&row, width, Direction::Ltr), INDENT)
+                }
+            };
+            self.push_text_line(&line);
+        }
+        self
+    }
+
+    /// Print each of `items` with [`item`](Self::item).
+    ///
+    /// Accepts anything iterable over [`ItemInput`] values or references —
+    /// a `Vec` deserialized from an order, a slice, or an iterator chain.
+    /// Pass the list through [`merge_items`](crate::merge_items) first to
+    /// collapse repeated scans of the same product into one line.
+    pub fn items<I>(self, items: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<ItemInput>,
+    {
+        items.into_iter().fold(self, |b, it| {
+            let it = it.borrow();
+            match it.vat_rate {
+                Some(rate) => b.item_vat(&it.name, it.qty, it.unit_price, it.discount, rate),
+                None => b.item(&it.name, it.qty, it.unit_price, it.discount),
+            }
+        })
+    }
+
+    /// Lay items out two per row across the page, for menus and price lists
+    /// on wide paper. Each cell is `cols / 2 - 1` wide and holds the name
+    /// (prefixed with `2x` when `qty` isn't 1, truncated to fit) and the line
+    /// total after discount. An odd number of items leaves the last right
+    /// cell empty.
+    ///
+    /// ```rust
+    /// use thermoprint::{ItemInput, PrintWidth, ReceiptBuilder};
+    /// use rust_decimal::prelude::*;
+    /// let b = ReceiptBuilder::new(PrintWidth::A4).two_column_items(&[
+    ///     ItemInput::new("Thieboudienne", 1, dec!(3500), None),
+    ///     ItemInput::new("Yassa poulet", 1, dec!(3000), None),
+    /// ]);
+    /// ```
+    pub fn two_column_items(mut self, items: &[ItemInput]) -> Self {
+        let cols = self.effective_cols();
+        let cell_width = (cols / 2).saturating_sub(1);
+        let gap = " ".repeat(cols - 2 * cell_width);
+
+        let cells: Vec<String> = items
+            .iter()
+            .map(|it| {
+                let name = match it.qty {
+                    1 => it.name.clone(),
+                    q => format!("{}x {}", q, it.name),
+                };
+                let total = it.unit_price * Decimal::from(it.qty) - it.discount.unwrap_or_default();
+                let value = self.fmt_item(total);
+                let name_width = cell_width.saturating_sub(value.chars().count() + 1);
+                two_col_dir(
+                    &truncate(&name, name_width),
+                    &value,
+                    cell_width,
+                    self.direction,
+                )
+            })
+            .collect();
+
+        let blank = " ".repeat(cell_width);
+        for pair in cells.chunks(2) {
+            let (first, second) = (&pair[0], pair.get(1));
+            // Right-to-left fills the right-hand cell first
+            let line = match (self.direction, second) {
+                (Direction::Ltr, Some(second)) => format!("{}{}{}", first, gap, second),
+                (Direction::Ltr, None) => first.clone(),
+                (Direction::Rtl, second) => {
+                    format!("{}{}{}", second.unwrap_or(&blank), gap, first)
+                }
+            };
+            self.push_text_line(&line);
+        }
+        self
+    }
+
     /// Print the subtotal HT (excluding tax) line.
     pub fn subtotal_ht(mut self, amount: Decimal) -> Self {
-        let cols = self.cols();
+        let cols = self.effective_cols();
         let label = self.labels.subtotal_ht;
         let value = self.fmt(amount);
-        let row = two_col(label, &value, cols);
-        self.push_text_line(&row);
+        self.push_row(label, &value, cols);
         let ht_label = self.labels.excl_tax_note;
-        let ht_line = right_align(ht_label, cols);
+        let ht_line = right_align_dir(ht_label, cols, self.direction);
         self.push_text_line(&ht_line);
         self
     }
@@ -452,14 +2164,13 @@ impl ReceiptBuilder {
         if amount <= Decimal::zero() {
             return self;
         }
-        let cols = self.cols();
+        let cols = self.effective_cols();
         let label = match coupon_code {
             Some(code) => format!("{} ({})", self.labels.discount, code),
             None => self.labels.discount.to_owned(),
         };
         let value = format!("-{}", self.fmt(amount));
-        let row = two_col(&label, &value, cols);
-        self.push_text_line(&row);
+        self.push_row(&label, &value, cols);
         self
     }
 
@@ -468,7 +2179,7 @@ impl ReceiptBuilder {
     /// Included taxes (e.g. VAT already in price) are labelled `"(incluse)"`.
     /// Non-included taxes are prefixed with `"+"`.
     pub fn taxes(mut self, entries: &[TaxEntry]) -> Self {
-        let cols = self.cols();
+        let cols = self.effective_cols();
 
         // Compute non-included total for the summary line
         let additional: Decimal = entries
@@ -495,44 +2206,122 @@ impl ReceiptBuilder {
             } else {
                 format!("+ {}", self.fmt(entry.amount))
             };
-            let row = two_col(&label, &value, cols);
-            self.push_text_line(&row);
+            self.push_row(&label, &value, cols);
         }
 
         if additional > Decimal::zero() {
             let sep = "-".repeat(cols.saturating_sub(2));
             self.push_text_line(&format!("  {}", sep));
-            let row = two_col(
+            self.push_row(
                 &format!("  {}", self.labels.additional_taxes),
                 &format!("+ {}", self.fmt(additional)),
                 cols,
             );
-            self.push_text_line(&row);
         }
 
         self
     }
 
+    /// Print the VAT breakdown of every item added with
+    /// [`item_vat`](Self::item_vat): VAT and taxable base per rate, then the
+    /// VAT total.
+    ///
+    /// VAT is computed once per rate on the accumulated base and rounded to
+    /// the [`MoneyFormat`] decimals, so per-line rounding cannot drift.
+    /// Prints nothing if no item carried a VAT rate.
+    pub fn auto_vat_summary(mut self) -> Self {
+        if self.vat_bases.is_empty() {
+            return self;
+        }
+        let cols = self.effective_cols();
+        let decimals = self.money.decimals;
+        let rows: Vec<(Decimal, Decimal, Decimal)> = self
+            .vat_bases
+            .iter()
+            .map(|(&rate, &base)| {
+                let vat = (base * rate / Decimal::from(100)).round_dp(decimals);
+                (rate, base, vat)
+            })
+            .collect();
+
+        self.push_text_line(self.labels.tax_details);
+        for &(rate, base, vat) in &rows {
+            let label = format!("  {} {}%", self.labels.vat, rate);
+            self.push_row(&label, &self.fmt(vat), cols);
+            let label = format!("    {}", self.labels.vat_base);
+            self.push_row(&label, &self.fmt(base), cols);
+        }
+
+        let total: Decimal = rows.iter().map(|&(_, _, vat)| vat).sum();
+        let sep = "-".repeat(cols.saturating_sub(2));
+        self.push_text_line(&format!("  {}", sep));
+        self.push_row(&format!("  {}", self.labels.vat), &self.fmt(total), cols);
+        self
+    }
+
     /// Print the grand total line (bold, double height).
     pub fn total(mut self, amount: Decimal) -> Self {
         let cols = self.cols();
         let value = self.fmt(amount);
-        let row = two_col(self.labels.total, &value, cols);
         self = self.bold(true).double_height(true);
-        self.push_text_line(&row);
+        self.push_row(self.labels.total, &value, cols);
         self = self.normal_size().bold(false);
         self
     }
 
+    /// Like [`total`](Self::total), but in `currency` instead of the
+    /// builder's, e.g. a USD equivalent under the local total.
+    ///
+    /// ```rust
+    /// use rust_decimal::Decimal;
+    /// use thermoprint::{ReceiptBuilder, PrintWidth};
+    /// let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+    ///     .total(Decimal::from(62_540))
+    ///     .total_in(Decimal::from(95), "USD")
+    ///     .build();
+    /// let text = String::from_utf8_lossy(&bytes);
+    /// assert!(text.contains("62540 FCFA") && text.contains("95 USD"));
+    /// ```
+    pub fn total_in(self, amount: Decimal, currency: &str) -> Self {
+        self.with_currency(currency, |b| b.total(amount))
+    }
+
+    /// Print `amount` written out in words, as formal invoices require,
+    /// e.g. "Soixante-deux mille cinq cent quarante FCFA". Uses the
+    /// [`language`](Self::language) (French and English; other languages
+    /// fall back to French), the currency and the money format's decimals;
+    /// see [`words::amount_in_words`]. Long amounts wrap onto several lines.
+    ///
+    /// ```rust
+    /// use rust_decimal::Decimal;
+    /// use thermoprint::{ReceiptBuilder, PrintWidth};
+    /// let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+    ///     .total_in_words(Decimal::from(62_540))
+    ///     .build();
+    /// assert_eq!(bytes, b"Soixante-deux mille cinq cent quarante FCFA\n");
+    /// ```
+    pub fn total_in_words(mut self, amount: Decimal) -> Self {
+        let text =
+            words::amount_in_words(amount, self.money.decimals, &self.currency, self.language);
+        let mut chars = text.chars();
+        let text: String = chars
+            .next()
+            .map(|first| first.to_uppercase().chain(chars).collect())
+            .unwrap_or_default();
+        for line in wrap(&text, self.effective_cols()) {
+            self.push_text_line(&line);
+        }
+        self
+    }
+
     /// Print the amount received by the customer.
     pub fn received(mut self, amount: Decimal) -> Self {
         if amount <= Decimal::zero() {
             return self;
         }
-        let cols = self.cols();
+        let cols = self.effective_cols();
         let value = self.fmt(amount);
-        let row = two_col(self.labels.received, &value, cols);
-        self.push_text_line(&row);
+        self.push_row(self.labels.received, &value, cols);
         self
     }
 
@@ -541,28 +2330,435 @@ impl ReceiptBuilder {
         if amount <= Decimal::zero() {
             return self;
         }
-        let cols = self.cols();
-        let value = self.fmt(amount);
-        let row = two_col(self.labels.change, &value, cols);
-        self.push_text_line(&row);
-        self
+        let cols = self.effective_cols();
+        let value = self.fmt(amount);
+        self.push_row(self.labels.change, &value, cols);
+        self
+    }
+
+    /// Start a [`TotalsBlock`]: subtotal, discount, tax and total rows
+    /// laid out together so every label starts, and every amount ends, in
+    /// the same column. Finish with [`TotalsBlock::render`].
+    ///
+    /// ```rust
+    /// use rust_decimal::Decimal;
+    /// use thermoprint::{ReceiptBuilder, PrintWidth, TaxEntry};
+    /// let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+    ///     .totals()
+    ///     .subtotal(Decimal::from(30_000))
+    ///     .tax(&TaxEntry::new("TVA 18%", Decimal::from(5_400), false))
+    ///     .grand_total(Decimal::from(35_400))
+    ///     .render()
+    ///     .build();
+    /// let text = String::from_utf8_lossy(&bytes);
+    /// assert!(text.contains("       SOUS-TOTAL HT  30000 FCFA\n"));
+    /// assert!(text.contains("       TVA 18%       + 5400 FCFA\n"));
+    /// ```
+    pub fn totals(self) -> TotalsBlock {
+        TotalsBlock {
+            builder: self,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Print a "served by" footer line.
+    pub fn served_by(mut self, name: &str) -> Self {
+        self.push_text_line(&format!("{} {}", self.labels.served_by, name));
+        self
+    }
+
+    /// Print a localized date line, e.g. `"Date: 12/06/2024 14:30"`.
+    ///
+    /// The value is printed as given, so the caller controls both the time
+    /// source and its format.
+    pub fn datetime_str(mut self, value: &str) -> Self {
+        self.push_text_line(&format!("{} {}", self.labels.date, value));
+        self
+    }
+
+    /// Print a block of fiscal identifiers (e.g. NINEA, RCCM) under a
+    /// centred "legal information" header, one `label .... value` row each.
+    ///
+    /// ```rust
+    /// use thermoprint::{ReceiptBuilder, PrintWidth};
+    /// let b = ReceiptBuilder::new(PrintWidth::Mm80)
+    ///     .fiscal_footer(&[("NINEA", "005123456 2G3"), ("RCCM", "SN-DKR-2024-B-123")]);
+    /// ```
+    pub fn fiscal_footer(mut self, fields: &[(&str, &str)]) -> Self {
+        let cols = self.effective_cols();
+        let header = self.labels.legal_info;
+        self = self
+            .align_center()
+            .bold(true)
+            .text_line(header)
+            .bold(false)
+            .align_start();
+        for (label, value) in fields {
+            self.push_row(label, value, cols);
+        }
+        self
+    }
+
+    /// Print an order metadata block (order number, date, cashier, payment
+    /// method, ...) as `label .... value` rows.
+    ///
+    /// When a row doesn't fit, the label is shortened with `"..."` as long as
+    /// at least 4 of its characters stay visible; otherwise the label gets its
+    /// own line and the value is wrapped and aligned to the line end below it.
+    ///
+    /// ```rust
+    /// use thermoprint::{ReceiptBuilder, PrintWidth};
+    /// let b = ReceiptBuilder::new(PrintWidth::Mm58)
+    ///     .meta(&[("Commande", "#1042"), ("Caissier", "Awa"), ("Paiement", "Wave")]);
+    /// ```
+    pub fn meta(mut self, rows: &[(&str, &str)]) -> Self {
+        let cols = self.effective_cols();
+        for (label, value) in rows {
+            let l = label.chars().count();
+            let r = value.chars().count();
+            if l + 1 + r <= cols {
+                self.push_row(label, value, cols);
+                continue;
+            }
+            let room = cols.saturating_sub(r + 1);
+            if room >= 7 {
+                let label = truncate(label, room);
+                self.push_row(&label, value, cols);
+                continue;
+            }
+            self.push_text_line(&truncate(label, cols));
+            for line in wrap(value, cols) {
+                self.push_text_line(&right_align_dir(&line, cols, self.direction));
+            }
+        }
+        self
+    }
+
+    /// Print a tip line for card receipts, e.g. `POURBOIRE: ________`,
+    /// padded with underscores to the full column width.
+    pub fn tip_line(mut self) -> Self {
+        let cols = self.effective_cols();
+        let label = self.labels.tip;
+        let fill = cols.saturating_sub(label.chars().count() + 1);
+        self.push_text_line(&format!("{} {}", label, "_".repeat(fill)));
+        self
+    }
+
+    /// Print a signature block for card receipts: the label, a blank line
+    /// to sign in, then a full-width underscore line.
+    pub fn signature_line(self) -> Self {
+        let label = self.labels.signature;
+        self.text_line(label).blank().divider('_')
+    }
+
+    /// Print a thank-you footer centred on the page.
+    pub fn thank_you(self, shop_name: &str) -> Self {
+        let ty = self.labels.thank_you;
+        let see_you = format!("{} {}", self.labels.see_you_at, shop_name);
+        self.align_center()
+            .text_line(ty)
+            .text_line(&see_you)
+            .align_start()
+    }
+
+    // ── Kitchen tickets ───────────────────────────────────────────────────────
+
+    /// Print a kitchen ticket header: the table in double size, then the
+    /// order number and time on one line, followed by a divider.
+    ///
+    /// Kitchen tickets carry no prices — combine with
+    /// [`kitchen_item`](Self::kitchen_item) instead of [`item`](Self::item).
+    pub fn order_header(mut self, table: &str, order_no: &str, time: &str) -> Self {
+        let cols = self.cols();
+        let table_line = format!("{} {}", self.labels.table, table);
+        self = self.align_center().bold(true).double_size(true);
+        self.push_text_line(&truncate(&table_line, self.effective_cols()));
+        self = self.double_size(false).bold(false).align_start();
+        let order = format!("{} #{}", self.labels.order, order_no);
+        self.push_row(&order, time, cols);
+        self.divider('=')
+    }
+
+    /// Print a kitchen ticket line: `qty x name` in double size, then each
+    /// modifier indented underneath in normal size.
+    pub fn kitchen_item(mut self, name: &str, qty: i32, modifiers: &[&str]) -> Self {
+        let cols = self.cols();
+        let line = format!("{} x {}", qty, name);
+        self = self.bold(true).double_size(true);
+        self.push_text_line(&truncate(&line, self.effective_cols()));
+        self = self.double_size(false).bold(false);
+        for m in modifiers {
+            self.push_text_line(&truncate(&format!("   - {}", m), cols));
+        }
+        self
+    }
+}
+
+/// Totals rows collected by [`ReceiptBuilder::totals`] and printed as one
+/// right-aligned block by [`render`](Self::render).
+///
+/// Labels share a column as wide as the longest label and amounts share
+/// a column as wide as the longest amount, so rows line up however their
+/// labels differ. The block sits against the right edge of the paper (the
+/// left edge with [`Direction::Rtl`]); labels too long to fit are
+/// truncated.
+pub struct TotalsBlock {
+    builder: ReceiptBuilder,
+    rows: Vec<TotalsRow>,
+}
+
+struct TotalsRow {
+    label: String,
+    value: String,
+    /// Printed bold and double height, like [`ReceiptBuilder::total`].
+    grand: bool,
+}
+
+impl TotalsBlock {
+    fn row(mut self, label: String, value: String, grand: bool) -> Self {
+        self.rows.push(TotalsRow {
+            label,
+            value,
+            grand,
+        });
+        self
+    }
+
+    /// Add the subtotal HT (excluding tax) row.
+    pub fn subtotal(self, amount: Decimal) -> Self {
+        let label = self.builder.labels.subtotal_ht.to_owned();
+        let value = self.builder.fmt(amount);
+        self.row(label, value, false)
+    }
+
+    /// Add a discount row, shown as a negative amount. Skipped if `amount`
+    /// is not positive.
+    pub fn discount(self, amount: Decimal) -> Self {
+        if amount <= Decimal::zero() {
+            return self;
+        }
+        let label = self.builder.labels.discount.to_owned();
+        let value = format!("-{}", self.builder.fmt(amount));
+        self.row(label, value, false)
+    }
+
+    /// Add a tax row, marked as in [`ReceiptBuilder::taxes`]: included
+    /// taxes get the localized "included" note, others a `+`. Skipped if
+    /// the amount is not positive.
+    pub fn tax(self, entry: &TaxEntry) -> Self {
+        if entry.amount <= Decimal::zero() {
+            return self;
+        }
+        let (label, value) = if entry.included {
+            (
+                format!("{} ({})", entry.label, self.builder.labels.tax_included),
+                self.builder.fmt(entry.amount),
+            )
+        } else {
+            (
+                entry.label.clone(),
+                format!("+ {}", self.builder.fmt(entry.amount)),
+            )
+        };
+        self.row(label, value, false)
+    }
+
+    /// Add a row with a custom label, e.g. a delivery fee.
+    pub fn line(self, label: &str, amount: Decimal) -> Self {
+        let value = self.builder.fmt(amount);
+        self.row(label.to_owned(), value, false)
+    }
+
+    /// Add the grand total row, printed bold and double height.
+    pub fn grand_total(self, amount: Decimal) -> Self {
+        let label = self.builder.labels.total.to_owned();
+        let value = self.builder.fmt(amount);
+        self.row(label, value, true)
+    }
+
+    /// Print the collected rows and return the builder.
+    pub fn render(self) -> ReceiptBuilder {
+        let mut b = self.builder;
+        let cols = b.effective_cols();
+        let value_w = self
+            .rows
+            .iter()
+            .map(|r| r.value.chars().count())
+            .max()
+            .unwrap_or(0)
+            .min(cols);
+        let label_w = self
+            .rows
+            .iter()
+            .map(|r| r.label.chars().count())
+            .max()
+            .unwrap_or(0)
+            .min(cols.saturating_sub(value_w + 1));
+        let pad = " ".repeat(cols.saturating_sub(label_w + 1 + value_w));
+
+        for row in &self.rows {
+            let label = truncate(&row.label, label_w);
+            let value = &row.value;
+            // Amounts are never cut: without room for a useful label beside
+            // them, fall back to a label line and a value line
+            let lines = if label_w < row.label.chars().count().min(4) {
+                two_col_lines(&row.label, value, cols, b.direction)
+            } else {
+                vec![match b.direction {
+                    Direction::Ltr => format!(
+                        "{}{:<lw$} {:>vw$}",
+                        pad,
+                        label,
+                        value,
+                        lw = label_w,
+                        vw = value_w
+                    ),
+                    Direction::Rtl => format!(
+                        "{:>vw$} {:>lw$}{}",
+                        value,
+                        label,
+                        pad,
+                        lw = label_w,
+                        vw = value_w
+                    ),
+                }]
+            };
+            if row.grand {
+                b = b.bold(true).double_height(true);
+            }
+            for line in &lines {
+                b.push_text_line(line);
+            }
+            if row.grand {
+                b = b.normal_size().bold(false);
+            }
+        }
+        b
     }
+}
 
-    /// Print a "served by" footer line.
-    pub fn served_by(mut self, name: &str) -> Self {
-        self.push_text_line(&format!("{} {}", self.labels.served_by, name));
-        self
+/// Concatenate `n` copies of a finished receipt, e.g. to print a merchant
+/// and a customer copy from bytes built elsewhere.
+///
+/// With `cut_between`, a partial cut (`GS V 66 0`) separates the copies
+/// unless `bytes` already ends with an Epson cut. `n == 0` returns no bytes.
+///
+/// ```rust
+/// use thermoprint::repeat_receipt;
+///
+/// let twice = repeat_receipt(b"TOTAL 5000\n", 2, true);
+/// assert_eq!(twice, b"TOTAL 5000\n\x1DVB\0TOTAL 5000\n");
+/// ```
+pub fn repeat_receipt(bytes: &[u8], n: u8, cut_between: bool) -> Vec<u8> {
+    let cut_between = cut_between && !Dialect::Epson.ends_with_cut(bytes);
+    let sep = if cut_between {
+        commands::cut_partial()
+    } else {
+        &[]
+    };
+    repeat_copies(bytes, n, sep)
+}
+
+/// A standalone reset sequence for recovering a printer left in a bad state
+/// (double-size text, bold, centred) by an interrupted or malformed print.
+///
+/// `ESC @`, code page 858, then normal size, bold off and left alignment
+/// spelled out for clones whose `ESC @` doesn't clear them. Prints nothing.
+///
+/// ```rust
+/// let bytes = thermoprint::reset_bytes();
+/// assert!(bytes.starts_with(&[0x1B, b'@']));
+/// ```
+pub fn reset_bytes() -> Vec<u8> {
+    [
+        commands::init(),
+        commands::code_page_858(),
+        commands::normal_size(),
+        commands::bold_off(),
+        commands::align_left(),
+    ]
+    .concat()
+}
+
+/// Split a finished receipt into pages of at most `lines_per_page` lines,
+/// for very long orders on printers whose buffer or cutter can't handle one
+/// continuous print.
+///
+/// Lines are counted from the `line_ending` the receipt was built with (see
+/// [`ReceiptBuilder::line_ending`]) and `ESC d` feeds; payloads such as
+/// images and barcodes are skipped, so bytes that happen to equal `LF` or
+/// `CR` inside them don't count. At each boundary a `continued_label` footer
+/// line, a partial cut (`GS V 66 0`) and a `continued_label` header line are
+/// inserted, the labels ending in `line_ending`; they don't count toward the
+/// next page. Cuts already in `bytes`
+/// start a new page, and no boundary is added when only feeds and cuts
+/// remain. `lines_per_page == 0` returns `bytes` unchanged. Expects Epson
+/// byte streams, like [`repeat_receipt`].
+///
+/// ```rust
+/// use thermoprint::{paginate, LineEnding};
+///
+/// let pages = paginate(b"A\nB\nC\n", 2, "Suite...", LineEnding::Lf);
+/// assert_eq!(pages, b"A\nB\nSuite...\n\x1DVB\0Suite...\nC\n");
+/// ```
+pub fn paginate(
+    bytes: &[u8],
+    lines_per_page: usize,
+    continued_label: &str,
+    line_ending: LineEnding,
+) -> Vec<u8> {
+    if lines_per_page == 0 {
+        return bytes.to_vec();
+    }
+    let mut label = encode_cp858(continued_label);
+    label.extend_from_slice(line_ending.bytes());
+    // The token that completes a line: the LF of `CR LF`, or a lone CR
+    let line_end = match line_ending {
+        LineEnding::Lf | LineEnding::CrLf => Token::LineFeed,
+        LineEnding::Cr => Token::Control(commands::CR),
+    };
+
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut lines = 0;
+    let mut start = 0;
+    for (token, end) in crate::debug::tokenize_spans(bytes) {
+        let is_cut = token.is_command("GS V");
+        let is_feed = matches!(token, Token::LineFeed | Token::FormFeed)
+            || token == Token::Control(commands::CR)
+            || token.is_command("ESC d")
+            || token.is_command("ESC J");
+        if is_cut {
+            lines = 0;
+        } else if lines >= lines_per_page && !is_feed {
+            // Something still prints after a full page: break here
+            out.extend_from_slice(&label);
+            out.extend_from_slice(commands::cut_partial());
+            out.extend_from_slice(&label);
+            lines = 0;
+        }
+        match &token {
+            t if *t == line_end => lines += 1,
+            Token::Command { name, params } if name == "ESC d" => {
+                lines += params.first().copied().unwrap_or(0) as usize;
+            }
+            _ => {}
+        }
+        out.extend_from_slice(&bytes[start..end]);
+        start = end;
     }
+    out
+}
 
-    /// Print a thank-you footer centred on the page.
-    pub fn thank_you(self, shop_name: &str) -> Self {
-        let ty = self.labels.thank_you;
-        let see_you = format!("{} {}", self.labels.see_you_at, shop_name);
-        self.align_center()
-            .text_line(ty)
-            .text_line(&see_you)
-            .align_left()
+/// Join `n` copies of `bytes` with `sep` between them.
+fn repeat_copies(bytes: &[u8], n: u8, sep: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity((bytes.len() + sep.len()) * n as usize);
+    for i in 0..n {
+        if i > 0 {
+            out.extend_from_slice(sep);
+        }
+        out.extend_from_slice(bytes);
     }
+    out
 }
 
 // ── WASM wrapper ──────────────────────────────────────────────────────────────
@@ -588,6 +2784,41 @@ pub mod wasm {
         })
     }
 
+    fn parse_line_ending(s: &str) -> Result<LineEnding, JsValue> {
+        match s.to_lowercase().as_str() {
+            "lf" => Ok(LineEnding::Lf),
+            "crlf" => Ok(LineEnding::CrLf),
+            "cr" => Ok(LineEnding::Cr),
+            other => Err(JsValue::from_str(&format!(
+                "thermoprint: unknown line ending '{}'. Use 'lf', 'crlf' or 'cr'",
+                other
+            ))),
+        }
+    }
+
+    fn parse_symbology(s: &str) -> Result<Symbology, JsValue> {
+        match s {
+            "code128" => Ok(Symbology::Code128),
+            "ean13" => Ok(Symbology::Ean13),
+            "ean8" => Ok(Symbology::Ean8),
+            other => Err(JsValue::from_str(&format!(
+                "thermoprint: unknown symbology '{}'. Use 'code128', 'ean13' or 'ean8'",
+                other
+            ))),
+        }
+    }
+
+    fn parse_font(s: &str) -> Result<HriFont, JsValue> {
+        match s.to_lowercase().as_str() {
+            "a" => Ok(HriFont::A),
+            "b" => Ok(HriFont::B),
+            other => Err(JsValue::from_str(&format!(
+                "thermoprint: unknown HRI font '{}'. Use 'a' or 'b'",
+                other
+            ))),
+        }
+    }
+
     #[wasm_bindgen]
     pub struct WasmReceiptBuilder {
         inner: ReceiptBuilder,
@@ -615,6 +2846,14 @@ pub mod wasm {
             })
         }
 
+        /// A ready-to-print self-test page; see [`ReceiptBuilder::test_page`].
+        pub fn test_page(width: &str) -> Result<WasmReceiptBuilder, JsValue> {
+            let b = WasmReceiptBuilder::new(width)?;
+            Ok(WasmReceiptBuilder {
+                inner: ReceiptBuilder::test_page(b.inner.width),
+            })
+        }
+
         /// Set currency symbol (default: `"FCFA"`).
         pub fn currency(self, symbol: &str) -> WasmReceiptBuilder {
             WasmReceiptBuilder {
@@ -622,6 +2861,37 @@ pub mod wasm {
             }
         }
 
+        /// Set money formatting, e.g. `money_format(2, " ", ",", false)` for
+        /// `"1 149,99 €"`. An empty `thousands_sep` disables grouping;
+        /// `decimal_sep` must be a single character.
+        pub fn money_format(
+            self,
+            decimals: u32,
+            thousands_sep: &str,
+            decimal_sep: &str,
+            symbol_before: bool,
+        ) -> Result<WasmReceiptBuilder, JsValue> {
+            let mut dec_chars = decimal_sep.chars();
+            let dec = match (dec_chars.next(), dec_chars.next()) {
+                (Some(c), None) => c,
+                _ => {
+                    return Err(JsValue::from_str(&format!(
+                        "thermoprint: invalid decimal separator '{}'",
+                        decimal_sep
+                    )))
+                }
+            };
+            let format = crate::types::MoneyFormat::new(
+                decimals,
+                thousands_sep.chars().next(),
+                dec,
+                symbol_before,
+            );
+            Ok(WasmReceiptBuilder {
+                inner: self.inner.money_format(format),
+            })
+        }
+
         /// Set receipt language: `"fr"`, `"en"`, `"es"`, `"pt"`, `"ar"`, `"wo"`.
         pub fn language(self, lang: &str) -> Result<WasmReceiptBuilder, JsValue> {
             let l = match lang.to_lowercase().as_str() {
@@ -643,11 +2913,61 @@ pub mod wasm {
             })
         }
 
+        /// Select the printer command set: `"epson"` or `"star"`.
+        pub fn dialect(self, dialect: &str) -> Result<WasmReceiptBuilder, JsValue> {
+            let d = match dialect.to_lowercase().as_str() {
+                "epson" => crate::dialect::Dialect::Epson,
+                "star" => crate::dialect::Dialect::Star,
+                other => {
+                    return Err(JsValue::from_str(&format!(
+                        "thermoprint: unknown dialect '{}'. Use 'epson' or 'star'",
+                        other
+                    )))
+                }
+            };
+            Ok(WasmReceiptBuilder {
+                inner: self.inner.dialect(d),
+            })
+        }
+
+        /// Set layout direction: `"ltr"` or `"rtl"`.
+        pub fn direction(self, dir: &str) -> Result<WasmReceiptBuilder, JsValue> {
+            let d = match dir.to_lowercase().as_str() {
+                "ltr" => crate::types::Direction::Ltr,
+                "rtl" => crate::types::Direction::Rtl,
+                other => {
+                    return Err(JsValue::from_str(&format!(
+                        "thermoprint: unknown direction '{}'. Use 'ltr' or 'rtl'",
+                        other
+                    )))
+                }
+            };
+            Ok(WasmReceiptBuilder {
+                inner: self.inner.direction(d),
+            })
+        }
+
         pub fn init(self) -> WasmReceiptBuilder {
             WasmReceiptBuilder {
                 inner: self.inner.init(),
             }
         }
+        pub fn init_once(self) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.init_once(),
+            }
+        }
+        pub fn init_with(self, resets: u8) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.init_with(resets),
+            }
+        }
+        pub fn intl_charset(self, n: u8) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.intl_charset(n),
+            }
+        }
+
         pub fn blank(self) -> WasmReceiptBuilder {
             WasmReceiptBuilder {
                 inner: self.inner.blank(),
@@ -668,6 +2988,31 @@ pub mod wasm {
                 inner: self.inner.align_right(),
             }
         }
+        pub fn left_margin(self, dots: u16) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.left_margin(dots),
+            }
+        }
+        pub fn content_width(self, cols: usize) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.content_width(cols),
+            }
+        }
+        pub fn at_position(self, dots: u16) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.at_position(dots),
+            }
+        }
+        pub fn indent(self, n: usize) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.indent(n),
+            }
+        }
+        pub fn dedent(self) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.dedent(),
+            }
+        }
         pub fn bold(self, on: bool) -> WasmReceiptBuilder {
             WasmReceiptBuilder {
                 inner: self.inner.bold(on),
@@ -693,6 +3038,33 @@ pub mod wasm {
                 inner: self.inner.underline(on),
             }
         }
+        /// Underline thickness: `"none"`, `"single"` or `"double"`.
+        pub fn underline_style(self, style: &str) -> Result<WasmReceiptBuilder, JsValue> {
+            let s = match style.to_lowercase().as_str() {
+                "none" => UnderlineStyle::None,
+                "single" => UnderlineStyle::Single,
+                "double" => UnderlineStyle::Double,
+                other => {
+                    return Err(JsValue::from_str(&format!(
+                        "thermoprint: unknown underline '{}'. Use 'none', 'single' or 'double'",
+                        other
+                    )))
+                }
+            };
+            Ok(WasmReceiptBuilder {
+                inner: self.inner.underline(s),
+            })
+        }
+        pub fn density(self, level: i8) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.density(level),
+            }
+        }
+        pub fn print_speed(self, level: u8) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.print_speed(level),
+            }
+        }
         pub fn text(self, s: &str) -> WasmReceiptBuilder {
             WasmReceiptBuilder {
                 inner: self.inner.text(s),
@@ -718,61 +3090,368 @@ pub mod wasm {
                 inner: self.inner.row(left, right),
             }
         }
+        pub fn paragraph(self, text: &str) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.paragraph(text),
+            }
+        }
+        pub fn boxed(self, lines: Vec<String>) -> WasmReceiptBuilder {
+            let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+            WasmReceiptBuilder {
+                inner: self.inner.boxed(&lines),
+            }
+        }
         pub fn divider(self, ch: &str) -> WasmReceiptBuilder {
             let c = ch.chars().next().unwrap_or('-');
             WasmReceiptBuilder {
                 inner: self.inner.divider(c),
             }
         }
+        pub fn rating(self, stars: u8, out_of: u8) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.rating(stars, out_of),
+            }
+        }
+        /// `value` and `max`: decimal strings, e.g. `"45"` and `"100"`.
+        pub fn bar(
+            self,
+            value: &str,
+            max: &str,
+            width: usize,
+        ) -> Result<WasmReceiptBuilder, JsValue> {
+            Ok(WasmReceiptBuilder {
+                inner: self
+                    .inner
+                    .bar(parse_decimal(value)?, parse_decimal(max)?, width),
+            })
+        }
+        pub fn section(self, title: &str, ch: &str) -> WasmReceiptBuilder {
+            let c = ch.chars().next().unwrap_or('-');
+            WasmReceiptBuilder {
+                inner: self.inner.section(title, c),
+            }
+        }
+        /// `widths`: segment widths, e.g. `new Uint32Array([10, 20, 16])`.
+        pub fn column_divider(
+            self,
+            widths: &[usize],
+            ch: &str,
+            junction: &str,
+        ) -> WasmReceiptBuilder {
+            let c = ch.chars().next().unwrap_or('-');
+            let j = junction.chars().next().unwrap_or('+');
+            WasmReceiptBuilder {
+                inner: self.inner.column_divider(widths, c, j),
+            }
+        }
+        pub fn divider_width(self, ch: &str, cols: usize) -> WasmReceiptBuilder {
+            let c = ch.chars().next().unwrap_or('-');
+            WasmReceiptBuilder {
+                inner: self.inner.divider_width(c, cols),
+            }
+        }
         pub fn feed(self, n: u8) -> WasmReceiptBuilder {
             WasmReceiptBuilder {
                 inner: self.inner.feed(n),
             }
         }
+        /// Where the currency symbol is printed: `"always"` (default) or
+        /// `"totals_only"` to leave it off item lines.
+        pub fn currency_on(self, placement: &str) -> Result<WasmReceiptBuilder, JsValue> {
+            let p = match placement.to_lowercase().as_str() {
+                "always" => CurrencyPlacement::Always,
+                "totals_only" => CurrencyPlacement::TotalsOnly,
+                other => {
+                    return Err(JsValue::from_str(&format!(
+                    "thermoprint: unknown currency placement '{}'. Use 'always' or 'totals_only'",
+                    other
+                )))
+                }
+            };
+            Ok(WasmReceiptBuilder {
+                inner: self.inner.currency_on(p),
+            })
+        }
+        pub fn feed_dots(self, n: u8) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.feed_dots(n),
+            }
+        }
+        pub fn cut_feed(self, lines: u8) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.cut_feed(lines),
+            }
+        }
         pub fn cut(self) -> WasmReceiptBuilder {
             WasmReceiptBuilder {
                 inner: self.inner.cut(),
             }
         }
+        pub fn feed_and_cut(self, lines: u8) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.feed_and_cut(lines),
+            }
+        }
+        pub fn cut_with_feed(self, units: u8) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.cut_with_feed(units),
+            }
+        }
         pub fn cut_full(self) -> WasmReceiptBuilder {
             WasmReceiptBuilder {
                 inner: self.inner.cut_full(),
             }
         }
+        pub fn cut_full_with_feed(self, units: u8) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.cut_full_with_feed(units),
+            }
+        }
         pub fn form_feed(self) -> WasmReceiptBuilder {
             WasmReceiptBuilder {
                 inner: self.inner.form_feed(),
             }
         }
-        pub fn open_cash_drawer(self) -> WasmReceiptBuilder {
+        pub fn open_cash_drawer(self) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.open_cash_drawer(),
+            }
+        }
+        pub fn open_cash_drawer_custom(
+            self,
+            pin: u8,
+            on_time: u8,
+            off_time: u8,
+        ) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.open_cash_drawer_custom(pin, on_time, off_time),
+            }
+        }
+
+        pub fn code_line_feed(self, on: bool) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.code_line_feed(on),
+            }
+        }
+
+        /// Set barcode HRI text position (`"none"`, `"above"`, `"below"`,
+        /// `"both"`) and font (`"a"` or `"b"`).
+        pub fn barcode_hri(
+            self,
+            position: &str,
+            font: &str,
+        ) -> Result<WasmReceiptBuilder, JsValue> {
+            let pos = match position.to_lowercase().as_str() {
+                "none" => HriPosition::None,
+                "above" => HriPosition::Above,
+                "below" => HriPosition::Below,
+                "both" => HriPosition::Both,
+                other => {
+                    return Err(JsValue::from_str(&format!(
+                        "thermoprint: unknown HRI position '{}'. Use 'none', 'above', 'below', or 'both'",
+                        other
+                    )))
+                }
+            };
+            Ok(WasmReceiptBuilder {
+                inner: self.inner.barcode_hri(pos, parse_font(font)?),
+            })
+        }
+        pub fn barcode_code128(self, value: &str) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.barcode_code128(value),
+            }
+        }
+        pub fn barcode_ean13(self, value: &str) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.barcode_ean13(value),
+            }
+        }
+        /// `price` is a decimal string, e.g. `"12.34"`.
+        pub fn barcode_price_ean13(
+            self,
+            item_code: &str,
+            price: &str,
+        ) -> Result<WasmReceiptBuilder, JsValue> {
+            let inner = self
+                .inner
+                .barcode_price_ean13(item_code, parse_decimal(price)?)
+                .map_err(|e| JsValue::from_str(&format!("thermoprint: {}", e)))?;
+            Ok(WasmReceiptBuilder { inner })
+        }
+        pub fn barcode_ean8(self, value: &str) -> Result<WasmReceiptBuilder, JsValue> {
+            let inner = self
+                .inner
+                .barcode_ean8(value)
+                .map_err(|e| JsValue::from_str(&format!("thermoprint: {}", e)))?;
+            Ok(WasmReceiptBuilder { inner })
+        }
+        /// `symbology`: `"code128"`, `"ean13"` or `"ean8"`.
+        pub fn labeled_barcode(
+            self,
+            label: &str,
+            value: &str,
+            symbology: &str,
+        ) -> Result<WasmReceiptBuilder, JsValue> {
+            let inner = self
+                .inner
+                .labeled_barcode(label, value, parse_symbology(symbology)?)
+                .map_err(|e| JsValue::from_str(&format!("thermoprint: {}", e)))?;
+            Ok(WasmReceiptBuilder { inner })
+        }
+        /// Barcode with explicit module width and height in dots.
+        /// `symbology`: `"code128"`, `"ean13"` or `"ean8"`.
+        pub fn barcode(
+            self,
+            symbology: &str,
+            value: &str,
+            bar_width: u8,
+            bar_height: u8,
+        ) -> Result<WasmReceiptBuilder, JsValue> {
+            let inner = self
+                .inner
+                .barcode(parse_symbology(symbology)?, value, bar_width, bar_height)
+                .map_err(|e| JsValue::from_str(&format!("thermoprint: {}", e)))?;
+            Ok(WasmReceiptBuilder { inner })
+        }
+        /// Barcode without printer HRI, then the value as centred text.
+        /// `symbology`: `"code128"`, `"ean13"` or `"ean8"`; `font`: `"a"` or `"b"`.
+        pub fn barcode_with_text(
+            self,
+            value: &str,
+            symbology: &str,
+            font: &str,
+        ) -> Result<WasmReceiptBuilder, JsValue> {
+            let inner = self
+                .inner
+                .barcode_with_text(value, parse_symbology(symbology)?, parse_font(font)?)
+                .map_err(|e| JsValue::from_str(&format!("thermoprint: {}", e)))?;
+            Ok(WasmReceiptBuilder { inner })
+        }
+        pub fn auto_barcode(self, value: &str) -> Result<WasmReceiptBuilder, JsValue> {
+            let inner = self
+                .inner
+                .auto_barcode(value)
+                .map_err(|e| JsValue::from_str(&format!("thermoprint: {}", e)))?;
+            Ok(WasmReceiptBuilder { inner })
+        }
+        pub fn order_reference(
+            self,
+            number: &str,
+            check_digit: bool,
+        ) -> Result<WasmReceiptBuilder, JsValue> {
+            let inner = self
+                .inner
+                .order_reference(number, check_digit)
+                .map_err(|e| JsValue::from_str(&format!("thermoprint: {}", e)))?;
+            Ok(WasmReceiptBuilder { inner })
+        }
+        pub fn qr_code(self, data: &str, size: u8) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.qr_code(data, size),
+            }
+        }
+
+        /// QR code from a `Uint8Array` payload, stored byte for byte.
+        pub fn qr_code_bytes(self, data: &[u8], size: u8) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.qr_code_bytes(data, size),
+            }
+        }
+
+        pub fn data_matrix(self, data: &str, size: u8) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.data_matrix(data, size),
+            }
+        }
+
+        /// Like `qr_code`, but throws if `data` is empty or too long for a QR code.
+        pub fn qr_code_checked(self, data: &str, size: u8) -> Result<WasmReceiptBuilder, JsValue> {
+            let inner = self
+                .inner
+                .qr_code_checked(data, size)
+                .map_err(|e| JsValue::from_str(&format!("thermoprint: {}", e)))?;
+            Ok(WasmReceiptBuilder { inner })
+        }
+
+        pub fn qr_with_caption(self, data: &str, size: u8, caption: &str) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.qr_with_caption(data, size, caption),
+            }
+        }
+        /// `amount` is a decimal string, e.g. `"62540"`.
+        pub fn payment_qr(
+            self,
+            base_url: &str,
+            amount: &str,
+            reference: &str,
+        ) -> Result<WasmReceiptBuilder, JsValue> {
+            Ok(WasmReceiptBuilder {
+                inner: self
+                    .inner
+                    .payment_qr(base_url, parse_decimal(amount)?, reference),
+            })
+        }
+        pub fn qr_vcard(self, name: &str, phone: &str, email: &str) -> WasmReceiptBuilder {
             WasmReceiptBuilder {
-                inner: self.inner.open_cash_drawer(),
+                inner: self.inner.qr_vcard(name, phone, email),
             }
         }
 
-        pub fn barcode_code128(self, value: &str) -> WasmReceiptBuilder {
-            WasmReceiptBuilder {
-                inner: self.inner.barcode_code128(value),
-            }
+        /// Print a Wi-Fi join QR code. `encryption`: `"wpa"`, `"wep"`, or `"nopass"`.
+        pub fn qr_wifi(
+            self,
+            ssid: &str,
+            password: &str,
+            encryption: &str,
+        ) -> Result<WasmReceiptBuilder, JsValue> {
+            let enc = match encryption.to_lowercase().as_str() {
+                "wpa" | "wpa2" => crate::qr::WifiEncryption::Wpa,
+                "wep" => crate::qr::WifiEncryption::Wep,
+                "nopass" | "none" | "" => crate::qr::WifiEncryption::None,
+                other => {
+                    return Err(JsValue::from_str(&format!(
+                        "thermoprint: unknown Wi-Fi encryption '{}'. Use 'wpa', 'wep', or 'nopass'",
+                        other
+                    )))
+                }
+            };
+            Ok(WasmReceiptBuilder {
+                inner: self.inner.qr_wifi(ssid, password, enc),
+            })
         }
-        pub fn barcode_ean13(self, value: &str) -> WasmReceiptBuilder {
+
+        /// Append pre-rasterised logo bytes (pass a `Uint8Array` from your own image pipeline).
+        pub fn logo_raw(self, bytes: &[u8]) -> WasmReceiptBuilder {
             WasmReceiptBuilder {
-                inner: self.inner.barcode_ean13(value),
+                inner: self.inner.logo_raw(bytes),
             }
         }
-        pub fn qr_code(self, data: &str, size: u8) -> WasmReceiptBuilder {
+
+        /// Append pre-rasterised logo bytes, centred on the page.
+        pub fn logo_raw_centered(self, bytes: &[u8]) -> WasmReceiptBuilder {
             WasmReceiptBuilder {
-                inner: self.inner.qr_code(data, size),
+                inner: self.inner.logo_raw_centered(bytes),
             }
         }
 
-        /// Append pre-rasterised logo bytes (pass a `Uint8Array` from your own image pipeline).
-        pub fn logo_raw(self, bytes: &[u8]) -> WasmReceiptBuilder {
+        pub fn nv_logo(self, n: u8, mode: u8) -> WasmReceiptBuilder {
             WasmReceiptBuilder {
-                inner: self.inner.logo_raw(bytes),
+                inner: self.inner.nv_logo(n, mode),
             }
         }
-
+        /// Print NV graphics stored under a two-character key, e.g. `"LG"`.
+        pub fn nv_graphic(self, key: &str) -> Result<WasmReceiptBuilder, JsValue> {
+            let k: [u8; 2] = key.as_bytes().try_into().map_err(|_| {
+                JsValue::from_str(&format!(
+                    "thermoprint: NV graphic key '{}' must be 2 ASCII characters",
+                    key
+                ))
+            })?;
+            Ok(WasmReceiptBuilder {
+                inner: self.inner.nv_graphic(k),
+            })
+        }
         pub fn shop_header(self, name: &str, phone: &str, address: &str) -> WasmReceiptBuilder {
             WasmReceiptBuilder {
                 inner: self.inner.shop_header(name, phone, address),
@@ -794,6 +3473,105 @@ pub mod wasm {
             })
         }
 
+        /// Line item with amounts in a one-off `currency`.
+        pub fn item_in(
+            self,
+            name: &str,
+            qty: i32,
+            unit_price: &str,
+            discount: Option<String>,
+            currency: &str,
+        ) -> Result<WasmReceiptBuilder, JsValue> {
+            let price = parse_decimal(unit_price)?;
+            let disc = discount.as_deref().map(parse_decimal).transpose()?;
+            Ok(WasmReceiptBuilder {
+                inner: self.inner.item_in(name, qty, price, disc, currency),
+            })
+        }
+
+        /// Print a line item on one line, e.g. `Polo shirt   2x15000=30000`.
+        pub fn item_compact(
+            self,
+            name: &str,
+            qty: i32,
+            unit_price: &str,
+            discount: Option<String>,
+        ) -> Result<WasmReceiptBuilder, JsValue> {
+            let price = parse_decimal(unit_price)?;
+            let disc = discount.as_deref().map(parse_decimal).transpose()?;
+            Ok(WasmReceiptBuilder {
+                inner: self.inner.item_compact(name, qty, price, disc),
+            })
+        }
+
+        /// Line ending: `"lf"` (default), `"crlf"` or `"cr"`.
+        pub fn line_ending(self, ending: &str) -> Result<WasmReceiptBuilder, JsValue> {
+            let e = parse_line_ending(ending)?;
+            Ok(WasmReceiptBuilder {
+                inner: self.inner.line_ending(e),
+            })
+        }
+
+        /// Layout of following items: `"detailed"` (default) or `"compact"`.
+        pub fn item_style(self, style: &str) -> Result<WasmReceiptBuilder, JsValue> {
+            let s = match style.to_lowercase().as_str() {
+                "detailed" => ItemStyle::Detailed,
+                "compact" => ItemStyle::Compact,
+                other => {
+                    return Err(JsValue::from_str(&format!(
+                        "thermoprint: unknown item style '{}'. Use 'detailed' or 'compact'",
+                        other
+                    )))
+                }
+            };
+            Ok(WasmReceiptBuilder {
+                inner: self.inner.item_style(s),
+            })
+        }
+
+        /// Add a line item subject to VAT; `vat_rate` is a percentage as a
+        /// decimal string, e.g. `item_vat("Polo", 2, "15000", null, "18")`.
+        pub fn item_vat(
+            self,
+            name: &str,
+            qty: i32,
+            unit_price: &str,
+            discount: Option<String>,
+            vat_rate: &str,
+        ) -> Result<WasmReceiptBuilder, JsValue> {
+            let price = parse_decimal(unit_price)?;
+            let disc = discount.as_deref().map(parse_decimal).transpose()?;
+            let rate = parse_decimal(vat_rate)?;
+            Ok(WasmReceiptBuilder {
+                inner: self.inner.item_vat(name, qty, price, disc, rate),
+            })
+        }
+
+        /// Print the per-rate VAT breakdown of all `item_vat` lines.
+        pub fn auto_vat_summary(self) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.auto_vat_summary(),
+            }
+        }
+
+        /// Add a line item sold by weight; `qty`, `unit_price` and `discount`
+        /// are decimal strings, e.g. `item_weighted("Tomates", "1.250", "kg", "3500", null)`.
+        pub fn item_weighted(
+            self,
+            name: &str,
+            qty: &str,
+            unit: &str,
+            unit_price: &str,
+            discount: Option<String>,
+        ) -> Result<WasmReceiptBuilder, JsValue> {
+            let q = parse_decimal(qty)?;
+            let price = parse_decimal(unit_price)?;
+            let disc = discount.as_deref().map(parse_decimal).transpose()?;
+            Ok(WasmReceiptBuilder {
+                inner: self.inner.item_weighted(name, q, unit, price, disc),
+            })
+        }
+
         pub fn subtotal_ht(self, amount: &str) -> Result<WasmReceiptBuilder, JsValue> {
             Ok(WasmReceiptBuilder {
                 inner: self.inner.subtotal_ht(parse_decimal(amount)?),
@@ -833,6 +3611,20 @@ pub mod wasm {
             })
         }
 
+        /// Grand total in a one-off `currency`, e.g. a USD equivalent.
+        pub fn total_in(self, amount: &str, currency: &str) -> Result<WasmReceiptBuilder, JsValue> {
+            Ok(WasmReceiptBuilder {
+                inner: self.inner.total_in(parse_decimal(amount)?, currency),
+            })
+        }
+
+        /// `amount` written out in words in the receipt language.
+        pub fn total_in_words(self, amount: &str) -> Result<WasmReceiptBuilder, JsValue> {
+            Ok(WasmReceiptBuilder {
+                inner: self.inner.total_in_words(parse_decimal(amount)?),
+            })
+        }
+
         pub fn received(self, amount: &str) -> Result<WasmReceiptBuilder, JsValue> {
             Ok(WasmReceiptBuilder {
                 inner: self.inner.received(parse_decimal(amount)?),
@@ -845,18 +3637,113 @@ pub mod wasm {
             })
         }
 
+        /// Start an aligned totals block; finish it with `render()`.
+        pub fn totals(self) -> WasmTotalsBlock {
+            WasmTotalsBlock {
+                inner: self.inner.totals(),
+            }
+        }
+
         pub fn served_by(self, name: &str) -> WasmReceiptBuilder {
             WasmReceiptBuilder {
                 inner: self.inner.served_by(name),
             }
         }
 
+        pub fn datetime_str(self, value: &str) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.datetime_str(value),
+            }
+        }
+
         pub fn thank_you(self, shop_name: &str) -> WasmReceiptBuilder {
             WasmReceiptBuilder {
                 inner: self.inner.thank_you(shop_name),
             }
         }
 
+        pub fn order_header(self, table: &str, order_no: &str, time: &str) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.order_header(table, order_no, time),
+            }
+        }
+
+        /// Print a kitchen ticket line; `modifiers` is an array of strings.
+        pub fn kitchen_item(
+            self,
+            name: &str,
+            qty: i32,
+            modifiers: Vec<String>,
+        ) -> WasmReceiptBuilder {
+            let mods: Vec<&str> = modifiers.iter().map(String::as_str).collect();
+            WasmReceiptBuilder {
+                inner: self.inner.kitchen_item(name, qty, &mods),
+            }
+        }
+
+        /// Print an indented add-on row; `price_delta` is a decimal string or `null`.
+        pub fn sub_item(
+            self,
+            name: &str,
+            price_delta: Option<String>,
+        ) -> Result<WasmReceiptBuilder, JsValue> {
+            let delta = price_delta.as_deref().map(parse_decimal).transpose()?;
+            Ok(WasmReceiptBuilder {
+                inner: self.inner.sub_item(name, delta),
+            })
+        }
+
+        /// Print an order metadata block; `labels[i]` is paired with `values[i]`.
+        pub fn meta(self, labels: Vec<String>, values: Vec<String>) -> WasmReceiptBuilder {
+            let rows: Vec<(&str, &str)> = labels
+                .iter()
+                .zip(&values)
+                .map(|(l, v)| (l.as_str(), v.as_str()))
+                .collect();
+            WasmReceiptBuilder {
+                inner: self.inner.meta(&rows),
+            }
+        }
+
+        /// Print fiscal identifiers; `labels[i]` is paired with `values[i]`.
+        pub fn fiscal_footer(self, labels: Vec<String>, values: Vec<String>) -> WasmReceiptBuilder {
+            let fields: Vec<(&str, &str)> = labels
+                .iter()
+                .zip(&values)
+                .map(|(l, v)| (l.as_str(), v.as_str()))
+                .collect();
+            WasmReceiptBuilder {
+                inner: self.inner.fiscal_footer(&fields),
+            }
+        }
+
+        pub fn tip_line(self) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.tip_line(),
+            }
+        }
+
+        pub fn signature_line(self) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.signature_line(),
+            }
+        }
+
+        pub fn effective_cols(&self) -> usize {
+            self.inner.effective_cols()
+        }
+
+        /// Whether the builder's paper width supports `feature`.
+        pub fn supports(&self, feature: Feature) -> bool {
+            self.inner.width.supports(feature)
+        }
+
+        pub fn repeat(self, n: u8) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.repeat(n),
+            }
+        }
+
         /// Finalise and return the ESC/POS bytes as a `Uint8Array`.
         pub fn build(self) -> Uint8Array {
             let bytes = self.inner.build();
@@ -866,6 +3753,102 @@ pub mod wasm {
         }
     }
 
+    /// WASM-bindgen wrapper around [`TotalsBlock`].
+    #[wasm_bindgen]
+    pub struct WasmTotalsBlock {
+        inner: TotalsBlock,
+    }
+
+    #[wasm_bindgen]
+    impl WasmTotalsBlock {
+        pub fn subtotal(self, amount: &str) -> Result<WasmTotalsBlock, JsValue> {
+            Ok(WasmTotalsBlock {
+                inner: self.inner.subtotal(parse_decimal(amount)?),
+            })
+        }
+
+        pub fn discount(self, amount: &str) -> Result<WasmTotalsBlock, JsValue> {
+            Ok(WasmTotalsBlock {
+                inner: self.inner.discount(parse_decimal(amount)?),
+            })
+        }
+
+        /// `included` is whether the tax is already in the item prices.
+        pub fn tax(
+            self,
+            label: &str,
+            amount: &str,
+            included: bool,
+        ) -> Result<WasmTotalsBlock, JsValue> {
+            let entry = TaxEntry::new(label, parse_decimal(amount)?, included);
+            Ok(WasmTotalsBlock {
+                inner: self.inner.tax(&entry),
+            })
+        }
+
+        pub fn line(self, label: &str, amount: &str) -> Result<WasmTotalsBlock, JsValue> {
+            Ok(WasmTotalsBlock {
+                inner: self.inner.line(label, parse_decimal(amount)?),
+            })
+        }
+
+        pub fn grand_total(self, amount: &str) -> Result<WasmTotalsBlock, JsValue> {
+            Ok(WasmTotalsBlock {
+                inner: self.inner.grand_total(parse_decimal(amount)?),
+            })
+        }
+
+        /// Print the block and return the receipt builder.
+        pub fn render(self) -> WasmReceiptBuilder {
+            WasmReceiptBuilder {
+                inner: self.inner.render(),
+            }
+        }
+    }
+
+    /// Concatenate `n` copies of finished receipt bytes, with a partial cut
+    /// between them when `cut_between` is set and the receipt does not
+    /// already end with a cut.
+    #[wasm_bindgen]
+    pub fn repeat_receipt(bytes: &[u8], n: u8, cut_between: bool) -> Uint8Array {
+        let out = super::repeat_receipt(bytes, n, cut_between);
+        let arr = Uint8Array::new_with_length(out.len() as u32);
+        arr.copy_from(&out);
+        arr
+    }
+
+    /// Split finished receipt bytes into pages of `lines_per_page` lines,
+    /// each cut, with `continued_label` lines around the cuts; see
+    /// [`super::paginate`]. `line_ending` is the receipt's: `"lf"`, `"crlf"`
+    /// or `"cr"`.
+    #[wasm_bindgen]
+    pub fn paginate(
+        bytes: &[u8],
+        lines_per_page: usize,
+        continued_label: &str,
+        line_ending: &str,
+    ) -> Result<Uint8Array, JsValue> {
+        let out = super::paginate(
+            bytes,
+            lines_per_page,
+            continued_label,
+            parse_line_ending(line_ending)?,
+        );
+        let arr = Uint8Array::new_with_length(out.len() as u32);
+        arr.copy_from(&out);
+        Ok(arr)
+    }
+
+    /// Printer reset sequence for recovering from a bad state; see
+    /// [`super::reset_bytes`].
+    #[wasm_bindgen]
+    pub fn reset_bytes() -> Uint8Array {
+        let out = super::reset_bytes();
+        let arr = Uint8Array::new_with_length(out.len() as u32);
+        arr.copy_from(&out);
+        arr
+    }
+
     /// Render a JSON receipt template to ESC/POS bytes.
     ///
     /// Accepts a JSON string describing the receipt layout and returns
@@ -883,15 +3866,141 @@ pub mod wasm {
     ///   ]
     /// }));
     /// ```
+    ///
+    /// Errors are strings of the form
+    /// `"thermoprint template error [<code>]: <message>"`, where `<code>` is
+    /// e.g. `invalid_json`, `unknown_width` or `image_path_unsupported`
+    /// (see [`TemplateError::code`](crate::template::TemplateError::code)).
     #[wasm_bindgen]
     pub fn render_template(json: &str) -> Result<Uint8Array, JsValue> {
-        let bytes = crate::template::render_json(json)
-            .map_err(|e| JsValue::from_str(&format!("thermoprint template error: {}", e)))?;
+        let bytes = crate::template::render_json(json).map_err(|e| {
+            JsValue::from_str(&format!("thermoprint template error [{}]: {}", e.code(), e))
+        })?;
+        let arr = Uint8Array::new_with_length(bytes.len() as u32);
+        arr.copy_from(&bytes);
+        Ok(arr)
+    }
+
+    /// Render several JSON templates into one stream, with an optional cut
+    /// between receipts; see [`render_batch`](crate::template::render_batch).
+    /// Errors are formatted as in [`render_template`].
+    #[wasm_bindgen]
+    pub fn render_template_batch(
+        templates: Vec<String>,
+        cut_between: bool,
+    ) -> Result<Uint8Array, JsValue> {
+        let templates: Vec<&str> = templates.iter().map(String::as_str).collect();
+        let bytes = crate::template::render_batch(&templates, cut_between).map_err(|e| {
+            JsValue::from_str(&format!("thermoprint template error [{}]: {}", e.code(), e))
+        })?;
         let arr = Uint8Array::new_with_length(bytes.len() as u32);
         arr.copy_from(&bytes);
         Ok(arr)
     }
 
+    /// JSON Schema for receipt templates, as a JSON string, for editor
+    /// validation and autocomplete.
+    #[wasm_bindgen]
+    pub fn template_json_schema() -> String {
+        crate::template::json_schema().to_string()
+    }
+
+    /// Dithering settings for [`dither_image`], wrapping
+    /// [`DitherOptions`](crate::dither::DitherOptions). Every setter is
+    /// optional; unset fields keep the defaults.
+    ///
+    /// ```js
+    /// const opts = new WasmDitherOptions().method("jjn").gamma(2.2);
+    /// ```
+    #[wasm_bindgen]
+    pub struct WasmDitherOptions {
+        inner: crate::dither::DitherOptions,
+        target_px: Option<u32>,
+    }
+
+    impl Default for WasmDitherOptions {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    #[wasm_bindgen]
+    impl WasmDitherOptions {
+        /// Floyd-Steinberg, `GS v 0`, bilinear scaling and gamma `1.0`.
+        #[wasm_bindgen(constructor)]
+        pub fn new() -> WasmDitherOptions {
+            WasmDitherOptions {
+                inner: crate::dither::DitherOptions::default(),
+                target_px: None,
+            }
+        }
+
+        /// `"floyd_steinberg"` (default), `"sierra_lite"`, `"jjn"` or
+        /// `"threshold"`.
+        pub fn method(mut self, method: &str) -> Result<WasmDitherOptions, JsValue> {
+            self.inner.method = match method {
+                "threshold" => crate::dither::DitherMethod::Threshold,
+                "floyd_steinberg" => crate::dither::DitherMethod::FloydSteinberg,
+                "sierra_lite" => crate::dither::DitherMethod::SierraLite,
+                "jjn" => crate::dither::DitherMethod::Jjn,
+                other => {
+                    return Err(JsValue::from_str(&format!(
+                        "thermoprint: unknown dither method '{}'. Use 'floyd_steinberg', 'sierra_lite', 'jjn' or 'threshold'",
+                        other
+                    )))
+                }
+            };
+            Ok(self)
+        }
+
+        /// `"gs_v0"` (default), `"esc_star"` for older column-mode printers,
+        /// or `"esc_star_single"` for single-density columns (cheap 58mm heads).
+        pub fn mode(mut self, mode: &str) -> Result<WasmDitherOptions, JsValue> {
+            self.inner.mode = match mode {
+                "gs_v0" => crate::types::RasterMode::GsV0,
+                "esc_star" => crate::types::RasterMode::EscStar,
+                "esc_star_single" => crate::types::RasterMode::EscStarSingle,
+                other => {
+                    return Err(JsValue::from_str(&format!(
+                        "thermoprint: unknown raster mode '{}'. Use 'gs_v0', 'esc_star' or 'esc_star_single'",
+                        other
+                    )))
+                }
+            };
+            Ok(self)
+        }
+
+        /// `"bilinear"` (default) or `"nearest"` to keep line-art edges
+        /// sharp when scaling.
+        pub fn filter(mut self, filter: &str) -> Result<WasmDitherOptions, JsValue> {
+            self.inner.filter = match filter {
+                "bilinear" => crate::dither::ResizeFilter::Bilinear,
+                "nearest" => crate::dither::ResizeFilter::Nearest,
+                other => {
+                    return Err(JsValue::from_str(&format!(
+                        "thermoprint: unknown resize filter '{}'. Use 'bilinear' or 'nearest'",
+                        other
+                    )))
+                }
+            };
+            Ok(self)
+        }
+
+        /// Gamma applied before dithering (default `1.0`); try `2.2` for photos.
+        pub fn gamma(mut self, gamma: f32) -> WasmDitherOptions {
+            self.inner.gamma = gamma;
+            self
+        }
+
+        /// Scale the image to exactly `px` wide, up or down, instead of only
+        /// shrinking it to `max_width_px` — e.g. a 100 px icon. `px` is
+        /// clamped to `max_width_px`.
+        pub fn target_width(mut self, px: u32) -> WasmDitherOptions {
+            self.target_px = Some(px);
+            self
+        }
+    }
+
     /// Dither an RGBA image to ESC/POS raster bytes.
     ///
     /// Use this to convert a logo or image from a `<canvas>` to printable bytes.
@@ -900,7 +4009,9 @@ pub mod wasm {
     /// - `width`: image width in pixels.
     /// - `height`: image height in pixels.
     /// - `max_width_px`: max printable width (e.g. 384 for 80mm).
-    /// - `method`: `"floyd_steinberg"` or `"threshold"` (default: Floyd-Steinberg).
+    /// - `options`: optional [`WasmDitherOptions`] (method, raster mode,
+    ///   resize filter, gamma, exact target width); omit for the defaults.
+    ///   The object is consumed by the call.
     ///
     /// Returns a `Uint8Array` of ESC/POS raster bytes to pass to `logo_raw()`.
     ///
@@ -908,7 +4019,8 @@ pub mod wasm {
     /// // Get RGBA from canvas
     /// const ctx = canvas.getContext('2d');
     /// const imageData = ctx.getImageData(0, 0, canvas.width, canvas.height);
-    /// const raster = dither_image(imageData.data, canvas.width, canvas.height, 384, "floyd_steinberg");
+    /// const raster = dither_image(imageData.data, canvas.width, canvas.height, 384,
+    ///   new WasmDitherOptions().method("sierra_lite"));
     ///
     /// const receipt = new WasmReceiptBuilder("80mm")
     ///   .init()
@@ -923,19 +4035,8 @@ pub mod wasm {
         width: u32,
         height: u32,
         max_width_px: u32,
-        method: Option<String>,
+        options: Option<WasmDitherOptions>,
     ) -> Result<Uint8Array, JsValue> {
-        let m = match method.as_deref() {
-            Some("threshold") => crate::dither::DitherMethod::Threshold,
-            Some("floyd_steinberg") | None => crate::dither::DitherMethod::FloydSteinberg,
-            Some(other) => {
-                return Err(JsValue::from_str(&format!(
-                    "thermoprint: unknown dither method '{}'. Use 'floyd_steinberg' or 'threshold'",
-                    other
-                )))
-            }
-        };
-
         if rgba.len() != (width * height * 4) as usize {
             return Err(JsValue::from_str(&format!(
                 "thermoprint: RGBA data length {} doesn't match {}×{}×4={}",
@@ -946,7 +4047,15 @@ pub mod wasm {
             )));
         }
 
-        let bytes = crate::dither::dither_rgba(rgba, width, height, max_width_px, m);
+        let options = options.unwrap_or_default();
+        let bytes = crate::dither::dither_rgba_within(
+            rgba,
+            width,
+            height,
+            max_width_px,
+            options.target_px,
+            &options.inner,
+        );
         let arr = Uint8Array::new_with_length(bytes.len() as u32);
         arr.copy_from(&bytes);
         Ok(arr)
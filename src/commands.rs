@@ -1,11 +1,22 @@
+#[cfg(feature = "std")]
+use crate::types::QrErrorCorrection;
+
 /// ESC byte (`0x1B`).
 pub const ESC: u8 = 0x1B;
 /// GS byte (`0x1D`).
 pub const GS: u8 = 0x1D;
+/// FS byte (`0x1C`) — prefix for Kanji / multibyte commands.
+pub const FS: u8 = 0x1C;
 /// Line feed byte (`0x0A`).
 pub const LF: u8 = 0x0A;
+/// Carriage return byte (`0x0D`).
+pub const CR: u8 = 0x0D;
 /// Form feed byte (`0x0C`) — page eject on A4 / impact printers.
 pub const FF: u8 = 0x0C;
+/// DLE byte (`0x10`) — prefix for real-time commands.
+pub const DLE: u8 = 0x10;
+/// EOT byte (`0x04`).
+pub const EOT: u8 = 0x04;
 
 // ── Initialisation ────────────────────────────────────────────────────────────
 
@@ -19,6 +30,39 @@ pub fn code_page_858() -> &'static [u8] {
     &[ESC, b't', 19]
 }
 
+/// `ESC R n` — select the international character set, which swaps a
+/// dozen ASCII glyphs (`#`, `$`, `@`, `[`, `\`, `]`, `^`, `` ` ``, `{`, `|`,
+/// `}`, `~`) for national variants, independently of the code page.
+///
+/// Standard values: 0 = USA, 1 = France, 2 = Germany, 3 = UK,
+/// 4 = Denmark I, 5 = Sweden, 6 = Italy, 7 = Spain I, 8 = Japan,
+/// 9 = Norway, 10 = Denmark II, 11 = Spain II, 12 = Latin America,
+/// 13 = Korea. `ESC @` resets it to 0.
+#[cfg(feature = "std")]
+pub fn intl_charset(n: u8) -> Vec<u8> {
+    vec![ESC, b'R', n]
+}
+
+/// `ESC t 1` — select the Katakana code page (half-width kana in 0xA1–0xDF).
+pub fn code_page_katakana() -> &'static [u8] {
+    &[ESC, b't', 1]
+}
+
+/// `FS C 1` — select Shift-JIS as the Kanji code system.
+pub fn kanji_code_system_shift_jis() -> &'static [u8] {
+    &[FS, b'C', 1]
+}
+
+/// `FS &` — enter Kanji (double-byte) character mode.
+pub fn kanji_mode_on() -> &'static [u8] {
+    &[FS, b'&']
+}
+
+/// `FS .` — leave Kanji character mode.
+pub fn kanji_mode_off() -> &'static [u8] {
+    &[FS, b'.']
+}
+
 // ── Alignment ─────────────────────────────────────────────────────────────────
 
 /// `ESC a 0` — left alignment.
@@ -34,6 +78,25 @@ pub fn align_right() -> &'static [u8] {
     &[ESC, b'a', 2]
 }
 
+// ── Position ──────────────────────────────────────────────────────────────────
+
+/// `GS L nL nH` — set the left margin in dots (not characters).
+///
+/// Takes effect at the start of a line; `0` restores the default.
+#[cfg(feature = "std")]
+pub fn left_margin(dots: u16) -> Vec<u8> {
+    let [lo, hi] = dots.to_le_bytes();
+    vec![GS, b'L', lo, hi]
+}
+
+/// `ESC $ nL nH` — move the print position to `dots` from the start of the
+/// line (not characters). Positions beyond the printable area are ignored.
+#[cfg(feature = "std")]
+pub fn absolute_position(dots: u16) -> Vec<u8> {
+    let [lo, hi] = dots.to_le_bytes();
+    vec![ESC, b'$', lo, hi]
+}
+
 // ── Text style ────────────────────────────────────────────────────────────────
 
 /// `ESC E 1` — bold on.
@@ -44,6 +107,14 @@ pub fn bold_on() -> &'static [u8] {
 pub fn bold_off() -> &'static [u8] {
     &[ESC, b'E', 0]
 }
+/// `ESC M 0` — character font A (12×24, default).
+pub fn font_a() -> &'static [u8] {
+    &[ESC, b'M', 0]
+}
+/// `ESC M 1` — character font B (9×17).
+pub fn font_b() -> &'static [u8] {
+    &[ESC, b'M', 1]
+}
 
 /// `ESC ! 0x10` — double height only.
 pub fn double_height_on() -> &'static [u8] {
@@ -70,14 +141,53 @@ pub fn underline_off() -> &'static [u8] {
 pub fn underline_on() -> &'static [u8] {
     &[ESC, b'-', 1]
 }
+/// Double (2-dot thick) underline.
+pub fn underline_double() -> &'static [u8] {
+    &[ESC, b'-', 2]
+}
+
+// ── Print density ─────────────────────────────────────────────────────────────
+
+/// Set print density / heat (`GS ( K 2 0 49 n`, Epson-style).
+///
+/// `level` runs from `-6` (lightest) through `0` (printer default) to `6`
+/// (darkest) and is clamped to that range. Density control is not part of
+/// the core ESC/POS set: this targets Epson TM printers and compatibles,
+/// while other vendors use `DC2 # n` or `ESC 7` and may ignore it.
+#[cfg(feature = "std")]
+pub fn print_density(level: i8) -> Vec<u8> {
+    let n = level.clamp(-6, 6) as u8; // negative levels encode as 250–255
+    vec![GS, b'(', b'K', 2, 0, 49, n]
+}
+
+/// Select the print speed (`GS ( K 2 0 50 n`, Epson-style).
+///
+/// `level` runs from `1` (slowest, best quality) to `9` (fastest); `0`
+/// restores the speed set in the printer's customize settings. Values above
+/// 9 are clamped. Epson TM models support a model-dependent subset of levels
+/// and round to the nearest one; other vendors may ignore the command or
+/// only offer speed selection through their setup utility.
+#[cfg(feature = "std")]
+pub fn print_speed(level: u8) -> Vec<u8> {
+    vec![GS, b'(', b'K', 2, 0, 50, level.min(9)]
+}
 
 // ── Line feed & paper movement ────────────────────────────────────────────────
 
 /// Advance `n` lines.
+#[cfg(feature = "std")]
 pub fn feed_lines(n: u8) -> Vec<u8> {
     vec![ESC, b'd', n]
 }
 
+/// `ESC J n` — print the buffer and feed `n` dots, for positioning finer
+/// than a line (e.g. aligning to a pre-printed form). A dot is the print
+/// head's vertical motion unit, typically 1/203 inch (0.125 mm).
+#[cfg(feature = "std")]
+pub fn feed_dots(n: u8) -> Vec<u8> {
+    vec![ESC, b'J', n]
+}
+
 /// Single line feed.
 pub fn lf() -> &'static [u8] {
     &[LF]
@@ -88,6 +198,17 @@ pub fn form_feed() -> &'static [u8] {
     &[FF]
 }
 
+/// `ESC 3 n` — set line spacing to `n` dots.
+#[cfg(feature = "std")]
+pub fn line_spacing(dots: u8) -> Vec<u8> {
+    vec![ESC, b'3', dots]
+}
+
+/// `ESC 2` — restore the default line spacing.
+pub fn line_spacing_default() -> &'static [u8] {
+    &[ESC, b'2']
+}
+
 // ── Paper cut ─────────────────────────────────────────────────────────────────
 
 /// Full cut with feed.
@@ -100,25 +221,43 @@ pub fn cut_partial() -> &'static [u8] {
     &[GS, b'V', 66, 0]
 }
 
+/// Feed the paper by `units` motion units (printer-specific, usually 1/203")
+/// past the cutter position, then partial cut (`GS V 66 n`).
+#[cfg(feature = "std")]
+pub fn cut_partial_feed(units: u8) -> Vec<u8> {
+    vec![GS, b'V', 66, units]
+}
+
+/// Feed the paper by `units` motion units past the cutter position, then
+/// full cut (`GS V 65 n`).
+#[cfg(feature = "std")]
+pub fn cut_full_feed(units: u8) -> Vec<u8> {
+    vec![GS, b'V', 65, units]
+}
+
 // ── Barcodes ──────────────────────────────────────────────────────────────────
 
 /// Set HRI (Human Readable Interpretation) position.
 /// `pos`: 0 = none, 1 = above, 2 = below, 3 = both.
+#[cfg(feature = "std")]
 pub fn barcode_hri_position(pos: u8) -> Vec<u8> {
     vec![GS, b'H', pos]
 }
 
 /// Set HRI font: 0 = Font A (default), 1 = Font B.
+#[cfg(feature = "std")]
 pub fn barcode_hri_font(font: u8) -> Vec<u8> {
     vec![GS, b'f', font]
 }
 
 /// Set barcode height in dots (default 162).
+#[cfg(feature = "std")]
 pub fn barcode_height(dots: u8) -> Vec<u8> {
     vec![GS, b'h', dots]
 }
 
 /// Set barcode module width (1–6, default 3).
+#[cfg(feature = "std")]
 pub fn barcode_width(width: u8) -> Vec<u8> {
     vec![GS, b'w', width]
 }
@@ -126,13 +265,131 @@ pub fn barcode_width(width: u8) -> Vec<u8> {
 /// Print a CODE128 barcode (`GS k 73 len data`).
 ///
 /// CODE128 supports full ASCII including hyphens — ideal for order numbers.
+/// The value is encoded with [`code128_data`], so code sets are selected
+/// automatically and `len` counts the code-set control characters.
+#[cfg(feature = "std")]
 pub fn barcode_code128(value: &str) -> Vec<u8> {
-    let mut cmd = vec![GS, b'k', 73, value.len() as u8];
-    cmd.extend_from_slice(value.as_bytes());
+    let data = code128_data(value.as_bytes());
+    let mut cmd = vec![GS, b'k', 73, data.len() as u8];
+    cmd.extend_from_slice(&data);
     cmd
 }
 
+/// Encode a CODE128 value with explicit code-set selection, using as few
+/// symbols as possible.
+///
+/// Printers expect the data after `GS k 73` to start with a code-set
+/// selector (`{A`, `{B` or `{C`). Code C packs two digits per symbol, code
+/// A covers control characters and code B everything else; the sets and
+/// the switches between them are chosen by dynamic programming over the
+/// value, so e.g. `12345X` becomes `{C 12 34 {B 5X` (6 symbols) rather than
+/// keeping the leading digit in B. Ties prefer staying in the current set,
+/// then B, C, A. SHIFT (`{S`) is not used, since not every printer accepts
+/// it. A literal `{` is escaped as `{{`.
+#[cfg(feature = "std")]
+pub fn code128_data(value: &[u8]) -> Vec<u8> {
+    const SETS: [u8; 3] = [b'B', b'C', b'A'];
+    let n = value.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    // direct[i][s]: fewest symbols for value[i..] when set s encodes the
+    // next character (no switch at i); usize::MAX if s can't encode it
+    let mut direct = vec![[usize::MAX; 3]; n + 1];
+    direct[n] = [0; 3];
+    for i in (0..n).rev() {
+        for (s, &set) in SETS.iter().enumerate() {
+            let Some(k) = code128_step(value, i, set) else {
+                continue;
+            };
+            // Best way to continue from i + k while in `s`: stay, or switch
+            let rest = (0..3)
+                .map(|t| direct[i + k][t].saturating_add(usize::from(t != s && i + k < n)))
+                .min()
+                .unwrap_or(usize::MAX);
+            direct[i][s] = rest.saturating_add(1);
+        }
+    }
+
+    let mut out = Vec::with_capacity(n + 4);
+    let mut current: Option<usize> = None;
+    let mut i = 0;
+    while i < n {
+        // Switching costs a symbol, except the start code, which is always sent
+        let switch = |t: usize| usize::from(current.is_some_and(|s| s != t));
+        let set = (0..3)
+            .filter(|&t| direct[i][t] != usize::MAX)
+            .min_by_key(|&t| (direct[i][t].saturating_add(switch(t)), current != Some(t)))
+            .expect("code set B encodes every byte from 0x20");
+        if current != Some(set) {
+            out.extend_from_slice(&[b'{', SETS[set]]);
+            current = Some(set);
+        }
+        match SETS[set] {
+            b'C' => {
+                out.push((value[i] - b'0') * 10 + (value[i + 1] - b'0'));
+                i += 2;
+            }
+            _ => {
+                if value[i] == b'{' {
+                    out.push(b'{');
+                }
+                out.push(value[i]);
+                i += 1;
+            }
+        }
+    }
+    out
+}
+
+/// Characters of `value` at `i` that one symbol of code `set` encodes, or
+/// `None` if the set can't encode what is there.
+#[cfg(feature = "std")]
+fn code128_step(value: &[u8], i: usize, set: u8) -> Option<usize> {
+    let c = value[i];
+    match set {
+        b'C' => (value.get(i + 1)?.is_ascii_digit() && c.is_ascii_digit()).then_some(2),
+        b'A' => (c <= 0x5F).then_some(1),
+        _ => (c >= 0x20).then_some(1),
+    }
+}
+
+/// Luhn (mod 10) check digit for an all-digit `value`, or `None` if the
+/// value is empty or contains anything but ASCII digits.
+///
+/// ```rust
+/// use thermoprint::commands::luhn_check_digit;
+/// assert_eq!(luhn_check_digit("7992739871"), Some('3'));
+/// ```
+pub fn luhn_check_digit(value: &str) -> Option<char> {
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    // Double every second digit starting from the rightmost
+    let sum: u32 = value
+        .bytes()
+        .rev()
+        .enumerate()
+        .map(|(i, b)| {
+            let d = u32::from(b - b'0');
+            if i % 2 == 0 {
+                let d = d * 2;
+                if d > 9 {
+                    d - 9
+                } else {
+                    d
+                }
+            } else {
+                d
+            }
+        })
+        .sum();
+    char::from_digit((10 - sum % 10) % 10, 10)
+}
+
 /// Print an EAN-13 barcode. `value` must be exactly 12 digits (check digit auto-added).
+#[cfg(feature = "std")]
 pub fn barcode_ean13(value: &str) -> Vec<u8> {
     let mut cmd = vec![GS, b'k', 2];
     cmd.extend_from_slice(value.as_bytes());
@@ -140,11 +397,52 @@ pub fn barcode_ean13(value: &str) -> Vec<u8> {
     cmd
 }
 
+/// Print an EAN-8 barcode. `value` must be exactly 7 digits (check digit auto-added).
+#[cfg(feature = "std")]
+pub fn barcode_ean8(value: &str) -> Vec<u8> {
+    let mut cmd = vec![GS, b'k', 3];
+    cmd.extend_from_slice(value.as_bytes());
+    cmd.push(0); // null terminator
+    cmd
+}
+
 // ── QR code ───────────────────────────────────────────────────────────────────
 
+/// Largest QR payload in bytes at error correction level M, as used by
+/// [`qr_code`]: the capacity of a version 40 symbol.
+///
+/// Byte-mode capacity at level M for some versions (the printer picks the
+/// smallest version that fits):
+///
+/// | Version | Modules | Bytes |
+/// |---|---|---|
+/// | 1 | 21×21 | 14 |
+/// | 5 | 37×37 | 84 |
+/// | 10 | 57×57 | 213 |
+/// | 20 | 97×97 | 666 |
+/// | 30 | 137×137 | 1370 |
+/// | 40 | 177×177 | 2331 |
+///
+/// Large versions print too small to scan reliably on 58 mm paper; keep
+/// URLs and payloads short where possible.
+pub const QR_MAX_BYTES: usize = 2331;
+
 /// Print a QR code. `size` is the module size (1–8, default 3).
 /// Error correction level M (15%).
+///
+/// `data` longer than [`QR_MAX_BYTES`] cannot be encoded; use
+/// [`ReceiptBuilder::qr_code_checked`](crate::ReceiptBuilder::qr_code_checked)
+/// to reject it instead of printing nothing.
+#[cfg(feature = "std")]
 pub fn qr_code(data: &str, size: u8) -> Vec<u8> {
+    qr_code_bytes(data.as_bytes(), size, QrErrorCorrection::M)
+}
+
+/// Print a QR code from raw bytes, stored exactly as given — for binary or
+/// byte-exact payloads such as EMV merchant-presented mobile-money codes.
+/// `size` is the module size (1–8).
+#[cfg(feature = "std")]
+pub fn qr_code_bytes(data: &[u8], size: u8, ecc: QrErrorCorrection) -> Vec<u8> {
     let mut cmd = Vec::new();
     let plen = (data.len() + 3) as u16;
 
@@ -159,13 +457,13 @@ pub fn qr_code(data: &str, size: u8) -> Vec<u8> {
         80,
         48, // fn 80: store data
     ]);
-    cmd.extend_from_slice(data.as_bytes());
+    cmd.extend_from_slice(data);
 
     // Set module size
     cmd.extend_from_slice(&[GS, b'(', b'k', 3, 0, 49, 67, size]);
 
-    // Set error correction level M
-    cmd.extend_from_slice(&[GS, b'(', b'k', 3, 0, 49, 69, 49]);
+    // Set error correction level (48 = L … 51 = H)
+    cmd.extend_from_slice(&[GS, b'(', b'k', 3, 0, 49, 69, 48 + ecc.index()]);
 
     // Print symbol
     cmd.extend_from_slice(&[GS, b'(', b'k', 3, 0, 49, 81, 48]);
@@ -173,14 +471,70 @@ pub fn qr_code(data: &str, size: u8) -> Vec<u8> {
     cmd
 }
 
+// ── Data Matrix ───────────────────────────────────────────────────────────────
+
+/// Print an ECC 200 Data Matrix symbol (`GS ( k`, cn = 54). `size` is the
+/// module size in dots (2–16); the printer picks the smallest square symbol
+/// that holds `data`.
+///
+/// Supported by recent Epson models (TM-T88VI and later, TM-m30); older
+/// printers skip the command.
+#[cfg(feature = "std")]
+pub fn data_matrix(data: &str, size: u8) -> Vec<u8> {
+    let mut cmd = Vec::new();
+
+    // Symbol type: square (m = 0), rows and columns chosen automatically
+    cmd.extend_from_slice(&[GS, b'(', b'k', 5, 0, 54, 66, 0, 0, 0]);
+
+    // Set module size
+    cmd.extend_from_slice(&[GS, b'(', b'k', 3, 0, 54, 67, size]);
+
+    // Store data in the symbol storage area
+    let [lo, hi] = ((data.len() + 3) as u16).to_le_bytes();
+    cmd.extend_from_slice(&[GS, b'(', b'k', lo, hi, 54, 80, 48]);
+    cmd.extend_from_slice(data.as_bytes());
+
+    // Print symbol
+    cmd.extend_from_slice(&[GS, b'(', b'k', 3, 0, 54, 81, 48]);
+
+    cmd
+}
+
 // ── Cash drawer ───────────────────────────────────────────────────────────────
 
 /// Kick cash drawer pin 2 (most drawers) and pin 5 (some drawers).
+#[cfg(feature = "std")]
 pub fn cash_drawer_kick() -> Vec<u8> {
-    vec![
-        ESC, b'p', 0, 25, 250, // pin 2
-        ESC, b'p', 1, 25, 250, // pin 5
-    ]
+    let mut cmd = cash_drawer_kick_pin(2, 25, 250);
+    cmd.extend_from_slice(&cash_drawer_kick_pin(5, 25, 250));
+    cmd
+}
+
+/// Kick a single cash drawer pin (`ESC p m t1 t2`).
+///
+/// `pin` is the drawer connector pin: `5` selects pin 5, anything else pin 2.
+/// `on_time` / `off_time` are the pulse ON and OFF times in 2 ms units,
+/// so `25, 250` is a 50 ms pulse followed by a 500 ms pause.
+#[cfg(feature = "std")]
+pub fn cash_drawer_kick_pin(pin: u8, on_time: u8, off_time: u8) -> Vec<u8> {
+    let m = if pin == 5 { 1 } else { 0 };
+    vec![ESC, b'p', m, on_time, off_time]
+}
+
+// ── Real-time status ──────────────────────────────────────────────────────────
+
+/// `DLE EOT n` — ask the printer to send one status byte immediately.
+///
+/// The printer answers even while its buffer is still printing (or when
+/// it is offline), so a host can poll this after a page-mode print or NV
+/// image upload to learn when the job has gone through. `n` selects the
+/// status: 1 printer, 2 offline cause, 3 error cause, 4 roll paper sensor.
+///
+/// Reading the reply needs a bidirectional connection; the builder never
+/// emits this command.
+#[cfg(feature = "std")]
+pub fn real_time_status(n: u8) -> Vec<u8> {
+    vec![DLE, EOT, n]
 }
 
 // ── Raster image ──────────────────────────────────────────────────────────────
@@ -190,6 +544,7 @@ pub fn cash_drawer_kick() -> Vec<u8> {
 /// `bytes_per_line` = ceil(width_px / 8)
 /// `height_px`      = number of raster lines
 /// `raster_data`    = packed 1-bit rows, MSB first (1 = print, 0 = white)
+#[cfg(feature = "std")]
 pub fn raster_image(bytes_per_line: u16, height_px: u16, raster_data: &[u8]) -> Vec<u8> {
     let mut cmd = Vec::with_capacity(8 + raster_data.len());
     cmd.extend_from_slice(&[GS, b'v', b'0', 0]); // m = 0: normal density
@@ -201,7 +556,213 @@ pub fn raster_image(bytes_per_line: u16, height_px: u16, raster_data: &[u8]) ->
     cmd
 }
 
-#[cfg(test)]
+/// Build a `GS v 0` raster command with each row padded on the left so the
+/// image sits centred within `max_width_px`.
+///
+/// Padding is added in whole bytes (8 px), so when the free space is not a
+/// multiple of 16 px the image lands up to 4 px left of true centre. Images
+/// already as wide as `max_width_px` are emitted unchanged.
+#[cfg(feature = "std")]
+pub fn raster_image_centered(
+    bytes_per_line: u16,
+    height_px: u16,
+    raster_data: &[u8],
+    max_width_px: u32,
+) -> Vec<u8> {
+    let page_bytes = max_width_px.div_ceil(8) as u16;
+    if bytes_per_line == 0 || bytes_per_line >= page_bytes {
+        return raster_image(bytes_per_line, height_px, raster_data);
+    }
+    let pad = ((page_bytes - bytes_per_line) / 2) as usize;
+    let bpl = bytes_per_line as usize;
+    let mut padded = Vec::with_capacity((pad + bpl) * height_px as usize);
+    for row in raster_data.chunks(bpl) {
+        padded.resize(padded.len() + pad, 0);
+        padded.extend_from_slice(row);
+    }
+    raster_image(bytes_per_line + pad as u16, height_px, &padded)
+}
+
+/// Re-centre an existing `GS v 0` raster command within `max_width_px`.
+///
+/// Bytes that are not a `GS v 0` command are returned unchanged.
+/// See [`raster_image_centered`] for the rounding rules.
+#[cfg(feature = "std")]
+pub fn center_raster(cmd: &[u8], max_width_px: u32) -> Vec<u8> {
+    if cmd.len() < 8 || cmd[..3] != [GS, b'v', b'0'] {
+        return cmd.to_vec();
+    }
+    let bytes_per_line = u16::from_le_bytes([cmd[4], cmd[5]]);
+    let height_px = u16::from_le_bytes([cmd[6], cmd[7]]);
+    let mut out = raster_image_centered(bytes_per_line, height_px, &cmd[8..], max_width_px);
+    out[3] = cmd[3]; // keep the original density mode
+    out
+}
+
+/// Build `ESC * 33` 24-dot column bit-image slices from packed 1-bit rows.
+///
+/// Takes the same row-major input as [`raster_image`] (`ceil(width_px / 8)`
+/// bytes per row, MSB first) and transposes it into stripes of 24 dots,
+/// three bytes per column with the top dot in the MSB. Line spacing is set
+/// to 24 dots while printing so stripes join without gaps, then restored.
+#[cfg(feature = "std")]
+pub fn column_image(width_px: u16, height_px: u16, raster_data: &[u8]) -> Vec<u8> {
+    column_image_m(33, width_px, height_px, raster_data)
+}
+
+/// Like [`column_image`], but `ESC * 32` (24-dot single density): each
+/// column prints two dots wide, so the image covers twice its pixel width.
+#[cfg(feature = "std")]
+pub fn column_image_single(width_px: u16, height_px: u16, raster_data: &[u8]) -> Vec<u8> {
+    column_image_m(32, width_px, height_px, raster_data)
+}
+
+/// `ESC * m` 24-dot column slices; `m` is 32 (single) or 33 (double density).
+#[cfg(feature = "std")]
+fn column_image_m(m: u8, width_px: u16, height_px: u16, raster_data: &[u8]) -> Vec<u8> {
+    let w = width_px as usize;
+    let h = height_px as usize;
+    let bytes_per_line = w.div_ceil(8);
+    let stripes = h.div_ceil(24);
+
+    let mut cmd = Vec::with_capacity(5 + stripes * (6 + 3 * w));
+    cmd.extend_from_slice(&line_spacing(24));
+
+    for stripe in 0..stripes {
+        cmd.extend_from_slice(&[
+            ESC,
+            b'*',
+            m,
+            (width_px & 0xFF) as u8,
+            ((width_px >> 8) & 0xFF) as u8,
+        ]);
+        for x in 0..w {
+            let mut column = [0u8; 3];
+            for dy in 0..24 {
+                let y = stripe * 24 + dy;
+                if y >= h {
+                    break;
+                }
+                let byte = raster_data
+                    .get(y * bytes_per_line + x / 8)
+                    .copied()
+                    .unwrap_or(0);
+                if byte & (0x80 >> (x % 8)) != 0 {
+                    column[dy / 8] |= 0x80 >> (dy % 8);
+                }
+            }
+            cmd.extend_from_slice(&column);
+        }
+        cmd.push(LF);
+    }
+
+    cmd.extend_from_slice(line_spacing_default());
+    cmd
+}
+
+// ── NV bit images ─────────────────────────────────────────────────────────────
+
+/// `FS p n m` — print NV bit image `n` (1-based) previously stored in the
+/// printer's non-volatile memory.
+///
+/// `mode`: 0 = normal, 1 = double width, 2 = double height, 3 = quadruple.
+#[cfg(feature = "std")]
+pub fn print_nv_logo(n: u8, mode: u8) -> Vec<u8> {
+    vec![FS, b'p', n, mode]
+}
+
+/// `FS q 1` — store a single image as NV bit image 1.
+///
+/// Takes the same packed row-major input as [`raster_image`]; the image is
+/// padded to a multiple of 8 dots in both directions and sent in the
+/// column format `FS q` expects. Note that `FS q` replaces **all** NV bit
+/// images, and that NV memory has a limited number of write cycles — define
+/// the logo once at set-up, not on every receipt.
+#[cfg(feature = "std")]
+pub fn define_nv_logo(width_px: u16, height_px: u16, raster_data: &[u8]) -> Vec<u8> {
+    let w = width_px as usize;
+    let h = height_px as usize;
+    let bytes_per_line = w.div_ceil(8);
+    let x_units = w.div_ceil(8); // horizontal size in 8-dot units
+    let y_units = h.div_ceil(8); // vertical size in 8-dot units
+
+    let mut cmd = Vec::with_capacity(7 + x_units * 8 * y_units);
+    cmd.extend_from_slice(&[
+        FS,
+        b'q',
+        1,
+        (x_units & 0xFF) as u8,
+        ((x_units >> 8) & 0xFF) as u8,
+        (y_units & 0xFF) as u8,
+        ((y_units >> 8) & 0xFF) as u8,
+    ]);
+    for x in 0..x_units * 8 {
+        for yb in 0..y_units {
+            let mut column = 0u8;
+            for dy in 0..8 {
+                let y = yb * 8 + dy;
+                if x >= w || y >= h {
+                    continue;
+                }
+                let byte = raster_data
+                    .get(y * bytes_per_line + x / 8)
+                    .copied()
+                    .unwrap_or(0);
+                if byte & (0x80 >> (x % 8)) != 0 {
+                    column |= 0x80 >> dy;
+                }
+            }
+            cmd.push(column);
+        }
+    }
+    cmd
+}
+
+/// `GS ( L` fn 67 — store a raster image as NV graphics data under the
+/// two-byte key `key` (each byte `0x20`–`0x7E`, e.g. `*b"LG"`).
+///
+/// Takes the same packed row-major input as [`raster_image`]. Unlike
+/// `FS q`, other stored graphics are kept; an image with the same key is
+/// replaced. Send this once at set-up — NV memory has limited write cycles —
+/// then print with [`print_nv_graphic`]. Payloads over 64 KiB switch to the
+/// extended `GS 8 L` form automatically.
+#[cfg(feature = "std")]
+pub fn define_nv_graphic(
+    key: [u8; 2],
+    width_px: u16,
+    height_px: u16,
+    raster_data: &[u8],
+) -> Vec<u8> {
+    let [xl, xh] = width_px.to_le_bytes();
+    let [yl, yh] = height_px.to_le_bytes();
+    // m fn a kc1 kc2 b xL xH yL yH c
+    let params = [48, 67, 48, key[0], key[1], 1, xl, xh, yl, yh, 49];
+    let len = params.len() + raster_data.len();
+
+    let mut cmd = Vec::with_capacity(7 + len);
+    match u16::try_from(len) {
+        Ok(len) => {
+            cmd.extend_from_slice(&[GS, b'(', b'L']);
+            cmd.extend_from_slice(&len.to_le_bytes());
+        }
+        Err(_) => {
+            cmd.extend_from_slice(&[GS, b'8', b'L']);
+            cmd.extend_from_slice(&(len as u32).to_le_bytes());
+        }
+    }
+    cmd.extend_from_slice(&params);
+    cmd.extend_from_slice(raster_data);
+    cmd
+}
+
+/// `GS ( L` fn 69 — print the NV graphics image stored under `key` at
+/// normal scale.
+#[cfg(feature = "std")]
+pub fn print_nv_graphic(key: [u8; 2]) -> Vec<u8> {
+    vec![GS, b'(', b'L', 6, 0, 48, 69, key[0], key[1], 1, 1]
+}
+
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
 
@@ -209,8 +770,94 @@ mod tests {
     fn code128_includes_value() {
         let cmd = barcode_code128("ORD-001");
         assert_eq!(cmd[2], 73); // CODE128 type
-        assert_eq!(cmd[3], 7); // length
-        assert_eq!(&cmd[4..], b"ORD-001");
+        assert_eq!(cmd[3], 9); // length, including the `{B` selector
+        assert_eq!(&cmd[4..], b"{BORD-001");
+    }
+
+    #[test]
+    fn feed_dots_bytes() {
+        assert_eq!(feed_dots(12), vec![ESC, b'J', 12]);
+    }
+
+    #[test]
+    fn underline_thickness_bytes() {
+        assert_eq!(underline_off(), &[ESC, b'-', 0]);
+        assert_eq!(underline_on(), &[ESC, b'-', 1]);
+        assert_eq!(underline_double(), &[ESC, b'-', 2]);
+    }
+
+    #[test]
+    fn ean8_type_byte() {
+        let cmd = barcode_ean8("9638507");
+        assert_eq!(&cmd[..3], &[GS, b'k', 3]);
+        assert_eq!(&cmd[3..10], b"9638507");
+        assert_eq!(cmd[10], 0);
+    }
+
+    #[test]
+    fn code128_numeric_uses_code_c() {
+        assert_eq!(code128_data(b"12345678"), vec![b'{', b'C', 12, 34, 56, 78]);
+        assert_eq!(code128_data(b"42"), vec![b'{', b'C', 42]);
+        // Odd run: first digit in B, rest in C
+        assert_eq!(
+            code128_data(b"123456789"),
+            vec![b'{', b'B', b'1', b'{', b'C', 23, 45, 67, 89]
+        );
+    }
+
+    #[test]
+    fn code128_mixed_runs() {
+        // Trailing run of 4+ digits switches to C
+        assert_eq!(
+            code128_data(b"AB123456"),
+            vec![b'{', b'B', b'A', b'B', b'{', b'C', 12, 34, 56]
+        );
+        // Short runs stay in B
+        assert_eq!(code128_data(b"A12B"), b"{BA12B".to_vec());
+        assert_eq!(
+            code128_data(b"INV2024"),
+            vec![b'{', b'B', b'I', b'N', b'V', b'{', b'C', 20, 24]
+        );
+        // Middle run of 6 switches to C and back
+        assert_eq!(
+            code128_data(b"X123456Y"),
+            vec![b'{', b'B', b'X', b'{', b'C', 12, 34, 56, b'{', b'B', b'Y']
+        );
+    }
+
+    /// Symbols in `code128_data` output: each `{X` selector or `{{` escape
+    /// is one symbol, like every other byte (start code included).
+    fn code128_symbols(data: &[u8]) -> usize {
+        let mut symbols = 0;
+        let mut i = 0;
+        while i < data.len() {
+            i += if data[i] == b'{' { 2 } else { 1 };
+            symbols += 1;
+        }
+        symbols
+    }
+
+    #[test]
+    fn code128_uses_fewest_symbols() {
+        // Odd leading run: pairs first, the last digit in B
+        assert_eq!(code128_data(b"12345X"), b"{C\x0C\x22{B5X".to_vec());
+        assert_eq!(code128_symbols(&code128_data(b"12345X")), 6);
+        // A middle run of 5 is worth a switch to C and back
+        assert_eq!(code128_symbols(&code128_data(b"X12345Y")), 8);
+        // Control characters stay in A, whose digits cost the same as B's
+        assert_eq!(code128_data(b"\t1\n"), b"{A\t1\n".to_vec());
+        assert_eq!(code128_symbols(&code128_data(b"\t1\n")), 4);
+        assert_eq!(code128_symbols(&code128_data(b"\t123456\n")), 8);
+        // Lowercase after a control character switches to B once
+        assert_eq!(code128_data(b"\tab"), b"{A\t{Bab".to_vec());
+        assert_eq!(code128_symbols(&code128_data(b"a{b")), 4);
+        assert_eq!(code128_data(b""), b"");
+    }
+
+    #[test]
+    fn code128_escapes_and_code_a() {
+        assert_eq!(code128_data(b"a{b"), b"{Ba{{b".to_vec());
+        assert_eq!(code128_data(b"\tX"), b"{A\tX".to_vec());
     }
 
     #[test]
@@ -220,6 +867,69 @@ mod tests {
         assert!(cmd.len() > 20);
     }
 
+    #[test]
+    fn data_matrix_uses_cn_54() {
+        let cmd = data_matrix("01034531200000111719112510ABCD1234", 4);
+        assert_eq!(cmd[..10], [GS, b'(', b'k', 5, 0, 54, 66, 0, 0, 0]);
+        assert!(cmd
+            .windows(8)
+            .any(|w| w == [GS, b'(', b'k', 3, 0, 54, 67, 4]));
+        // 34 data bytes + cn, fn, m
+        assert!(cmd
+            .windows(8)
+            .any(|w| w == [GS, b'(', b'k', 37, 0, 54, 80, 48]));
+        assert!(cmd.ends_with(&[GS, b'(', b'k', 3, 0, 54, 81, 48]));
+    }
+
+    #[test]
+    fn cash_drawer_kick_defaults_to_both_pins() {
+        assert_eq!(
+            cash_drawer_kick(),
+            vec![ESC, b'p', 0, 25, 250, ESC, b'p', 1, 25, 250]
+        );
+    }
+
+    #[test]
+    fn real_time_status_bytes() {
+        assert_eq!(real_time_status(1), [0x10, 0x04, 1]);
+        assert_eq!(real_time_status(4), [DLE, EOT, 4]);
+    }
+
+    #[test]
+    fn cash_drawer_kick_single_pin() {
+        assert_eq!(
+            cash_drawer_kick_pin(5, 50, 100),
+            vec![ESC, b'p', 1, 50, 100]
+        );
+        assert_eq!(cash_drawer_kick_pin(2, 10, 20), vec![ESC, b'p', 0, 10, 20]);
+    }
+
+    #[test]
+    fn intl_charset_bytes() {
+        assert_eq!(intl_charset(1), [ESC, b'R', 1]);
+        assert_eq!(intl_charset(0), [0x1B, 0x52, 0x00]);
+    }
+
+    #[test]
+    fn print_density_bytes() {
+        assert_eq!(print_density(3), vec![GS, b'(', b'K', 2, 0, 49, 3]);
+        assert_eq!(print_density(-2), vec![GS, b'(', b'K', 2, 0, 49, 254]);
+        assert_eq!(print_density(20), vec![GS, b'(', b'K', 2, 0, 49, 6]);
+    }
+
+    #[test]
+    fn print_speed_bytes() {
+        assert_eq!(print_speed(9), vec![GS, b'(', b'K', 2, 0, 50, 9]);
+        assert_eq!(print_speed(0), vec![GS, b'(', b'K', 2, 0, 50, 0]);
+        assert_eq!(print_speed(42), vec![GS, b'(', b'K', 2, 0, 50, 9]);
+    }
+
+    #[test]
+    fn position_commands_are_little_endian() {
+        assert_eq!(left_margin(300), vec![GS, b'L', 0x2C, 0x01]);
+        assert_eq!(absolute_position(64), vec![ESC, b'$', 64, 0]);
+    }
+
     #[test]
     fn raster_header_correct() {
         let data = vec![0xFFu8; 4]; // 1 line of 32 pixels
@@ -230,4 +940,134 @@ mod tests {
         assert_eq!(cmd[6], 1); // yL
         assert_eq!(cmd[7], 0); // yH
     }
+
+    #[test]
+    fn column_image_transposes_rows() {
+        // 2 px wide, 3 rows: left column fully black, right column only row 1
+        let rows = [0x80u8, 0xC0, 0x80];
+        let cmd = column_image(2, 3, &rows);
+        assert_eq!(&cmd[..3], &[ESC, b'3', 24]);
+        assert_eq!(&cmd[3..8], &[ESC, b'*', 33, 2, 0]);
+        assert_eq!(&cmd[8..11], &[0xE0, 0, 0]); // column 0: dots 0..3
+        assert_eq!(&cmd[11..14], &[0x40, 0, 0]); // column 1: dot 1
+        assert_eq!(cmd[14], LF);
+        assert_eq!(&cmd[15..], &[ESC, b'2']);
+    }
+
+    #[test]
+    fn column_image_splits_into_24_dot_stripes() {
+        let rows = vec![0xFFu8; 25]; // 8 px wide, 25 rows → 2 stripes
+        let cmd = column_image(8, 25, &rows);
+        let slices = cmd.windows(3).filter(|w| *w == [ESC, b'*', 33]).count();
+        assert_eq!(slices, 2);
+    }
+
+    #[test]
+    fn column_image_single_uses_single_density() {
+        let rows = [0x80u8, 0xC0, 0x80];
+        let single = column_image_single(2, 3, &rows);
+        let mut double = column_image(2, 3, &rows);
+        assert_eq!(&single[3..8], &[ESC, b'*', 32, 2, 0]);
+        double[5] = 32; // only m differs
+        assert_eq!(single, double);
+    }
+
+    #[test]
+    fn nv_logo_print_command() {
+        assert_eq!(print_nv_logo(1, 0), vec![FS, b'p', 1, 0]);
+    }
+
+    #[test]
+    fn nv_logo_definition_is_column_major() {
+        // 2 px wide, 3 rows: left column fully black, right column only row 1
+        let rows = [0x80u8, 0xC0, 0x80];
+        let cmd = define_nv_logo(2, 3, &rows);
+        assert_eq!(&cmd[..7], &[FS, b'q', 1, 1, 0, 1, 0]);
+        assert_eq!(cmd.len(), 7 + 8); // 8 columns × 1 byte
+        assert_eq!(cmd[7], 0xE0);
+        assert_eq!(cmd[8], 0x40);
+        assert!(cmd[9..].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn nv_graphic_definition_header() {
+        let data = [0xFFu8, 0x00, 0xAA, 0x55]; // 16×2 px
+        let cmd = define_nv_graphic(*b"LG", 16, 2, &data);
+        assert_eq!(&cmd[..5], &[GS, b'(', b'L', 15, 0]); // 11 params + 4 data
+        assert_eq!(&cmd[5..16], &[48, 67, 48, b'L', b'G', 1, 16, 0, 2, 0, 49]);
+        assert_eq!(&cmd[16..], &data);
+    }
+
+    #[test]
+    fn nv_graphic_large_uses_extended_length() {
+        let data = vec![0u8; 70_000];
+        let cmd = define_nv_graphic(*b"LG", 560, 1000, &data);
+        assert_eq!(&cmd[..3], &[GS, b'8', b'L']);
+        assert_eq!(&cmd[3..7], &70_011u32.to_le_bytes());
+        assert_eq!(cmd.len(), 7 + 11 + 70_000);
+    }
+
+    #[test]
+    fn nv_graphic_print_command() {
+        assert_eq!(
+            print_nv_graphic(*b"LG"),
+            vec![GS, b'(', b'L', 6, 0, 48, 69, b'L', b'G', 1, 1]
+        );
+    }
+
+    #[test]
+    fn qr_code_bytes_keeps_binary_payload() {
+        let data = [0x00, 0xFF, 0x0A, 0x1B, 0x00];
+        let cmd = qr_code_bytes(&data, 4, QrErrorCorrection::H);
+        // pL pH = data length + 3, then cn=49 fn=80 m=48
+        assert_eq!(&cmd[..8], &[GS, b'(', b'k', 8, 0, 49, 80, 48]);
+        assert_eq!(&cmd[8..13], &data);
+        assert_eq!(&cmd[13..21], &[GS, b'(', b'k', 3, 0, 49, 67, 4]);
+        assert_eq!(&cmd[21..29], &[GS, b'(', b'k', 3, 0, 49, 69, 51]);
+        assert_eq!(
+            qr_code("hi", 3),
+            qr_code_bytes(b"hi", 3, QrErrorCorrection::M)
+        );
+    }
+
+    #[test]
+    fn luhn_check_digits() {
+        assert_eq!(luhn_check_digit("7992739871"), Some('3'));
+        assert_eq!(luhn_check_digit("2024001"), Some('6'));
+        assert_eq!(luhn_check_digit("0"), Some('0'));
+        assert_eq!(luhn_check_digit(""), None);
+        assert_eq!(luhn_check_digit("ORD-1"), None);
+    }
+
+    #[test]
+    fn cut_with_feed_distance() {
+        assert_eq!(cut_partial_feed(0), cut_partial());
+        assert_eq!(cut_partial_feed(48), vec![GS, b'V', 66, 48]);
+        assert_eq!(cut_full_feed(24), vec![GS, b'V', 65, 24]);
+    }
+
+    #[test]
+    fn raster_centered_pads_rows() {
+        // 16 px image on a 48 px page → 2 bytes of padding per row
+        let data = vec![0xAAu8, 0xBB, 0xCC, 0xDD];
+        let cmd = raster_image_centered(2, 2, &data, 48);
+        assert_eq!(cmd[4], 4); // xL: 2 pad + 2 image
+        assert_eq!(&cmd[8..], &[0, 0, 0xAA, 0xBB, 0, 0, 0xCC, 0xDD]);
+    }
+
+    #[test]
+    fn raster_centered_rounds_down_to_whole_bytes() {
+        // 8 px image on a 32 px page → 3 free bytes, 1 on the left
+        let cmd = raster_image_centered(1, 1, &[0xFF], 32);
+        assert_eq!(&cmd[8..], &[0, 0xFF]);
+    }
+
+    #[test]
+    fn center_raster_reparses_header() {
+        let original = raster_image(1, 1, &[0xFF]);
+        let cmd = center_raster(&original, 40);
+        assert_eq!(cmd[4], 3);
+        assert_eq!(&cmd[8..], &[0, 0, 0xFF]);
+        assert_eq!(center_raster(b"abc", 40), b"abc");
+    }
 }
@@ -0,0 +1,285 @@
+//! Split ESC/POS byte streams into commands and text, for tests and tooling.
+//!
+//! Unlike substring matching on `String::from_utf8_lossy`, tokens keep
+//! control sequences apart from text and decode text runs from CP858, so
+//! accented characters and command parameters can't be confused.
+//!
+//! ```rust
+//! use thermoprint::{ReceiptBuilder, PrintWidth};
+//! use thermoprint::debug::{tokenize, Token};
+//!
+//! let b = ReceiptBuilder::new(PrintWidth::Mm58)
+//!     .bold(true)
+//!     .text_line("Café");
+//!
+//! assert_eq!(
+//!     tokenize(b.as_bytes()),
+//!     vec![
+//!         Token::command("ESC E", &[1]),
+//!         Token::Text("Café".to_owned()),
+//!         Token::LineFeed,
+//!     ]
+//! );
+//! ```
+
+use crate::commands::{DLE, ESC, FF, FS, GS, LF};
+use crate::encoding::cp858_char;
+
+/// One element of an ESC/POS byte stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token {
+    /// A run of printable characters, decoded from CP858 (or Shift-JIS in
+    /// Kanji mode). Never contains a line feed.
+    Text(String),
+    /// `LF` — print and feed one line.
+    LineFeed,
+    /// `FF` — page eject.
+    FormFeed,
+    /// A recognised command such as `"ESC a"`, `"GS V"` or `"GS ( k"`, with
+    /// the bytes that follow the command name (including any payload).
+    Command {
+        /// Command mnemonic, e.g. `"GS k"`
+        name: String,
+        /// Parameter and payload bytes
+        params: Vec<u8>,
+    },
+    /// Any other control byte.
+    Control(u8),
+}
+
+impl Token {
+    /// Convenience constructor for [`Token::Command`].
+    pub fn command(name: &str, params: &[u8]) -> Self {
+        Token::Command {
+            name: name.to_owned(),
+            params: params.to_vec(),
+        }
+    }
+
+    /// The decoded text, if this is a [`Token::Text`].
+    pub fn text(&self) -> Option<&str> {
+        match self {
+            Token::Text(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// `true` if this is a command with the given mnemonic.
+    pub fn is_command(&self, mnemonic: &str) -> bool {
+        matches!(self, Token::Command { name, .. } if name == mnemonic)
+    }
+}
+
+/// Split `bytes` into commands and text runs.
+///
+/// Parameter lengths follow the ESC/POS specification for the commands this
+/// crate emits; unknown `ESC`/`GS`/`FS` commands are assumed to take one
+/// parameter. A command truncated by the end of the stream keeps whatever
+/// parameters are present.
+pub fn tokenize(bytes: &[u8]) -> Vec<Token> {
+    tokenize_spans(bytes)
+        .into_iter()
+        .map(|(token, _)| token)
+        .collect()
+}
+
+/// Like [`tokenize`], but pairs each token with the offset just past its
+/// last byte, so callers can splice bytes in between tokens.
+pub(crate) fn tokenize_spans(bytes: &[u8]) -> Vec<(Token, usize)> {
+    let mut tokens = Vec::new();
+    let mut text = String::new();
+    let mut kanji = false;
+    let mut i = 0;
+
+    // Byte at `i`, or 0 past the end of the stream
+    let at = |i: usize| bytes.get(i).copied().unwrap_or(0);
+    let u16_at = |i: usize| u16::from_le_bytes([at(i), at(i + 1)]) as usize;
+
+    while i < bytes.len() {
+        let b = bytes[i];
+        let (name, head, len) = match b {
+            ESC => {
+                let cmd = at(i + 1);
+                let len = match cmd {
+                    b'@' | b'2' => 0,
+                    b'$' => 2,
+                    b'p' => 3,
+                    b'*' => {
+                        let per_column = if at(i + 2) >= 32 { 3 } else { 1 };
+                        3 + u16_at(i + 3) * per_column
+                    }
+                    _ => 1, // ESC a/E/!/-/t/3/R/J/M/d …
+                };
+                (format!("ESC {}", cmd as char), 2, len)
+            }
+            GS => {
+                let cmd = at(i + 1);
+                match cmd {
+                    b'(' | b'8' => {
+                        let func = at(i + 2);
+                        let len = if cmd == b'8' {
+                            u32::from_le_bytes([at(i + 3), at(i + 4), at(i + 5), at(i + 6)])
+                                as usize
+                                + 4
+                        } else {
+                            u16_at(i + 3) + 2
+                        };
+                        (format!("GS {} {}", cmd as char, func as char), 3, len)
+                    }
+                    b'v' => {
+                        let func = at(i + 2);
+                        let len = 5 + u16_at(i + 4) * u16_at(i + 6);
+                        (format!("GS v {}", func as char), 3, len)
+                    }
+                    b'V' => ("GS V".to_owned(), 2, if at(i + 2) >= 65 { 2 } else { 1 }),
+                    b'k' => {
+                        let m = at(i + 2);
+                        let len = if m >= 65 {
+                            2 + at(i + 3) as usize
+                        } else {
+                            // NUL-terminated data
+                            let rest = bytes.get(i + 3..).unwrap_or(&[]);
+                            2 + rest.iter().position(|&c| c == 0).unwrap_or(rest.len())
+                        };
+                        ("GS k".to_owned(), 2, len)
+                    }
+                    b'L' | b'W' => (format!("GS {}", cmd as char), 2, 2),
+                    _ => (format!("GS {}", cmd as char), 2, 1), // GS H/f/h/w/B/! …
+                }
+            }
+            FS => {
+                let cmd = at(i + 1);
+                let len = match cmd {
+                    b'&' | b'.' => 0,
+                    b'p' => 2,
+                    b'q' => {
+                        // n images, each xL xH yL yH + x * y * 8 bytes
+                        let mut len = 1;
+                        for _ in 0..at(i + 2) {
+                            let start = i + 2 + len;
+                            len += 4 + u16_at(start) * u16_at(start + 2) * 8;
+                        }
+                        len
+                    }
+                    _ => 1,
+                };
+                match cmd {
+                    b'&' => kanji = true,
+                    b'.' => kanji = false,
+                    _ => {}
+                }
+                (format!("FS {}", cmd as char), 2, len)
+            }
+            DLE => {
+                let name = match at(i + 1) {
+                    0x04 => "DLE EOT".to_owned(),
+                    0x05 => "DLE ENQ".to_owned(),
+                    0x14 => "DLE DC4".to_owned(),
+                    other => format!("DLE {:#04x}", other),
+                };
+                let len = if at(i + 1) == 0x14 { 3 } else { 1 };
+                (name, 2, len)
+            }
+            LF | FF => {
+                flush(&mut tokens, &mut text, i);
+                let token = if b == LF {
+                    Token::LineFeed
+                } else {
+                    Token::FormFeed
+                };
+                tokens.push((token, i + 1));
+                i += 1;
+                continue;
+            }
+            _ if kanji => {
+                text.push(crate::preview::kanji_char(b, at(i + 1)));
+                i += 2;
+                continue;
+            }
+            0x00..=0x1F | 0x7F => {
+                flush(&mut tokens, &mut text, i);
+                tokens.push((Token::Control(b), i + 1));
+                i += 1;
+                continue;
+            }
+            _ => {
+                text.push(cp858_char(b));
+                i += 1;
+                continue;
+            }
+        };
+
+        flush(&mut tokens, &mut text, i);
+        let start = (i + head).min(bytes.len());
+        let end = start.saturating_add(len).min(bytes.len());
+        let params = bytes[start..end].to_vec();
+        i = end.max(i + 1);
+        tokens.push((Token::Command { name, params }, i));
+    }
+    flush(&mut tokens, &mut text, bytes.len());
+    tokens
+}
+
+/// Emit the pending text run, if any, ending at offset `end`.
+fn flush(tokens: &mut Vec<(Token, usize)>, text: &mut String, end: usize) {
+    if !text.is_empty() {
+        tokens.push((Token::Text(std::mem::take(text)), end));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PrintWidth;
+    use crate::{commands, ReceiptBuilder};
+
+    #[test]
+    fn splits_commands_and_text() {
+        let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+            .align_center()
+            .text_line("Reçu n°1")
+            .cut()
+            .build();
+        assert_eq!(
+            tokenize(&bytes),
+            vec![
+                Token::command("ESC a", &[1]),
+                Token::Text("Reçu n°1".to_owned()),
+                Token::LineFeed,
+                Token::command("GS V", &[66, 0]),
+            ]
+        );
+    }
+
+    #[test]
+    fn payload_commands_consume_their_data() {
+        let mut bytes = commands::raster_image(1, 2, &[0x0A, 0x0A]);
+        bytes.extend(commands::barcode_code128("A\nB"));
+        bytes.extend(commands::qr_code("x\ny", 4));
+        bytes.extend(commands::real_time_status(2));
+        bytes.extend(b"end");
+
+        let tokens = tokenize(&bytes);
+        let (last, commands) = tokens.split_last().unwrap();
+        assert_eq!(*last, Token::Text("end".to_owned()));
+        assert!(commands[0].is_command("GS v 0"));
+        assert!(commands[1].is_command("GS k"));
+        let (status, qr) = commands[2..].split_last().unwrap();
+        assert!(qr.iter().all(|t| t.is_command("GS ( k")));
+        assert_eq!(*status, Token::command("DLE EOT", &[2]));
+    }
+
+    #[test]
+    fn truncated_commands_do_not_panic() {
+        for bytes in [
+            &[GS][..],
+            &[GS, b'k'],
+            &[GS, b'k', 73, 10, b'A'],
+            &[GS, b'(', b'k', 9],
+            &[FS, b'q', 2, 1, 0],
+            &[ESC, b'*', 33, 0xFF, 0xFF],
+        ] {
+            tokenize(bytes);
+        }
+    }
+}
@@ -0,0 +1,298 @@
+//! Printer command dialects.
+//!
+//! Most receipt printers speak Epson ESC/POS, which is what [`commands`]
+//! generates. Star printers in Star Line Mode use different sequences for a
+//! handful of commands; selecting [`Dialect::Star`] with
+//! [`ReceiptBuilder::dialect`](crate::ReceiptBuilder::dialect) routes those
+//! through Star byte generators:
+//!
+//! | Operation | Epson | Star |
+//! |---|---|---|
+//! | Code page 858 | `ESC t 19` | `ESC GS t 4` |
+//! | Bold on / off | `ESC E 1` / `ESC E 0` | `ESC E` / `ESC F` |
+//! | Alignment | `ESC a n` | `ESC GS a n` |
+//! | Partial / full cut | `GS V 66 0` / `GS V 0` | `ESC d 3` / `ESC d 2` |
+//! | Cut after feeding | `GS V 66 n` / `GS V 65 n` | `ESC J n` + `ESC d 1` / `ESC d 0` |
+//! | Feed `n` lines | `ESC d n` | `ESC a n` |
+//! | Feed `n` dots | `ESC J n` | `ESC J n/2` (1/4 mm steps) |
+//! | Barcodes | `GS w` `GS h` `GS H` `GS f` `GS k` | `ESC b n1 n2 n3 n4 ... RS` |
+//! | QR code | `GS ( k` | `ESC GS y` |
+//!
+//! Text, sizes, margins and images are sent as ESC/POS in both dialects;
+//! most Star models accept them. [`preview`](crate::preview) and
+//! [`debug::tokenize`](crate::debug::tokenize) only understand Epson streams.
+
+use crate::commands::{self, ESC, GS};
+use crate::types::{Align, HriFont, HriPosition, QrErrorCorrection};
+
+/// `RS` — terminates Star barcode data.
+const RS: u8 = 0x1E;
+
+/// Command set spoken by the target printer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Dialect {
+    /// Epson ESC/POS (default), also used by most compatible printers
+    #[default]
+    Epson,
+    /// Star Line Mode (TSP100, TSP650, mC-Print, ...)
+    Star,
+}
+
+/// One-dimensional barcode symbologies the builder prints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Symbology {
+    /// CODE128: printable ASCII, up to 255 encoded bytes
+    Code128,
+    /// EAN-13: 12 digits (check digit added by the printer) or 13
+    Ean13,
+    /// EAN-8: 7 digits (check digit added by the printer)
+    Ean8,
+}
+
+impl Dialect {
+    /// Select code page 858 for the text that follows.
+    pub(crate) fn code_page_858(self) -> &'static [u8] {
+        match self {
+            Dialect::Epson => commands::code_page_858(),
+            Dialect::Star => &[ESC, GS, b't', 4],
+        }
+    }
+
+    /// Turn bold text on or off.
+    pub(crate) fn bold(self, on: bool) -> &'static [u8] {
+        match (self, on) {
+            (Dialect::Epson, true) => commands::bold_on(),
+            (Dialect::Epson, false) => commands::bold_off(),
+            (Dialect::Star, true) => &[ESC, b'E'],
+            (Dialect::Star, false) => &[ESC, b'F'],
+        }
+    }
+
+    /// Align the lines that follow.
+    pub(crate) fn align(self, align: Align) -> &'static [u8] {
+        match (self, align) {
+            (Dialect::Epson, Align::Left) => commands::align_left(),
+            (Dialect::Epson, Align::Center) => commands::align_center(),
+            (Dialect::Epson, Align::Right) => commands::align_right(),
+            (Dialect::Star, Align::Left) => &[ESC, GS, b'a', 0],
+            (Dialect::Star, Align::Center) => &[ESC, GS, b'a', 1],
+            (Dialect::Star, Align::Right) => &[ESC, GS, b'a', 2],
+        }
+    }
+
+    /// Feed `n` lines. `ESC d n` would cut on Star, which feeds with
+    /// `ESC a n` instead.
+    pub(crate) fn feed_lines(self, n: u8) -> Vec<u8> {
+        match self {
+            Dialect::Epson => commands::feed_lines(n),
+            Dialect::Star => vec![ESC, b'a', n],
+        }
+    }
+
+    /// Feed `n` dots (1/203"); Star feeds in 1/4 mm steps, so `n` is
+    /// halved and rounded up.
+    pub(crate) fn feed_dots(self, n: u8) -> Vec<u8> {
+        match self {
+            Dialect::Epson => commands::feed_dots(n),
+            Dialect::Star => vec![ESC, b'J', n.div_ceil(2)],
+        }
+    }
+
+    /// Cut the paper, optionally after feeding `feed` motion units.
+    ///
+    /// Star feeds in 1/4 mm steps, so `feed` (Epson units, usually 1/203")
+    /// is halved and rounded up.
+    pub(crate) fn cut(self, full: bool, feed: Option<u8>) -> Vec<u8> {
+        match (self, feed) {
+            (Dialect::Epson, None) if full => commands::cut_full().to_vec(),
+            (Dialect::Epson, None) => commands::cut_partial().to_vec(),
+            (Dialect::Epson, Some(n)) if full => commands::cut_full_feed(n),
+            (Dialect::Epson, Some(n)) => commands::cut_partial_feed(n),
+            // ESC d 2 / 3: feed to the cutter, then full / partial cut
+            (Dialect::Star, None) => vec![ESC, b'd', if full { 2 } else { 3 }],
+            // ESC d 0 / 1: cut at the current position
+            (Dialect::Star, Some(n)) => {
+                vec![ESC, b'J', n.div_ceil(2), ESC, b'd', u8::from(!full)]
+            }
+        }
+    }
+
+    /// `true` if `bytes` ends in a cut of this dialect.
+    ///
+    /// `ESC d n` is a cut on Star but "feed n lines" on Epson, so a trailing
+    /// feed is not mistaken for a cut.
+    pub(crate) fn ends_with_cut(self, bytes: &[u8]) -> bool {
+        match self {
+            Dialect::Epson => matches!(
+                *bytes,
+                [.., GS, b'V', 65 | 66, _] | [.., GS, b'V', 0 | 1 | 48 | 49]
+            ),
+            Dialect::Star => matches!(*bytes, [.., ESC, b'd', 0..=3]),
+        }
+    }
+
+    /// Print a barcode with the given module width and height in dots.
+    ///
+    /// Star printers only place HRI text below the bars and ignore `font`.
+    pub(crate) fn barcode(
+        self,
+        symbology: Symbology,
+        value: &str,
+        width: u8,
+        height: u8,
+        hri: HriPosition,
+        font: HriFont,
+    ) -> Vec<u8> {
+        match self {
+            Dialect::Epson => {
+                let mut cmd = commands::barcode_width(width);
+                cmd.extend(commands::barcode_height(height));
+                cmd.extend(commands::barcode_hri_position(hri.code()));
+                cmd.extend(commands::barcode_hri_font(font.code()));
+                cmd.extend(match symbology {
+                    Symbology::Code128 => commands::barcode_code128(value),
+                    Symbology::Ean13 => commands::barcode_ean13(value),
+                    Symbology::Ean8 => commands::barcode_ean8(value),
+                });
+                cmd
+            }
+            Dialect::Star => {
+                let kind = match symbology {
+                    Symbology::Code128 => b'6',
+                    Symbology::Ean13 => b'3',
+                    Symbology::Ean8 => b'2',
+                };
+                // 3: no HRI, 4: HRI below — neither feeds a line
+                let text = if hri == HriPosition::None { b'3' } else { b'4' };
+                // Modes 1–3 are 2–4 dot modules
+                let mode = b'0' + width.saturating_sub(1).clamp(1, 3);
+                let mut cmd = vec![ESC, b'b', kind, text, mode, height];
+                cmd.extend_from_slice(value.as_bytes());
+                cmd.push(RS);
+                cmd
+            }
+        }
+    }
+
+    /// Print a model 2 QR code with `size`-dot cells.
+    pub(crate) fn qr_code(self, data: &[u8], size: u8, ecc: QrErrorCorrection) -> Vec<u8> {
+        match self {
+            Dialect::Epson => commands::qr_code_bytes(data, size, ecc),
+            Dialect::Star => {
+                let len = data.len() as u16;
+                let mut cmd = vec![ESC, GS, b'y', b'S', b'0', 2]; // model 2
+                cmd.extend_from_slice(&[ESC, GS, b'y', b'S', b'1', ecc.index()]);
+                cmd.extend_from_slice(&[ESC, GS, b'y', b'S', b'2', size]);
+                let [lo, hi] = len.to_le_bytes();
+                cmd.extend_from_slice(&[ESC, GS, b'y', b'D', b'1', 0, lo, hi]);
+                cmd.extend_from_slice(data);
+                cmd.extend_from_slice(&[ESC, GS, b'y', b'P']);
+                cmd
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn epson_matches_commands() {
+        let e = Dialect::Epson;
+        assert_eq!(e.code_page_858(), commands::code_page_858());
+        assert_eq!(e.bold(true), commands::bold_on());
+        assert_eq!(e.bold(false), commands::bold_off());
+        assert_eq!(e.align(Align::Center), commands::align_center());
+        assert_eq!(e.cut(false, None), commands::cut_partial());
+        assert_eq!(e.cut(true, None), commands::cut_full());
+        assert_eq!(e.cut(false, Some(40)), commands::cut_partial_feed(40));
+        assert_eq!(e.cut(true, Some(40)), commands::cut_full_feed(40));
+        assert_eq!(
+            e.qr_code(b"hi", 4, QrErrorCorrection::M),
+            commands::qr_code("hi", 4)
+        );
+    }
+
+    #[test]
+    fn detects_trailing_cut() {
+        for dialect in [Dialect::Epson, Dialect::Star] {
+            for feed in [None, Some(0), Some(40)] {
+                assert!(dialect.ends_with_cut(&dialect.cut(false, feed)));
+                assert!(dialect.ends_with_cut(&dialect.cut(true, feed)));
+            }
+            assert!(!dialect.ends_with_cut(b"TOTAL\n"));
+            assert!(!dialect.ends_with_cut(&[]));
+        }
+        // Epson `ESC d 3` feeds three lines
+        assert!(!Dialect::Epson.ends_with_cut(&commands::feed_lines(3)));
+    }
+
+    #[test]
+    fn star_cut_sequences() {
+        let s = Dialect::Star;
+        assert_eq!(s.cut(false, None), vec![ESC, b'd', 3]);
+        assert_eq!(s.cut(true, None), vec![ESC, b'd', 2]);
+        assert_eq!(s.cut(false, Some(41)), vec![ESC, b'J', 21, ESC, b'd', 1]);
+        assert_eq!(s.cut(true, Some(40)), vec![ESC, b'J', 20, ESC, b'd', 0]);
+    }
+
+    #[test]
+    fn star_bold_and_alignment() {
+        let s = Dialect::Star;
+        assert_eq!(s.bold(true), [ESC, b'E']);
+        assert_eq!(s.bold(false), [ESC, b'F']);
+        assert_eq!(s.align(Align::Left), [ESC, GS, b'a', 0]);
+        assert_eq!(s.align(Align::Center), [ESC, GS, b'a', 1]);
+        assert_eq!(s.align(Align::Right), [ESC, GS, b'a', 2]);
+    }
+
+    #[test]
+    fn feed_dots_in_dialect_units() {
+        assert_eq!(Dialect::Epson.feed_dots(15), vec![ESC, b'J', 15]);
+        assert_eq!(Dialect::Star.feed_dots(15), vec![ESC, b'J', 8]);
+    }
+
+    #[test]
+    fn star_feeds_lines_without_cutting() {
+        assert_eq!(Dialect::Epson.feed_lines(3), commands::feed_lines(3));
+        let feed = Dialect::Star.feed_lines(3);
+        assert_eq!(feed, vec![ESC, b'a', 3]);
+        assert!(!Dialect::Star.ends_with_cut(&feed));
+    }
+
+    #[test]
+    fn star_barcode_is_one_command() {
+        let cmd = Dialect::Star.barcode(
+            Symbology::Code128,
+            "ORD-1",
+            2,
+            60,
+            HriPosition::Below,
+            HriFont::A,
+        );
+        assert_eq!(cmd[..6], [ESC, b'b', b'6', b'4', b'1', 60]);
+        assert_eq!(&cmd[6..11], b"ORD-1");
+        assert_eq!(cmd.last(), Some(&RS));
+
+        let cmd = Dialect::Star.barcode(
+            Symbology::Ean13,
+            "123456789012",
+            3,
+            80,
+            HriPosition::None,
+            HriFont::B,
+        );
+        assert_eq!(cmd[..6], [ESC, b'b', b'3', b'3', b'2', 80]);
+    }
+
+    #[test]
+    fn star_qr_stores_then_prints() {
+        let cmd = Dialect::Star.qr_code(b"https://example.com", 5, QrErrorCorrection::M);
+        assert!(cmd.windows(6).any(|w| w == [ESC, GS, b'y', b'S', b'2', 5]));
+        assert!(cmd
+            .windows(8)
+            .any(|w| w == [ESC, GS, b'y', b'D', b'1', 0, 19, 0]));
+        assert!(cmd.ends_with(&[ESC, GS, b'y', b'P']));
+    }
+}
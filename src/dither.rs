@@ -24,6 +24,7 @@
 //! ```
 
 use crate::commands;
+use crate::types::RasterMode;
 
 /// Dithering algorithm to use.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -33,6 +34,59 @@ pub enum DitherMethod {
     /// Floyd-Steinberg error-diffusion dithering.
     /// Produces much better results for photographs and gradients.
     FloydSteinberg,
+    /// Sierra Lite: a three-neighbour kernel, faster than Floyd-Steinberg
+    /// with similar quality.
+    SierraLite,
+    /// Jarvis-Judice-Ninke: spreads error over twelve neighbours in three
+    /// rows, giving smoother gradients with less worm-like patterning.
+    Jjn,
+}
+
+/// Sampling filter used when an image is scaled down to the printable width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResizeFilter {
+    /// Bilinear interpolation — smooth, best for photos (default).
+    #[default]
+    Bilinear,
+    /// Nearest-neighbour sampling — keeps hard edges in line-art logos and
+    /// QR-like graphics.
+    Nearest,
+}
+
+/// All dithering settings, for [`dither_rgba_with`].
+///
+/// ```rust
+/// use thermoprint::dither::{dither_rgba_with, DitherOptions};
+///
+/// let rgba = [128u8, 128, 128, 255].repeat(8);
+/// let opts = DitherOptions { gamma: 2.2, ..DitherOptions::default() };
+/// let raster = dither_rgba_with(&rgba, 8, 1, 384, &opts);
+/// assert_eq!(raster.len(), 9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DitherOptions {
+    /// Dithering algorithm (default: Floyd-Steinberg)
+    pub method: DitherMethod,
+    /// Bit-image command family (default: `GS v 0`)
+    pub mode: RasterMode,
+    /// Filter used when scaling down (default: bilinear)
+    pub filter: ResizeFilter,
+    /// Gamma applied to the grayscale image before dithering:
+    /// `v = 255 * (v / 255)^gamma`. Values above 1.0 darken midtones, which
+    /// keeps detail in photos that thermal heads otherwise wash out.
+    /// `1.0` (default) leaves the image unchanged.
+    pub gamma: f32,
+}
+
+impl Default for DitherOptions {
+    fn default() -> Self {
+        Self {
+            method: DitherMethod::FloydSteinberg,
+            mode: RasterMode::GsV0,
+            filter: ResizeFilter::Bilinear,
+            gamma: 1.0,
+        }
+    }
 }
 
 /// Convert RGBA pixel data to ESC/POS raster bytes using the specified
@@ -53,6 +107,65 @@ pub fn dither_rgba(
     height: u32,
     max_width_px: u32,
     method: DitherMethod,
+) -> Vec<u8> {
+    dither_rgba_mode(rgba, width, height, max_width_px, method, RasterMode::GsV0)
+}
+
+/// Like [`dither_rgba`], but packs the result for the given [`RasterMode`].
+///
+/// Use [`RasterMode::EscStar`] for older printers that only understand the
+/// `ESC *` column bit-image command.
+/// [`RasterMode::EscStarSingle`] prints every column two dots wide, so the
+/// image is scaled to half of `max_width_px`.
+pub fn dither_rgba_mode(
+    rgba: &[u8],
+    width: u32,
+    height: u32,
+    max_width_px: u32,
+    method: DitherMethod,
+    mode: RasterMode,
+) -> Vec<u8> {
+    dither_rgba_filtered(
+        rgba,
+        width,
+        height,
+        max_width_px,
+        method,
+        mode,
+        ResizeFilter::Bilinear,
+    )
+}
+
+/// Like [`dither_rgba_mode`], but with an explicit [`ResizeFilter`] for
+/// images wider than `max_width_px`.
+///
+/// Use [`ResizeFilter::Nearest`] for crisp black-and-white logos.
+pub fn dither_rgba_filtered(
+    rgba: &[u8],
+    width: u32,
+    height: u32,
+    max_width_px: u32,
+    method: DitherMethod,
+    mode: RasterMode,
+    filter: ResizeFilter,
+) -> Vec<u8> {
+    let opts = DitherOptions {
+        method,
+        mode,
+        filter,
+        ..DitherOptions::default()
+    };
+    dither_rgba_with(rgba, width, height, max_width_px, &opts)
+}
+
+/// Convert RGBA pixel data to ESC/POS raster bytes with every setting in
+/// [`DitherOptions`], including gamma correction.
+pub fn dither_rgba_with(
+    rgba: &[u8],
+    width: u32,
+    height: u32,
+    max_width_px: u32,
+    opts: &DitherOptions,
 ) -> Vec<u8> {
     assert_eq!(
         rgba.len(),
@@ -61,16 +174,65 @@ pub fn dither_rgba(
     );
 
     // Convert RGBA to grayscale float buffer
-    let (gray, w, h) = to_grayscale_resized(rgba, width, height, max_width_px);
+    let max_width_px = max_width_px / opts.mode.dot_width();
+    let (gray, w, h) = to_grayscale_resized(rgba, width, height, max_width_px, opts.filter);
+    dither_and_pack(gray, w, h, opts)
+}
 
-    // Apply dithering → 1-bit
-    let mono = match method {
-        DitherMethod::Threshold => threshold(&gray, w, h),
-        DitherMethod::FloydSteinberg => floyd_steinberg(&gray, w, h),
-    };
+/// Like [`dither_rgba_with`], but scales the image to exactly `target_px`
+/// wide (up or down, keeping the aspect ratio) instead of only shrinking
+/// images wider than the paper — e.g. a 100 px icon on an 80mm receipt.
+///
+/// Clamp `target_px` to [`PrintWidth::max_image_px`](crate::PrintWidth::max_image_px)
+/// yourself; a target of 0 is treated as 1.
+///
+/// ```rust
+/// use thermoprint::dither::{dither_rgba_sized, DitherOptions};
+///
+/// let rgba = [0u8, 0, 0, 255].repeat(384 * 10);
+/// let raster = dither_rgba_sized(&rgba, 384, 10, 96, &DitherOptions::default());
+/// assert_eq!(raster[4], 12); // 96 px → 12 bytes per line
+/// ```
+pub fn dither_rgba_sized(
+    rgba: &[u8],
+    width: u32,
+    height: u32,
+    target_px: u32,
+    opts: &DitherOptions,
+) -> Vec<u8> {
+    assert_eq!(
+        rgba.len(),
+        (width * height * 4) as usize,
+        "RGBA data length mismatch"
+    );
 
-    // Pack into ESC/POS raster
-    pack_raster(&mono, w, h)
+    let (gray, _, _) = to_grayscale_resized(rgba, width, height, width, opts.filter);
+    let (gray, w, h) = resize_gray(
+        &gray,
+        width,
+        height,
+        (target_px / opts.mode.dot_width()).max(1),
+        opts.filter,
+    );
+    dither_and_pack(gray, w, h, opts)
+}
+
+/// Dither for a printable width of `max_width_px`: scaled to exactly
+/// `target_px` when set (clamped to `max_width_px`), otherwise only shrunk
+/// to fit, as the WASM `dither_image` does.
+#[cfg(any(test, all(feature = "wasm", target_arch = "wasm32")))]
+pub(crate) fn dither_rgba_within(
+    rgba: &[u8],
+    width: u32,
+    height: u32,
+    max_width_px: u32,
+    target_px: Option<u32>,
+    opts: &DitherOptions,
+) -> Vec<u8> {
+    match target_px {
+        Some(px) => dither_rgba_sized(rgba, width, height, px.min(max_width_px), opts),
+        None => dither_rgba_with(rgba, width, height, max_width_px, opts),
+    }
 }
 
 /// Convert RGBA pixel data to ESC/POS raster bytes using simple threshold.
@@ -95,12 +257,29 @@ pub fn floyd_steinberg_rgba(rgba: &[u8], width: u32, height: u32, max_width_px:
 
 // ── Internal helpers ─────────────────────────────────────────────────────────
 
+/// Gamma-correct, dither to 1-bit and pack a `w × h` grayscale buffer.
+fn dither_and_pack(mut gray: Vec<f32>, w: u32, h: u32, opts: &DitherOptions) -> Vec<u8> {
+    apply_gamma(&mut gray, opts.gamma);
+
+    // Apply dithering → 1-bit
+    let mono = match opts.method {
+        DitherMethod::Threshold => threshold(&gray, w, h),
+        DitherMethod::FloydSteinberg => floyd_steinberg(gray, w, h),
+        DitherMethod::SierraLite => error_diffusion(gray, w, h, &SIERRA_LITE, 4.0),
+        DitherMethod::Jjn => error_diffusion(gray, w, h, &JJN, 48.0),
+    };
+
+    // Pack into ESC/POS raster
+    pack_raster(&mono, w, h, opts.mode)
+}
+
 /// Convert RGBA to grayscale f32 buffer, optionally resizing if too wide.
 fn to_grayscale_resized(
     rgba: &[u8],
     width: u32,
     height: u32,
     max_width_px: u32,
+    filter: ResizeFilter,
 ) -> (Vec<f32>, u32, u32) {
     // First convert to grayscale at original size
     let mut gray: Vec<f32> = Vec::with_capacity((width * height) as usize);
@@ -117,13 +296,37 @@ fn to_grayscale_resized(
     if width <= max_width_px {
         return (gray, width, height);
     }
+    resize_gray(&gray, width, height, max_width_px, filter)
+}
 
-    // Bilinear downscale
-    let new_w = max_width_px;
-    let new_h = ((height as u64 * max_width_px as u64) / width as u64) as u32;
+/// Scale a grayscale buffer to `new_w` pixels wide, keeping the aspect
+/// ratio. Works both ways, though only [`dither_rgba_sized`] scales up.
+fn resize_gray(
+    gray: &[f32],
+    width: u32,
+    height: u32,
+    new_w: u32,
+    filter: ResizeFilter,
+) -> (Vec<f32>, u32, u32) {
+    let new_h = ((height as u64 * new_w as u64) / width as u64) as u32;
     let new_h = new_h.max(1);
     let mut resized = Vec::with_capacity((new_w * new_h) as usize);
 
+    if filter == ResizeFilter::Nearest {
+        // Sample the source pixel under the centre of each target pixel
+        let src = |dst: u32, dst_len: u32, src_len: u32| {
+            ((2 * dst as u64 + 1) * src_len as u64 / (2 * dst_len as u64)) as u32
+        };
+        for y in 0..new_h {
+            let row = src(y, new_h, height) * width;
+            for x in 0..new_w {
+                resized.push(gray[(row + src(x, new_w, width)) as usize]);
+            }
+        }
+        return (resized, new_w, new_h);
+    }
+
+    // Bilinear downscale
     for y in 0..new_h {
         for x in 0..new_w {
             let src_x = (x as f32 * (width - 1) as f32) / (new_w - 1).max(1) as f32;
@@ -154,6 +357,17 @@ fn to_grayscale_resized(
     (resized, new_w, new_h)
 }
 
+/// Gamma-correct a 0–255 grayscale buffer in place, clamping to 0–255.
+/// A gamma of 1.0, or one that isn't a positive finite number, is a no-op.
+fn apply_gamma(gray: &mut [f32], gamma: f32) {
+    if gamma == 1.0 || !(gamma.is_finite() && gamma > 0.0) {
+        return;
+    }
+    for v in gray {
+        *v = (255.0 * (*v / 255.0).clamp(0.0, 1.0).powf(gamma)).clamp(0.0, 255.0);
+    }
+}
+
 /// Simple threshold: < 128 → black (true), >= 128 → white (false).
 fn threshold(gray: &[f32], width: u32, height: u32) -> Vec<bool> {
     let mut mono = Vec::with_capacity((width * height) as usize);
@@ -164,41 +378,116 @@ fn threshold(gray: &[f32], width: u32, height: u32) -> Vec<bool> {
 }
 
 /// Floyd-Steinberg error-diffusion dithering.
-fn floyd_steinberg(gray: &[f32], width: u32, height: u32) -> Vec<bool> {
+///
+/// Diffuses error in place in `gray`, one row at a time: the current and
+/// next rows are split off as slices so the inner loop does no `y * w`
+/// index arithmetic.
+fn floyd_steinberg(mut gray: Vec<f32>, width: u32, height: u32) -> Vec<bool> {
     let w = width as usize;
     let h = height as usize;
-    let mut buf = gray.to_vec();
-    let mut mono = vec![false; w * h];
+    let mut mono = Vec::with_capacity(w * h);
+    if w == 0 {
+        return mono;
+    }
+
+    let mut rest = &mut gray[..w * h];
+    while !rest.is_empty() {
+        let (cur, tail) = rest.split_at_mut(w);
+        let mut next = tail.get_mut(..w);
 
-    for y in 0..h {
         for x in 0..w {
-            let idx = y * w + x;
-            let old = buf[idx];
-            let new_val = if old < 128.0 { 0.0 } else { 255.0 };
-            mono[idx] = new_val == 0.0; // black = print
+            let old = cur[x];
+            let black = old < 128.0; // black = print
+            let new_val = if black { 0.0 } else { 255.0 };
+            mono.push(black);
             let err = old - new_val;
 
             // Distribute error to neighbours
             if x + 1 < w {
-                buf[idx + 1] += err * 7.0 / 16.0;
+                cur[x + 1] += err * 7.0 / 16.0;
             }
-            if y + 1 < h {
+            if let Some(next) = next.as_deref_mut() {
                 if x > 0 {
-                    buf[(y + 1) * w + (x - 1)] += err * 3.0 / 16.0;
+                    next[x - 1] += err * 3.0 / 16.0;
                 }
-                buf[(y + 1) * w + x] += err * 5.0 / 16.0;
+                next[x] += err * 5.0 / 16.0;
                 if x + 1 < w {
-                    buf[(y + 1) * w + (x + 1)] += err * 1.0 / 16.0;
+                    next[x + 1] += err * 1.0 / 16.0;
                 }
             }
         }
+        rest = tail;
     }
 
     mono
 }
 
-/// Pack 1-bit monochrome data into a GS v 0 raster command.
-fn pack_raster(mono: &[bool], width: u32, height: u32) -> Vec<u8> {
+/// Sierra Lite kernel as `(dx, dy, weight)`, divisor 4:
+///
+/// ```text
+///     X 2
+///   1 1
+/// ```
+const SIERRA_LITE: [(isize, usize, f32); 3] = [(1, 0, 2.0), (-1, 1, 1.0), (0, 1, 1.0)];
+
+/// Jarvis-Judice-Ninke kernel as `(dx, dy, weight)`, divisor 48:
+///
+/// ```text
+///         X 7 5
+///     3 5 7 5 3
+///     1 3 5 3 1
+/// ```
+const JJN: [(isize, usize, f32); 12] = [
+    (1, 0, 7.0),
+    (2, 0, 5.0),
+    (-2, 1, 3.0),
+    (-1, 1, 5.0),
+    (0, 1, 7.0),
+    (1, 1, 5.0),
+    (2, 1, 3.0),
+    (-2, 2, 1.0),
+    (-1, 2, 3.0),
+    (0, 2, 5.0),
+    (1, 2, 3.0),
+    (2, 2, 1.0),
+];
+
+/// Generic error diffusion with a `(dx, dy, weight)` kernel: each pixel's
+/// quantisation error times `weight / divisor` is added to the neighbour
+/// `dx` columns right and `dy` rows down. Neighbours outside the image are
+/// skipped.
+fn error_diffusion(
+    mut gray: Vec<f32>,
+    width: u32,
+    height: u32,
+    kernel: &[(isize, usize, f32)],
+    divisor: f32,
+) -> Vec<bool> {
+    let w = width as usize;
+    let h = height as usize;
+    let mut mono = Vec::with_capacity(w * h);
+
+    for y in 0..h {
+        for x in 0..w {
+            let old = gray[y * w + x];
+            let black = old < 128.0; // black = print
+            mono.push(black);
+            let err = old - if black { 0.0 } else { 255.0 };
+
+            for &(dx, dy, weight) in kernel {
+                let (nx, ny) = (x as isize + dx, y + dy);
+                if nx >= 0 && (nx as usize) < w && ny < h {
+                    gray[ny * w + nx as usize] += err * weight / divisor;
+                }
+            }
+        }
+    }
+
+    mono
+}
+
+/// Pack 1-bit monochrome data into a `GS v 0` or `ESC *` bit-image command.
+fn pack_raster(mono: &[bool], width: u32, height: u32, mode: RasterMode) -> Vec<u8> {
     let bytes_per_line = width.div_ceil(8) as usize;
     let mut raster = Vec::with_capacity(bytes_per_line * height as usize);
 
@@ -214,13 +503,32 @@ fn pack_raster(mono: &[bool], width: u32, height: u32) -> Vec<u8> {
         raster.extend_from_slice(&row);
     }
 
-    commands::raster_image(bytes_per_line as u16, height as u16, &raster)
+    match mode {
+        RasterMode::GsV0 => commands::raster_image(bytes_per_line as u16, height as u16, &raster),
+        RasterMode::EscStar => commands::column_image(width as u16, height as u16, &raster),
+        RasterMode::EscStarSingle => {
+            commands::column_image_single(width as u16, height as u16, &raster)
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn target_width_is_clamped_to_the_paper() {
+        let rgba = [0u8, 0, 0, 255].repeat(100 * 10);
+        let opts = DitherOptions::default();
+        // 1000 px on 384 px paper: 48 bytes per line, not 125
+        let raster = dither_rgba_within(&rgba, 100, 10, 384, Some(1000), &opts);
+        assert_eq!(&raster[4..6], [48, 0]);
+        let raster = dither_rgba_within(&rgba, 100, 10, 384, Some(200), &opts);
+        assert_eq!(&raster[4..6], [25, 0]);
+        let raster = dither_rgba_within(&rgba, 100, 10, 384, None, &opts);
+        assert_eq!(&raster[4..6], [13, 0]);
+    }
+
     #[test]
     fn solid_black_4x1() {
         let rgba = vec![0u8, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255];
@@ -252,6 +560,41 @@ mod tests {
         assert_eq!(result.len(), 8 + 2);
     }
 
+    #[test]
+    fn diffusion_kernels_match_floyd_steinberg_size() {
+        // 20x3 horizontal gradient, wider than one byte and with a partial one
+        let rgba: Vec<u8> = (0..20 * 3)
+            .flat_map(|i| {
+                let v = (i % 20 * 12) as u8;
+                [v, v, v, 255]
+            })
+            .collect();
+        let fs = dither_rgba(&rgba, 20, 3, 384, DitherMethod::FloydSteinberg);
+        assert_eq!(fs.len(), 8 + 3 * 3);
+        for method in [DitherMethod::SierraLite, DitherMethod::Jjn] {
+            let out = dither_rgba(&rgba, 20, 3, 384, method);
+            assert_eq!(out.len(), fs.len());
+            assert_eq!(out[..8], fs[..8]);
+        }
+    }
+
+    #[test]
+    fn diffusion_kernel_weights_sum_to_divisor() {
+        let sum = |k: &[(isize, usize, f32)]| k.iter().map(|&(_, _, w)| w).sum::<f32>();
+        assert_eq!(sum(&SIERRA_LITE), 4.0);
+        assert_eq!(sum(&JJN), 48.0);
+    }
+
+    #[test]
+    fn mid_gray_dithers_to_about_half_black() {
+        let rgba = [128u8, 128, 128, 255].repeat(16 * 16);
+        for method in [DitherMethod::SierraLite, DitherMethod::Jjn] {
+            let out = dither_rgba(&rgba, 16, 16, 384, method);
+            let black: u32 = out[8..].iter().map(|b| b.count_ones()).sum();
+            assert!((96..=160).contains(&black), "{:?}: {}", method, black);
+        }
+    }
+
     #[test]
     fn transparent_pixels_become_white() {
         // Fully transparent pixel → should become white (not printed)
@@ -270,6 +613,120 @@ mod tests {
         assert_eq!(result[8], 0xFF); // all black
     }
 
+    #[test]
+    fn esc_star_mode_emits_column_image() {
+        let rgba = vec![0u8, 0, 0, 255, 0, 0, 0, 255];
+        let result = dither_rgba_mode(
+            &rgba,
+            2,
+            1,
+            384,
+            DitherMethod::Threshold,
+            RasterMode::EscStar,
+        );
+        assert_eq!(&result[3..8], &[0x1B, b'*', 33, 2, 0]);
+        assert_eq!(&result[8..14], &[0x80, 0, 0, 0x80, 0, 0]);
+    }
+
+    #[test]
+    fn gamma_one_is_identity() {
+        let mut gray: Vec<f32> = (0..=255).map(|v| v as f32).collect();
+        let before = gray.clone();
+        apply_gamma(&mut gray, 1.0);
+        assert_eq!(gray, before);
+    }
+
+    #[test]
+    fn gamma_above_one_darkens_midtones() {
+        let mut gray = vec![0.0, 128.0, 255.0];
+        apply_gamma(&mut gray, 2.2);
+        assert_eq!(gray[0], 0.0);
+        assert!((gray[1] - 56.0).abs() < 1.0, "{}", gray[1]);
+        assert_eq!(gray[2], 255.0);
+
+        // A mid-grey that thresholds to white prints black after gamma
+        let rgba = [150u8, 150, 150, 255].repeat(8);
+        let plain = DitherOptions {
+            method: DitherMethod::Threshold,
+            ..DitherOptions::default()
+        };
+        let dark = DitherOptions {
+            gamma: 2.2,
+            ..plain
+        };
+        assert_eq!(dither_rgba_with(&rgba, 8, 1, 384, &plain)[8], 0x00);
+        assert_eq!(dither_rgba_with(&rgba, 8, 1, 384, &dark)[8], 0xFF);
+    }
+
+    /// The original indexed implementation, kept to check the fast path.
+    fn floyd_steinberg_reference(gray: &[f32], width: u32, height: u32) -> Vec<bool> {
+        let w = width as usize;
+        let h = height as usize;
+        let mut buf = gray.to_vec();
+        let mut mono = vec![false; w * h];
+
+        for y in 0..h {
+            for x in 0..w {
+                let idx = y * w + x;
+                let old = buf[idx];
+                let new_val = if old < 128.0 { 0.0 } else { 255.0 };
+                mono[idx] = new_val == 0.0; // black = print
+                let err = old - new_val;
+
+                // Distribute error to neighbours
+                if x + 1 < w {
+                    buf[idx + 1] += err * 7.0 / 16.0;
+                }
+                if y + 1 < h {
+                    if x > 0 {
+                        buf[(y + 1) * w + (x - 1)] += err * 3.0 / 16.0;
+                    }
+                    buf[(y + 1) * w + x] += err * 5.0 / 16.0;
+                    if x + 1 < w {
+                        buf[(y + 1) * w + (x + 1)] += err * 1.0 / 16.0;
+                    }
+                }
+            }
+        }
+
+        mono
+    }
+
+    #[test]
+    fn floyd_steinberg_matches_reference() {
+        for (w, h) in [(384u32, 500u32), (7, 3), (1, 5), (5, 1)] {
+            let gray: Vec<f32> = (0..w * h)
+                .map(|i| ((i * 37 + i / w * 11) % 256) as f32)
+                .collect();
+            assert_eq!(
+                floyd_steinberg(gray.clone(), w, h),
+                floyd_steinberg_reference(&gray, w, h),
+                "{}x{}",
+                w,
+                h
+            );
+        }
+    }
+
+    #[test]
+    fn nearest_filter_keeps_hard_edges() {
+        // 16×16 checkerboard of 2×2 blocks, scaled to 12 px wide
+        let rgba: Vec<u8> = (0..16u32)
+            .flat_map(|y| (0..16u32).map(move |x| if (x / 2 + y / 2) % 2 == 0 { 0u8 } else { 255 }))
+            .flat_map(|v| [v, v, v, 255])
+            .collect();
+        let is_hard = |g: &[f32]| g.iter().all(|&v| v == 0.0 || v == 255.0);
+
+        let (bilinear, w, h) = to_grayscale_resized(&rgba, 16, 16, 12, ResizeFilter::Bilinear);
+        assert_eq!((w, h), (12, 12));
+        assert!(!is_hard(&bilinear));
+
+        let (nearest, w, h) = to_grayscale_resized(&rgba, 16, 16, 12, ResizeFilter::Nearest);
+        assert_eq!((w, h), (12, 12));
+        assert!(is_hard(&nearest));
+        assert!(nearest.contains(&0.0) && nearest.contains(&255.0));
+    }
+
     #[test]
     fn convenience_functions() {
         let rgba = vec![0u8, 0, 0, 255, 255, 255, 255, 255];
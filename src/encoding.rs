@@ -1,10 +1,50 @@
+use crate::types::Direction;
+
 /// Encode a UTF-8 string to Code Page 858 bytes.
 ///
 /// CP858 is the standard ESC/POS code page for Western European languages.
 /// It supports French, Spanish, Portuguese accented characters and the Euro sign.
-/// Characters outside the mapping fall back to their ASCII byte value.
+/// Common typographic punctuation (curly quotes, dashes, ellipsis, non-breaking
+/// spaces, arrows) is transliterated to ASCII first; anything still outside the
+/// code page is printed as `?`.
+///
+/// Control characters other than tab (`0x00`–`0x1F`, e.g. an `ESC` or a
+/// line feed inside a product name) are replaced with a space, so text can
+/// never inject printer commands; line feeds come from the builder only.
+///
+/// ```rust
+/// use thermoprint::encoding::encode_cp858;
+/// assert_eq!(encode_cp858("Pain\x1B@\n"), b"Pain @ ");
+/// assert_eq!(encode_cp858("Qty\t2"), b"Qty\t2");
+/// ```
 pub fn encode_cp858(text: &str) -> Vec<u8> {
-    text.chars().map(cp858_byte).collect()
+    let mut out = Vec::with_capacity(text.len());
+    for c in text.chars() {
+        match transliterate(c) {
+            Some(ascii) => out.extend_from_slice(ascii.as_bytes()),
+            None => out.push(cp858_byte(c)),
+        }
+    }
+    out
+}
+
+/// ASCII replacement for Unicode punctuation that CP858 cannot print.
+fn transliterate(c: char) -> Option<&'static str> {
+    Some(match c {
+        '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{201B}' | '\u{2032}' => "'",
+        '\u{201C}' | '\u{201D}' | '\u{201E}' | '\u{201F}' | '\u{2033}' => "\"",
+        '\u{2010}'..='\u{2015}' | '\u{2212}' => "-",
+        '\u{2026}' => "...",
+        '\u{A0}' | '\u{2007}' | '\u{2009}' | '\u{202F}' => " ",
+        '\u{2039}' => "<",
+        '\u{203A}' => ">",
+        '\u{2022}' | '\u{2605}' => "*",
+        '\u{2606}' => "-",
+        '\u{2122}' => "TM",
+        '\u{2190}' => "<-",
+        '\u{2192}' => "->",
+        _ => return None,
+    })
 }
 
 /// Map a single Unicode scalar to its CP858 byte.
@@ -42,49 +82,331 @@ fn cp858_byte(c: char) -> u8 {
         'Ñ' => 0xA5,
         // Currency
         '€' => 0xD5,
-        // Everything else — pass through as-is (ASCII-safe)
-        other => other as u8,
+        // Control characters would be read as commands
+        '\t' => b'\t',
+        '\u{0}'..='\u{1F}' => b' ',
+        // ASCII passes through as-is
+        '\u{20}'..='\u{7F}' => c as u8,
+        // Rest of the code page, or `?` when the printer has no glyph
+        other => CP858_HIGH
+            .iter()
+            .position(|&h| h == other)
+            .map_or(b'?', |i| 0x80 + i as u8),
+    }
+}
+
+/// Decode Code Page 858 bytes back to a UTF-8 string.
+///
+/// The inverse of [`encode_cp858`] for text bytes; used by the preview
+/// renderer. Bytes below `0x80` are treated as ASCII.
+pub fn decode_cp858(bytes: &[u8]) -> String {
+    bytes.iter().map(|&b| cp858_char(b)).collect()
+}
+
+/// Map a single CP858 byte to its Unicode scalar.
+#[inline]
+pub(crate) fn cp858_char(b: u8) -> char {
+    if b < 0x80 {
+        b as char
+    } else {
+        CP858_HIGH[(b - 0x80) as usize]
+    }
+}
+
+/// Unicode scalars for CP858 bytes `0x80`–`0xFF`.
+#[rustfmt::skip]
+const CP858_HIGH: [char; 128] = [
+    'Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç', 'ê', 'ë', 'è', 'ï', 'î', 'ì', 'Ä', 'Å',
+    'É', 'æ', 'Æ', 'ô', 'ö', 'ò', 'û', 'ù', 'ÿ', 'Ö', 'Ü', 'ø', '£', 'Ø', '×', 'ƒ',
+    'á', 'í', 'ó', 'ú', 'ñ', 'Ñ', 'ª', 'º', '¿', '®', '¬', '½', '¼', '¡', '«', '»',
+    '░', '▒', '▓', '│', '┤', 'Á', 'Â', 'À', '©', '╣', '║', '╗', '╝', '¢', '¥', '┐',
+    '└', '┴', '┬', '├', '─', '┼', 'ã', 'Ã', '╚', '╔', '╩', '╦', '╠', '═', '╬', '¤',
+    'ð', 'Ð', 'Ê', 'Ë', 'È', '€', 'Í', 'Î', 'Ï', '┘', '┌', '█', '▄', '¦', 'Ì', '▀',
+    'Ó', 'ß', 'Ô', 'Ò', 'õ', 'Õ', 'µ', 'þ', 'Þ', 'Ú', 'Û', 'Ù', 'ý', 'Ý', '¯', '´',
+    '\u{AD}', '±', '‗', '¾', '¶', '§', '÷', '¸', '°', '¨', '·', '¹', '³', '²', '■', '\u{A0}',
+];
+
+/// Multibyte code pages for printers with a Kanji / CJK font.
+#[cfg(feature = "shift-jis")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MultibyteEncoding {
+    /// Shift-JIS (JIS X 0208) for Japanese printers such as the Epson TM-J series.
+    ShiftJis,
+}
+
+/// Encode a UTF-8 string with the given multibyte code page.
+#[cfg(feature = "shift-jis")]
+pub fn encode_multibyte(text: &str, encoding: MultibyteEncoding) -> Vec<u8> {
+    match encoding {
+        MultibyteEncoding::ShiftJis => encode_shift_jis(text),
+    }
+}
+
+/// Encode a UTF-8 string to Shift-JIS bytes for an ESC/POS printer.
+///
+/// ASCII and half-width katakana are sent as single bytes. Runs of
+/// double-byte characters (full-width kana, Kanji, CJK punctuation) are
+/// wrapped in `FS &` … `FS .` so the printer switches to Kanji mode only
+/// while it needs to. Characters with no Shift-JIS mapping become `?`, and
+/// control characters other than tab become a space, as in [`encode_cp858`].
+///
+/// The printer must have Shift-JIS selected (`FS C 1`) and the Katakana
+/// code page active — [`ReceiptBuilder::multibyte`](crate::ReceiptBuilder::multibyte)
+/// sends both.
+#[cfg(feature = "shift-jis")]
+pub fn encode_shift_jis(text: &str) -> Vec<u8> {
+    use crate::commands::{kanji_mode_off, kanji_mode_on};
+
+    let mut out = Vec::with_capacity(text.len());
+    let mut kanji = false;
+    for c in text.chars() {
+        match shift_jis_code(c) {
+            Some(code) if code > 0xFF => {
+                if !kanji {
+                    out.extend_from_slice(kanji_mode_on());
+                    kanji = true;
+                }
+                out.extend_from_slice(&code.to_be_bytes());
+            }
+            single => {
+                if kanji {
+                    out.extend_from_slice(kanji_mode_off());
+                    kanji = false;
+                }
+                out.push(single.map_or(b'?', |b| b as u8));
+            }
+        }
+    }
+    if kanji {
+        out.extend_from_slice(kanji_mode_off());
+    }
+    out
+}
+
+/// Map a Unicode scalar to its Shift-JIS code (one byte if `<= 0xFF`).
+#[cfg(feature = "shift-jis")]
+fn shift_jis_code(c: char) -> Option<u16> {
+    match c {
+        '\t' => Some(0x09),
+        '\u{0}'..='\u{1F}' => Some(0x20),
+        '\u{20}'..='\u{7F}' => Some(c as u16),
+        '¥' => Some(0x5C),
+        '‾' => Some(0x7E),
+        // Half-width katakana block maps linearly onto 0xA1–0xDF
+        '\u{FF61}'..='\u{FF9F}' => Some(c as u16 - 0xFF61 + 0xA1),
+        _ => {
+            let cp = u16::try_from(c as u32).ok()?;
+            crate::sjis_table::SHIFT_JIS
+                .binary_search_by_key(&cp, |&(u, _)| u)
+                .ok()
+                .map(|i| crate::sjis_table::SHIFT_JIS[i].1)
+        }
     }
 }
 
 /// Truncate a string to `max_chars` Unicode scalar values.
-/// Appends `"..."` if truncated.
+/// Appends `"..."` if truncated, unless `max_chars` is 3 or less, where the
+/// text is cut without one so some of it stays visible.
 pub fn truncate(text: &str, max_chars: usize) -> String {
     let count = text.chars().count();
     if count <= max_chars {
         text.to_owned()
+    } else if max_chars <= 3 {
+        text.chars().take(max_chars).collect()
     } else {
-        let cut = max_chars.saturating_sub(3);
-        let s: String = text.chars().take(cut).collect();
+        let s: String = text.chars().take(max_chars - 3).collect();
         format!("{}...", s)
     }
 }
 
+/// Truncate a string to fit `cols` paper columns when every character is
+/// printed `size_multiplier` columns wide (2 under double-width text).
+///
+/// ```rust
+/// use thermoprint::encoding::truncate_cols;
+/// assert_eq!(truncate_cols("BOULANGERIE DU PLATEAU", 32, 2), "BOULANGERIE D...");
+/// ```
+pub fn truncate_cols(text: &str, cols: usize, size_multiplier: usize) -> String {
+    truncate(text, cols / size_multiplier.max(1))
+}
+
 /// Centre a string within `width` columns using space padding.
 pub fn center(text: &str, width: usize) -> String {
+    center_dir(text, width, Direction::Ltr)
+}
+
+/// Centre a string within `width` columns for the given reading direction.
+///
+/// Right-to-left lines are padded on both sides to the full width, with an
+/// odd leftover space on the left, so they don't depend on the printer's
+/// alignment setting. Text wider than `width` is [`truncate`]d.
+pub fn center_dir(text: &str, width: usize, dir: Direction) -> String {
     let len = text.chars().count();
     if len >= width {
-        return text.to_owned();
+        return truncate(text, width);
     }
     let pad = (width - len) / 2;
-    format!("{}{}", " ".repeat(pad), text)
+    match dir {
+        Direction::Ltr => format!("{}{}", " ".repeat(pad), text),
+        Direction::Rtl => {
+            let lead = width - len - pad;
+            format!("{}{}{}", " ".repeat(lead), text, " ".repeat(pad))
+        }
+    }
 }
 
 /// Right-align a string within `width` columns.
 pub fn right_align(text: &str, width: usize) -> String {
+    right_align_dir(text, width, Direction::Ltr)
+}
+
+/// Align a string to the line end within `width` columns: the right edge
+/// for left-to-right, the left edge (padded to full width) for right-to-left.
+/// Text wider than `width` is returned unchanged, since it is usually an
+/// amount that must print in full.
+pub fn right_align_dir(text: &str, width: usize, dir: Direction) -> String {
     let len = text.chars().count();
     if len >= width {
         return text.to_owned();
     }
-    format!("{}{}", " ".repeat(width - len), text)
+    let pad = " ".repeat(width - len);
+    match dir {
+        Direction::Ltr => format!("{}{}", pad, text),
+        Direction::Rtl => format!("{}{}", text, pad),
+    }
 }
 
 /// Build a two-column row: label flush-left, value flush-right, total = `width`.
 pub fn two_col(left: &str, right: &str, width: usize) -> String {
-    let l = left.chars().count();
+    two_col_dir(left, right, width, Direction::Ltr)
+}
+
+/// Build a two-column row for the given reading direction: right-to-left
+/// rows put the label (`left`) flush-right and the value flush-left.
+///
+/// The value is never cut; see [`two_col_lines`]. When the label and the
+/// value need two lines they are separated by `'\n'`.
+///
+/// ```rust
+/// use thermoprint::encoding::two_col;
+/// assert_eq!(two_col("Cafe au lait", "1500", 12), "Cafe... 1500");
+/// assert_eq!(two_col("TOTAL", "1 250 000 FCFA", 12), "TOTAL\n1 250 000 FCFA");
+/// ```
+pub fn two_col_dir(left: &str, right: &str, width: usize, dir: Direction) -> String {
+    two_col_lines(left, right, width, dir).join("\n")
+}
+
+/// Lay out a two-column row as one line, or two when the value leaves no
+/// room for the label.
+///
+/// The value is never cut. When both sides don't fit with a space between
+/// them the label is [`truncate`]d; if that would leave fewer than four
+/// columns of label, the label gets a line of its own and the value follows
+/// at the line end. A value wider than `width` is kept whole.
+pub fn two_col_lines(left: &str, right: &str, width: usize, dir: Direction) -> Vec<String> {
     let r = right.chars().count();
-    let gap = width.saturating_sub(l + r);
-    format!("{}{}{}", left, " ".repeat(gap.max(1)), right)
+    let room = width.saturating_sub(r + 1);
+    let l = left.chars().count();
+    if l > 0 && room < l.min(4) {
+        let label = truncate(left, width);
+        let label = match dir {
+            Direction::Ltr => label,
+            Direction::Rtl => right_align_dir(&label, width, Direction::Ltr),
+        };
+        return vec![label, right_align_dir(right, width, dir)];
+    }
+    let left = truncate(left, room);
+    let gap = " ".repeat(width.saturating_sub(left.chars().count() + r));
+    vec![match dir {
+        Direction::Ltr => format!("{}{}{}", left, gap, right),
+        Direction::Rtl => format!("{}{}{}", right, gap, left),
+    }]
+}
+
+/// Word-wrap `text` to `width` columns, breaking at spaces. A word longer
+/// than `width` is split across lines.
+pub fn wrap(text: &str, width: usize) -> Vec<String> {
+    if width == 0 {
+        return vec![text.to_owned()];
+    }
+    wrap_words(text, width)
+        .into_iter()
+        .map(|words| words.join(" "))
+        .collect()
+}
+
+/// Greedy wrap of `text` into lines of words at most `width` columns wide.
+fn wrap_words(text: &str, width: usize) -> Vec<Vec<String>> {
+    let mut lines: Vec<Vec<String>> = Vec::new();
+    let mut current: Vec<String> = Vec::new();
+    let mut current_len = 0;
+    for word in text.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+        while word.len() > width {
+            if !current.is_empty() {
+                lines.push(std::mem::take(&mut current));
+                current_len = 0;
+            }
+            let rest = word.split_off(width);
+            lines.push(vec![word.into_iter().collect()]);
+            word = rest;
+        }
+        if word.is_empty() {
+            continue;
+        }
+        let needed = if current.is_empty() {
+            word.len()
+        } else {
+            current_len + 1 + word.len()
+        };
+        if needed > width {
+            lines.push(std::mem::take(&mut current));
+            current_len = word.len();
+        } else {
+            current_len = needed;
+        }
+        current.push(word.into_iter().collect());
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+/// Word-wrap `text` to `width` columns and justify every line except the
+/// last by spreading extra spaces across the inter-word gaps.
+///
+/// Extra spaces go to the leftmost gaps first. Lines holding a single word,
+/// and the final line, stay left-aligned. A word longer than `width` is
+/// split across lines without justification.
+pub fn justify(text: &str, width: usize) -> Vec<String> {
+    if width == 0 {
+        return vec![text.to_owned()];
+    }
+    let lines = wrap_words(text, width);
+
+    let last = lines.len().saturating_sub(1);
+    lines
+        .into_iter()
+        .enumerate()
+        .map(|(i, words)| {
+            if i == last || words.len() < 2 {
+                return words.join(" ");
+            }
+            let letters: usize = words.iter().map(|w| w.chars().count()).sum();
+            let gaps = words.len() - 1;
+            let spaces = width.saturating_sub(letters);
+            let (base, extra) = (spaces / gaps, spaces % gaps);
+            let mut line = String::new();
+            for (j, word) in words.iter().enumerate() {
+                line.push_str(word);
+                if j < gaps {
+                    line.push_str(&" ".repeat(base + usize::from(j < extra)));
+                }
+            }
+            line
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -103,11 +425,120 @@ mod tests {
         assert_eq!(encoded, vec![b'1', b'0', 0xD5]);
     }
 
+    #[test]
+    fn smart_quotes_become_ascii() {
+        assert_eq!(
+            encode_cp858("\u{201C}Bon\u{201D} l\u{2019}ami"),
+            b"\"Bon\" l'ami"
+        );
+    }
+
+    #[test]
+    fn dashes_and_ellipsis_become_ascii() {
+        assert_eq!(
+            encode_cp858("10\u{2013}12 \u{2014} ok\u{2026}"),
+            b"10-12 - ok..."
+        );
+        assert_eq!(encode_cp858("A\u{A0}\u{2192}B"), b"A ->B");
+    }
+
+    #[test]
+    fn box_drawing_characters() {
+        assert_eq!(
+            encode_cp858("┌─┐│└┘"),
+            vec![0xDA, 0xC4, 0xBF, 0xB3, 0xC0, 0xD9]
+        );
+    }
+
+    #[test]
+    fn block_and_star_characters() {
+        assert_eq!(encode_cp858("█░▒▓"), vec![0xDB, 0xB0, 0xB1, 0xB2]);
+        assert_eq!(encode_cp858("★★★☆"), b"***-");
+    }
+
+    #[test]
+    fn unmappable_becomes_question_mark() {
+        assert_eq!(encode_cp858("\u{4E2D}"), b"?");
+        // Characters elsewhere in the code page still get their CP858 byte
+        assert_eq!(encode_cp858("á£"), vec![0xA0, 0x9C]);
+    }
+
+    #[cfg(feature = "shift-jis")]
+    #[test]
+    fn shift_jis_mixed_header() {
+        // ASCII, full-width katakana (Kanji mode), half-width katakana
+        let encoded = encode_shift_jis("SHOP ショップ ｼｮｯﾌﾟ");
+        let mut expected = b"SHOP ".to_vec();
+        expected.extend_from_slice(&[0x1C, b'&']);
+        expected.extend_from_slice(&[0x83, 0x56, 0x83, 0x87, 0x83, 0x62, 0x83, 0x76]);
+        expected.extend_from_slice(&[0x1C, b'.']);
+        expected.extend_from_slice(&[b' ', 0xBC, 0xAE, 0xAF, 0xCC, 0xDF]);
+        assert_eq!(encoded, expected);
+    }
+
+    #[cfg(feature = "shift-jis")]
+    #[test]
+    fn shift_jis_kanji_and_unmapped() {
+        // 日本 = 0x93FA 0x967B; emoji has no mapping
+        let encoded = encode_shift_jis("日本😀");
+        assert_eq!(
+            encoded,
+            vec![0x1C, b'&', 0x93, 0xFA, 0x96, 0x7B, 0x1C, b'.', b'?']
+        );
+    }
+
+    #[test]
+    fn decode_round_trips_french() {
+        let text = "Café crème à 10€, Ça va";
+        assert_eq!(decode_cp858(&encode_cp858(text)), text);
+    }
+
     #[test]
     fn truncate_short() {
         assert_eq!(truncate("hello", 10), "hello");
     }
 
+    #[test]
+    fn truncate_below_ellipsis_width() {
+        assert_eq!(truncate("hello", 3), "hel");
+        assert_eq!(truncate("hello", 1), "h");
+        assert_eq!(truncate("hello", 0), "");
+        assert_eq!(truncate("hello", 4), "h...");
+    }
+
+    #[test]
+    fn overlong_text_is_cut_to_width() {
+        assert_eq!(center("abcdefgh", 6), "abc...");
+        assert_eq!(two_col("Cafe au lait", "1500", 12), "Cafe... 1500");
+    }
+
+    #[test]
+    fn amounts_are_never_cut() {
+        assert_eq!(right_align("1234567890", 6), "1234567890");
+        assert_eq!(
+            two_col("Cafe", "123456789012345", 12),
+            "Cafe\n123456789012345"
+        );
+        assert_eq!(two_col("", "123456789012345", 12), "123456789012345");
+        // Too little room beside the value for a useful label
+        assert_eq!(
+            two_col("Sous-total", "12 500 FCFA", 14),
+            "Sous-total\n   12 500 FCFA"
+        );
+        assert_eq!(
+            two_col_dir("TOTAL", "12 500 FCFA", 14, Direction::Rtl),
+            "         TOTAL\n12 500 FCFA   "
+        );
+    }
+
+    #[test]
+    fn truncate_cols_counts_wide_characters() {
+        assert_eq!(truncate_cols("abcdefgh", 8, 1), "abcdefgh");
+        assert_eq!(truncate_cols("abcdefgh", 8, 2), "a...");
+        assert_eq!(truncate_cols("abcd", 8, 2), "abcd");
+        assert_eq!(truncate_cols("abcd", 8, 0), "abcd");
+    }
+
     #[test]
     fn truncate_long() {
         let t = truncate("hello world", 8);
@@ -115,9 +546,107 @@ mod tests {
         assert_eq!(t.chars().count(), 8);
     }
 
+    #[test]
+    fn justify_pads_all_but_last_line() {
+        let lines = justify("aa bb cc dd ee", 8);
+        assert_eq!(lines, vec!["aa bb cc", "dd ee"]);
+        let lines = justify("a bb c dddd", 7);
+        assert_eq!(lines, vec!["a  bb c", "dddd"]);
+        assert_eq!(lines[0].chars().count(), 7);
+    }
+
+    #[test]
+    fn justify_splits_long_words() {
+        let lines = justify("hi abcdefghij ok", 4);
+        assert_eq!(lines, vec!["hi", "abcd", "efgh", "ij", "ok"]);
+    }
+
+    #[test]
+    fn wrap_breaks_at_spaces_without_padding() {
+        let lines = wrap("deux cent quatre-vingts mille", 13);
+        assert_eq!(lines, vec!["deux cent", "quatre-vingts", "mille"]);
+        assert_eq!(wrap("quatre-vingts", 8), vec!["quatre-v", "ingts"]);
+    }
+
     #[test]
     fn two_col_fills_width() {
         let row = two_col("TOTAL", "29500 FCFA", 48);
         assert_eq!(row.chars().count(), 48);
     }
+
+    #[test]
+    fn rtl_layout_mirrors_rows() {
+        let row = two_col_dir("TOTAL", "500", 12, Direction::Rtl);
+        assert_eq!(row, "500    TOTAL");
+        assert_eq!(right_align_dir("ok", 5, Direction::Rtl), "ok   ");
+        assert_eq!(right_align_dir("ok", 5, Direction::Ltr), "   ok");
+    }
+
+    #[test]
+    fn rtl_center_pads_both_sides() {
+        assert_eq!(center_dir("ab", 5, Direction::Ltr), " ab");
+        assert_eq!(center_dir("ab", 5, Direction::Rtl), "  ab ");
+        assert_eq!(center_dir("abc", 3, Direction::Rtl), "abc");
+    }
+
+    mod props {
+        use super::*;
+        use proptest::prelude::*;
+
+        fn cols(s: &str) -> usize {
+            s.chars().count()
+        }
+
+        fn dir() -> impl Strategy<Value = Direction> {
+            prop_oneof![Just(Direction::Ltr), Just(Direction::Rtl)]
+        }
+
+        proptest! {
+            #[test]
+            fn truncate_fits(text in "\\PC*", max in 0usize..64) {
+                let out = truncate(&text, max);
+                prop_assert!(cols(&out) <= max);
+                if cols(&text) <= max {
+                    prop_assert_eq!(out, text);
+                }
+            }
+
+            #[test]
+            fn aligned_lines_fit(text in "\\PC*", width in 0usize..64, dir in dir()) {
+                prop_assert!(cols(&center_dir(&text, width, dir)) <= width);
+                let right = right_align_dir(&text, width, dir);
+                prop_assert!(cols(&right) <= width || right == text);
+            }
+
+            #[test]
+            fn two_col_fits(
+                left in "\\PC*",
+                right in "\\PC*",
+                width in 0usize..64,
+                dir in dir(),
+            ) {
+                prop_assume!(!left.contains('\n') && !right.contains('\n'));
+                let row = two_col_dir(&left, &right, width, dir);
+                // The value survives whole; only a value wider than the
+                // line makes a line longer than `width`
+                prop_assert!(row.contains(right.as_str()));
+                for line in row.split('\n') {
+                    prop_assert!(cols(line) <= width || line.trim() == right.trim());
+                }
+                if cols(&left) + cols(&right) < width {
+                    prop_assert_eq!(cols(&row), width);
+                }
+            }
+
+            #[test]
+            fn wrapped_lines_fit(text in "\\PC*", width in 1usize..64) {
+                for line in wrap(&text, width) {
+                    prop_assert!(cols(&line) <= width);
+                }
+                for line in justify(&text, width) {
+                    prop_assert!(cols(&line) <= width);
+                }
+            }
+        }
+    }
 }
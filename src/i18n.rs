@@ -30,6 +30,24 @@ pub struct ReceiptLabels {
     pub see_you_at: &'static str,
     /// Discount on item prefix (e.g. "Discount:")
     pub item_discount: &'static str,
+    /// Tip line label on card receipts (e.g. "TIP:")
+    pub tip: &'static str,
+    /// Signature line label on card receipts (e.g. "SIGNATURE:")
+    pub signature: &'static str,
+    /// Table label on kitchen tickets (e.g. "TABLE")
+    pub table: &'static str,
+    /// Order number label on kitchen tickets (e.g. "ORDER")
+    pub order: &'static str,
+    /// Date/time line prefix (e.g. "Date:")
+    pub date: &'static str,
+    /// Header above fiscal identifiers (e.g. "LEGAL INFORMATION")
+    pub legal_info: &'static str,
+    /// VAT name in the per-rate summary (e.g. "VAT")
+    pub vat: &'static str,
+    /// Taxable base label in the per-rate summary (e.g. "Taxable base")
+    pub vat_base: &'static str,
+    /// Caption under a mobile money payment QR (e.g. "Pay with your phone")
+    pub pay_by_phone: &'static str,
 }
 
 /// Supported receipt languages.
@@ -78,6 +96,15 @@ pub const LABELS_FR: ReceiptLabels = ReceiptLabels {
     thank_you: "Merci pour votre confiance!",
     see_you_at: "A bientot chez",
     item_discount: "Remise:",
+    tip: "POURBOIRE:",
+    signature: "SIGNATURE:",
+    table: "TABLE",
+    order: "COMMANDE",
+    date: "Date:",
+    legal_info: "INFORMATIONS LEGALES",
+    vat: "TVA",
+    vat_base: "Base HT",
+    pay_by_phone: "Payez avec votre telephone",
 };
 
 /// English labels.
@@ -95,6 +122,15 @@ pub const LABELS_EN: ReceiptLabels = ReceiptLabels {
     thank_you: "Thank you for your purchase!",
     see_you_at: "See you soon at",
     item_discount: "Discount:",
+    tip: "TIP:",
+    signature: "SIGNATURE:",
+    table: "TABLE",
+    order: "ORDER",
+    date: "Date:",
+    legal_info: "LEGAL INFORMATION",
+    vat: "VAT",
+    vat_base: "Taxable base",
+    pay_by_phone: "Pay with your phone",
 };
 
 /// Spanish labels.
@@ -112,6 +148,15 @@ pub const LABELS_ES: ReceiptLabels = ReceiptLabels {
     thank_you: "Gracias por su compra!",
     see_you_at: "Hasta pronto en",
     item_discount: "Descuento:",
+    tip: "PROPINA:",
+    signature: "FIRMA:",
+    table: "MESA",
+    order: "PEDIDO",
+    date: "Fecha:",
+    legal_info: "INFORMACION LEGAL",
+    vat: "IVA",
+    vat_base: "Base imponible",
+    pay_by_phone: "Pague con su telefono",
 };
 
 /// Portuguese labels.
@@ -129,6 +174,15 @@ pub const LABELS_PT: ReceiptLabels = ReceiptLabels {
     thank_you: "Obrigado pela sua compra!",
     see_you_at: "Ate breve em",
     item_discount: "Desconto:",
+    tip: "GORJETA:",
+    signature: "ASSINATURA:",
+    table: "MESA",
+    order: "PEDIDO",
+    date: "Data:",
+    legal_info: "INFORMACOES LEGAIS",
+    vat: "IVA",
+    vat_base: "Base tributavel",
+    pay_by_phone: "Pague com o seu telefone",
 };
 
 /// Arabic (Latin-transliterated for thermal printer compatibility).
@@ -146,6 +200,15 @@ pub const LABELS_AR: ReceiptLabels = ReceiptLabels {
     thank_you: "Choukran li thiqatikum!",
     see_you_at: "Ila al-liqa' fi",
     item_discount: "Takhfid:",
+    tip: "AL-BAKHSHISH:",
+    signature: "AT-TAWQI':",
+    table: "TAWILA",
+    order: "TALAB",
+    date: "At-Tarikh:",
+    legal_info: "MA'LUMAT QANUNIYA",
+    vat: "DARIBAT AL-QIMA",
+    vat_base: "Al-asas",
+    pay_by_phone: "Idfa' bi-hatifik",
 };
 
 /// Wolof labels.
@@ -163,6 +226,15 @@ pub const LABELS_WO: ReceiptLabels = ReceiptLabels {
     thank_you: "Jere jef ci sanu confiance!",
     see_you_at: "Ba beneen yoon ci",
     item_discount: "Wanaag:",
+    tip: "MAYE:",
+    signature: "XAATIM:",
+    table: "TAABAL",
+    order: "KOMAND",
+    date: "Bes:",
+    legal_info: "XIBAAR YU YOON",
+    vat: "TVA",
+    vat_base: "Base HT",
+    pay_by_phone: "Fayal ak sa telefon",
 };
 
 #[cfg(test)]
@@ -183,6 +255,15 @@ mod tests {
             assert!(!l.subtotal_ht.is_empty());
             assert!(!l.total.is_empty());
             assert!(!l.thank_you.is_empty());
+            assert!(!l.tip.is_empty());
+            assert!(!l.signature.is_empty());
+            assert!(!l.table.is_empty());
+            assert!(!l.order.is_empty());
+            assert!(!l.date.is_empty());
+            assert!(!l.legal_info.is_empty());
+            assert!(!l.vat.is_empty());
+            assert!(!l.vat_base.is_empty());
+            assert!(!l.pay_by_phone.is_empty());
         }
     }
 }
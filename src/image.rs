@@ -1,7 +1,25 @@
 use crate::commands;
 use crate::error::ThermoprintError;
+use crate::types::RasterMode;
 use image::{DynamicImage, GenericImageView};
 
+/// Rotation applied to an image before it is rasterised.
+///
+/// Angles are clockwise. `Rot90` and `Rot270` swap width and height, and
+/// the max-width clamp is applied to the rotated image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rotation {
+    /// Keep the image as authored (default).
+    #[default]
+    None,
+    /// Rotate 90° clockwise.
+    Rot90,
+    /// Rotate 180°.
+    Rot180,
+    /// Rotate 270° clockwise (90° counter-clockwise).
+    Rot270,
+}
+
 /// Load an image file and convert it to ESC/POS raster bytes.
 ///
 /// The image is converted to 1-bit monochrome and packed into
@@ -16,8 +34,140 @@ pub fn load_and_rasterise(path: &str, max_width_px: u32) -> Result<Vec<u8>, Ther
     Ok(rasterise(&img, max_width_px))
 }
 
+/// Like [`load_and_rasterise`], but rotates the image first.
+pub fn load_and_rasterise_with(
+    path: &str,
+    max_width_px: u32,
+    rotation: Rotation,
+) -> Result<Vec<u8>, ThermoprintError> {
+    let img = image::open(path).map_err(|e| ThermoprintError::LogoLoad {
+        path: path.to_owned(),
+        reason: e.to_string(),
+    })?;
+    Ok(rasterise_with(&img, max_width_px, rotation))
+}
+
+/// Like [`load_and_rasterise_with`], but packs the image for the given
+/// [`RasterMode`].
+pub fn load_and_rasterise_as(
+    path: &str,
+    max_width_px: u32,
+    rotation: Rotation,
+    mode: RasterMode,
+) -> Result<Vec<u8>, ThermoprintError> {
+    let img = image::open(path).map_err(|e| ThermoprintError::LogoLoad {
+        path: path.to_owned(),
+        reason: e.to_string(),
+    })?;
+    Ok(rasterise_as(&img, max_width_px, rotation, mode))
+}
+
+/// Load an image file and rasterise it scaled to exactly `target_px` wide;
+/// see [`rasterise_sized`].
+pub fn load_and_rasterise_sized(
+    path: &str,
+    target_px: u32,
+    mode: RasterMode,
+) -> Result<Vec<u8>, ThermoprintError> {
+    let img = image::open(path).map_err(|e| ThermoprintError::LogoLoad {
+        path: path.to_owned(),
+        reason: e.to_string(),
+    })?;
+    Ok(rasterise_sized(&img, target_px, mode))
+}
+
+/// Convert an already-loaded [`DynamicImage`] to bit-image bytes in the given
+/// [`RasterMode`], scaled up or down to print exactly `target_px` dots wide
+/// with the aspect ratio kept, e.g. for a small icon instead of a
+/// full-width banner.
+///
+/// Clamp `target_px` to [`PrintWidth::max_image_px`](crate::PrintWidth::max_image_px)
+/// yourself; a target of 0 is treated as 1.
+pub fn rasterise_sized(img: &DynamicImage, target_px: u32, mode: RasterMode) -> Vec<u8> {
+    let new_w = (target_px / mode.dot_width()).max(1);
+    let (orig_w, orig_h) = img.dimensions();
+    let new_h = (orig_h as u64 * new_w as u64 / orig_w.max(1) as u64).max(1) as u32;
+    let img = img.resize_exact(new_w, new_h, image::imageops::FilterType::Lanczos3);
+    rasterise_as(&img, new_w * mode.dot_width(), Rotation::None, mode)
+}
+
 /// Convert an already-loaded [`DynamicImage`] to ESC/POS raster bytes.
 pub fn rasterise(img: &DynamicImage, max_width_px: u32) -> Vec<u8> {
+    rasterise_with(img, max_width_px, Rotation::None)
+}
+
+/// Convert an already-loaded [`DynamicImage`] to ESC/POS raster bytes,
+/// rotating the pixels before the width clamp and packing.
+pub fn rasterise_with(img: &DynamicImage, max_width_px: u32, rotation: Rotation) -> Vec<u8> {
+    rasterise_as(img, max_width_px, rotation, RasterMode::GsV0)
+}
+
+/// Convert an already-loaded [`DynamicImage`] to ESC/POS bit-image bytes in
+/// the given [`RasterMode`]. With [`RasterMode::EscStarSingle`] the image is
+/// clamped to half of `max_width_px`, since each column prints two dots wide.
+pub fn rasterise_as(
+    img: &DynamicImage,
+    max_width_px: u32,
+    rotation: Rotation,
+    mode: RasterMode,
+) -> Vec<u8> {
+    let rotated;
+    let img = match rotation {
+        Rotation::None => img,
+        Rotation::Rot90 => {
+            rotated = img.rotate90();
+            &rotated
+        }
+        Rotation::Rot180 => {
+            rotated = img.rotate180();
+            &rotated
+        }
+        Rotation::Rot270 => {
+            rotated = img.rotate270();
+            &rotated
+        }
+    };
+
+    let (width, height, raster) = pack_rows(img, max_width_px / mode.dot_width());
+    let bytes_per_line = width.div_ceil(8) as usize;
+
+    match mode {
+        RasterMode::GsV0 => commands::raster_image(bytes_per_line as u16, height as u16, &raster),
+        RasterMode::EscStar => commands::column_image(width as u16, height as u16, &raster),
+        RasterMode::EscStarSingle => {
+            commands::column_image_single(width as u16, height as u16, &raster)
+        }
+    }
+}
+
+/// Convert an image to the `GS ( L` command that stores it in the printer's
+/// NV graphics memory under `key`.
+///
+/// Send the returned bytes to the printer **once** (e.g. at terminal
+/// set-up); afterwards each receipt only needs the 11-byte
+/// [`commands::print_nv_graphic`] via
+/// [`ReceiptBuilder::nv_graphic`](crate::ReceiptBuilder::nv_graphic).
+///
+/// ```rust,no_run
+/// use thermoprint::{image::define_nv_graphic, PrintWidth, ReceiptBuilder};
+///
+/// let logo = image::open("logo.png").unwrap();
+/// let setup = define_nv_graphic(&logo, *b"LG", PrintWidth::Mm80.max_image_px());
+/// // write `setup` to the printer once...
+///
+/// let receipt = ReceiptBuilder::new(PrintWidth::Mm80)
+///     .init()
+///     .nv_graphic(*b"LG")
+///     .build();
+/// ```
+pub fn define_nv_graphic(img: &DynamicImage, key: [u8; 2], max_width_px: u32) -> Vec<u8> {
+    let (width, height, raster) = pack_rows(img, max_width_px);
+    commands::define_nv_graphic(key, width as u16, height as u16, &raster)
+}
+
+/// Clamp `img` to `max_width_px` and pack it into 1-bit rows (MSB first,
+/// `ceil(width / 8)` bytes per row). Returns `(width, height, rows)`.
+fn pack_rows(img: &DynamicImage, max_width_px: u32) -> (u32, u32, Vec<u8>) {
     let (orig_w, orig_h) = img.dimensions();
 
     // Resize if wider than the printable area
@@ -48,7 +198,7 @@ pub fn rasterise(img: &DynamicImage, max_width_px: u32) -> Vec<u8> {
         raster.extend_from_slice(&row);
     }
 
-    commands::raster_image(bytes_per_line as u16, height as u16, &raster)
+    (width, height, raster)
 }
 
 #[cfg(test)]
@@ -82,4 +232,69 @@ mod tests {
         let result = rasterise(&dyn_img, 384);
         assert_eq!(result[8], 0x00); // nothing printed
     }
+
+    #[test]
+    fn rasterise_sized_scales_to_target_width() {
+        // Both a wide banner and a tiny icon come out 100 px wide
+        for (w, h) in [(400, 200), (20, 10)] {
+            let img = DynamicImage::ImageLuma8(GrayImage::new(w, h));
+            let result = rasterise_sized(&img, 100, RasterMode::GsV0);
+            assert_eq!(result[4], 13); // xL: ceil(100 / 8) bytes per line
+            assert_eq!(result[6], 50); // yL: aspect ratio kept
+            assert_eq!(result.len(), 8 + 13 * 50);
+        }
+    }
+
+    #[test]
+    fn esc_star_single_rasterises_at_half_width() {
+        let img = DynamicImage::ImageLuma8(GrayImage::new(768, 48));
+        let result = rasterise_as(&img, 384, Rotation::None, RasterMode::EscStarSingle);
+        assert_eq!(&result[3..8], &[0x1B, b'*', 32, 192, 0]); // 192 columns, 2 dots each
+        let result = rasterise_sized(&img, 100, RasterMode::EscStarSingle);
+        assert_eq!(&result[3..8], &[0x1B, b'*', 32, 50, 0]);
+    }
+
+    #[test]
+    fn rotate_90_swaps_dimensions() {
+        // 8x1 black bar becomes a 1x8 column
+        let mut img = GrayImage::new(8, 1);
+        for x in 0..8 {
+            img.put_pixel(x, 0, Luma([0u8]));
+        }
+        let dyn_img = DynamicImage::ImageLuma8(img);
+        let result = rasterise_with(&dyn_img, 384, Rotation::Rot90);
+
+        assert_eq!(result[4], 1); // xL: 1 byte per line
+        assert_eq!(result[6], 8); // yL: 8 lines
+        assert_eq!(&result[8..], &[0x80; 8]);
+    }
+
+    #[test]
+    fn rotate_clamps_after_rotation() {
+        // 4x16 image rotated 90° is 16 wide, clamped to 8
+        let img = GrayImage::new(4, 16);
+        let dyn_img = DynamicImage::ImageLuma8(img);
+        let result = rasterise_with(&dyn_img, 8, Rotation::Rot270);
+        assert_eq!(result[4], 1); // 8 px → 1 byte
+        assert_eq!(result[6], 2); // 4 * 8 / 16
+    }
+
+    #[test]
+    fn nv_graphic_defined_once_printed_by_key() {
+        let mut img = GrayImage::from_pixel(16, 2, Luma([255u8]));
+        for x in 0..8 {
+            img.put_pixel(x, 0, Luma([0u8]));
+        }
+        let dyn_img = DynamicImage::ImageLuma8(img);
+
+        let setup = define_nv_graphic(&dyn_img, *b"LG", 384);
+        assert_eq!(&setup[..5], &[0x1D, b'(', b'L', 15, 0]);
+        assert_eq!(&setup[16..], &[0xFF, 0x00, 0x00, 0x00]);
+
+        let receipt = crate::ReceiptBuilder::new(crate::PrintWidth::Mm80)
+            .nv_graphic(*b"LG")
+            .build();
+        assert!(receipt.len() < setup.len());
+        assert!(receipt.starts_with(&commands::print_nv_graphic(*b"LG")));
+    }
 }
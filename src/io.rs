@@ -0,0 +1,58 @@
+//! Sending receipts straight to a printer device.
+//!
+//! For headless tools and daemons that talk to a printer exposed as a
+//! device file — `/dev/usb/lp0`, `/dev/ttyUSB0`, `/dev/rfcomm0` — without
+//! going through Tauri or a browser.
+
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+
+/// Bytes written per chunk. Matches the Tauri plugin's serial writer.
+const CHUNK_SIZE: usize = 4096;
+
+/// Write ESC/POS bytes to a printer device in chunks, flushing after each.
+///
+/// The path must already exist — it is opened for writing but never
+/// created, so a mistyped device path fails instead of leaving a stray file.
+///
+/// ```rust,no_run
+/// use thermoprint::{PrintWidth, ReceiptBuilder};
+///
+/// let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+///     .init()
+///     .text_line("Hello")
+///     .cut()
+///     .build();
+/// thermoprint::io::write_to_device("/dev/usb/lp0", &bytes)?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn write_to_device(path: &str, bytes: &[u8]) -> io::Result<()> {
+    let mut device = OpenOptions::new().write(true).open(path)?;
+    for chunk in bytes.chunks(CHUNK_SIZE) {
+        device.write_all(chunk)?;
+        device.flush()?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_all_chunks() {
+        let path = std::env::temp_dir().join(format!("thermoprint-io-{}.bin", std::process::id()));
+        std::fs::File::create(&path).unwrap();
+        let bytes: Vec<u8> = (0..10_000u32).map(|i| i as u8).collect();
+
+        write_to_device(path.to_str().unwrap(), &bytes).unwrap();
+
+        assert_eq!(std::fs::read(&path).unwrap(), bytes);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn missing_device_is_an_error() {
+        assert!(write_to_device("/nonexistent/thermoprint/lp0", b"x").is_err());
+    }
+}
@@ -9,6 +9,7 @@ A fluent, correct ESC/POS receipt builder for thermal printers.
 ## Quickstart (native)
 
 ```rust
+# #[cfg(feature = "std")] {
 use thermoprint::{ReceiptBuilder, PrintWidth};
 use rust_decimal::prelude::*;
 
@@ -36,6 +37,7 @@ let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
     .build();
 
 // `bytes` is Vec<u8> — send it to your printer however you like
+# }
 ```
 
 ## WASM / TypeScript
@@ -59,37 +61,97 @@ const bytes = new ReceiptBuilder(PrintWidth.Mm80)
 ```
 */
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(unsafe_code)]
 #![warn(missing_docs)]
 
+// The crate is also built as a `cdylib` for wasm-pack, and a `no_std`
+// cdylib has no panic handler. On targets with an OS, link std for it even
+// without the `std` feature; the API stays `core`-only. Bare-metal targets
+// (`target_os = "none"`) drop the cdylib and stay truly `no_std`.
+#[cfg(all(not(feature = "std"), not(target_os = "none")))]
+extern crate std;
+
 /// Fluent receipt builder API.
+#[cfg(feature = "std")]
 pub mod builder;
 /// Raw ESC/POS command byte sequences.
 pub mod commands;
+/// Tokenizer for inspecting ESC/POS byte streams in tests.
+#[cfg(feature = "std")]
+pub mod debug;
+/// Printer command dialects (Epson ESC/POS, Star Line Mode).
+#[cfg(feature = "std")]
+pub mod dialect;
 /// Image dithering — pure Rust, works in native and WASM.
+#[cfg(feature = "std")]
 pub mod dither;
 /// CP-858 text encoding and layout helpers.
+#[cfg(feature = "std")]
 pub mod encoding;
 /// Error types.
+#[cfg(feature = "std")]
 pub mod error;
 /// Internationalisation — receipt label translations.
+#[cfg(feature = "std")]
 pub mod i18n;
+/// Receipt data model with inspectable totals.
+#[cfg(feature = "std")]
+pub mod model;
+/// Plain-text preview of ESC/POS byte streams.
+#[cfg(feature = "std")]
+pub mod preview;
+/// QR code payload helpers (vCard, Wi-Fi).
+#[cfg(feature = "std")]
+pub mod qr;
 /// JSON template engine for receipt generation.
+#[cfg(feature = "std")]
 pub mod template;
 /// Shared domain types (alignment, print width, tax entries).
+#[cfg(feature = "std")]
 pub mod types;
+/// Amounts written out in words for invoices.
+#[cfg(feature = "std")]
+pub mod words;
+
+#[cfg(feature = "shift-jis")]
+mod sjis_table;
 
 /// Image rasterisation (native builds only).
 #[cfg(feature = "native")]
 pub mod image;
 
+/// Direct device output (native builds only).
+#[cfg(feature = "native")]
+pub mod io;
+
 // Convenient top-level re-exports
-pub use builder::ReceiptBuilder;
-pub use dither::{dither_rgba, DitherMethod};
+#[cfg(feature = "std")]
+pub use builder::{paginate, repeat_receipt, reset_bytes, ReceiptBuilder, TotalsBlock};
+#[cfg(feature = "std")]
+pub use dialect::{Dialect, Symbology};
+#[cfg(feature = "std")]
+pub use dither::{
+    dither_rgba, dither_rgba_filtered, dither_rgba_mode, dither_rgba_with, DitherMethod,
+    DitherOptions, ResizeFilter,
+};
+#[cfg(feature = "std")]
 pub use error::ThermoprintError;
+#[cfg(feature = "std")]
 pub use i18n::{Language, ReceiptLabels};
+#[cfg(feature = "std")]
+pub use model::ReceiptModel;
+#[cfg(feature = "std")]
 pub use template::{render_json, ReceiptTemplate};
-pub use types::{Align, PrintWidth, TaxEntry};
+#[cfg(feature = "std")]
+pub use types::{
+    merge_items, Align, CurrencyPlacement, Direction, Feature, HriFont, HriPosition, ItemInput,
+    ItemStyle, LineEnding, MoneyFormat, PrintWidth, QrErrorCorrection, RasterMode, TaxEntry,
+    UnderlineStyle,
+};
+
+#[cfg(feature = "native")]
+pub use image::Rotation;
 
 // ── WASM public surface ───────────────────────────────────────────────────────
 #[cfg(all(feature = "wasm", target_arch = "wasm32"))]
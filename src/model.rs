@@ -0,0 +1,196 @@
+//! Receipt data model, separate from the byte stream.
+//!
+//! [`ReceiptBuilder`] writes bytes as you go, so totals have to be known
+//! before they are printed. A [`ReceiptModel`] collects items, taxes and an
+//! order discount first; query its totals, adjust it (e.g. add a loyalty
+//! discount above a threshold), then render it.
+//!
+//! ```rust
+//! use rust_decimal::Decimal;
+//! use thermoprint::{ItemInput, Language, MoneyFormat, PrintWidth, ReceiptModel, TaxEntry};
+//!
+//! let mut model = ReceiptModel::new();
+//! model
+//!     .add_item(ItemInput::new("Polo shirt", 2, Decimal::from(15_000), None))
+//!     .add_tax(TaxEntry::new("TVA 18%", Decimal::from(5_400), false));
+//!
+//! if model.subtotal() >= Decimal::from(25_000) {
+//!     model.set_discount(Decimal::from(2_000), Some("FIDELITE"));
+//! }
+//! assert_eq!(model.total(), Decimal::from(33_400));
+//!
+//! let bytes = model.render(PrintWidth::Mm80, Language::Fr, MoneyFormat::default());
+//! ```
+
+use rust_decimal::Decimal;
+
+use crate::builder::ReceiptBuilder;
+use crate::i18n::Language;
+use crate::types::{ItemInput, MoneyFormat, PrintWidth, TaxEntry};
+
+/// Items, taxes and an order discount whose totals can be inspected before
+/// printing.
+#[derive(Debug, Clone, Default)]
+pub struct ReceiptModel {
+    items: Vec<ItemInput>,
+    taxes: Vec<TaxEntry>,
+    discount: Option<(Decimal, Option<String>)>,
+}
+
+impl ReceiptModel {
+    /// An empty receipt.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a line item.
+    pub fn add_item(&mut self, item: ItemInput) -> &mut Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Add a tax entry. Only taxes not `included` in the prices change the
+    /// [`total`](Self::total).
+    pub fn add_tax(&mut self, tax: TaxEntry) -> &mut Self {
+        self.taxes.push(tax);
+        self
+    }
+
+    /// Set (or replace) the discount on the whole order, with an optional
+    /// coupon code printed next to it.
+    pub fn set_discount(&mut self, amount: Decimal, coupon_code: Option<&str>) -> &mut Self {
+        self.discount = Some((amount, coupon_code.map(str::to_owned)));
+        self
+    }
+
+    /// Remove the order discount.
+    pub fn clear_discount(&mut self) -> &mut Self {
+        self.discount = None;
+        self
+    }
+
+    /// Items added so far.
+    pub fn items(&self) -> &[ItemInput] {
+        &self.items
+    }
+
+    /// Tax entries added so far.
+    pub fn taxes(&self) -> &[TaxEntry] {
+        &self.taxes
+    }
+
+    /// Order discount amount (zero if none).
+    pub fn discount(&self) -> Decimal {
+        self.discount.as_ref().map_or(Decimal::ZERO, |(d, _)| *d)
+    }
+
+    /// Sum of the line totals, after per-item discounts.
+    pub fn subtotal(&self) -> Decimal {
+        self.items
+            .iter()
+            .map(|it| it.unit_price * Decimal::from(it.qty) - it.discount.unwrap_or_default())
+            .sum()
+    }
+
+    /// Amount to pay: [`subtotal`](Self::subtotal) minus the order discount,
+    /// plus the taxes added on top.
+    pub fn total(&self) -> Decimal {
+        self.total_with(&self.taxes)
+    }
+
+    /// Like [`total`](Self::total), but with `taxes` instead of the ones
+    /// added to the model — to compare tax scenarios without changing it.
+    pub fn total_with(&self, taxes: &[TaxEntry]) -> Decimal {
+        let additional: Decimal = taxes.iter().filter(|t| !t.included).map(|t| t.amount).sum();
+        self.subtotal() - self.discount() + additional
+    }
+
+    /// Print the receipt body with `builder`: items, a divider, the subtotal,
+    /// discount, taxes and total. Add a header before or a footer and cut
+    /// after as with any builder; the builder's currency is used.
+    pub fn write_to(&self, builder: ReceiptBuilder) -> ReceiptBuilder {
+        let mut b = builder
+            .items(&self.items)
+            .divider('-')
+            .subtotal_ht(self.subtotal());
+        if let Some((amount, code)) = &self.discount {
+            b = b.discount(*amount, code.as_deref());
+        }
+        if !self.taxes.is_empty() {
+            b = b.taxes(&self.taxes);
+        }
+        b.total(self.total())
+    }
+
+    /// Render the receipt body on its own, as [`write_to`](Self::write_to)
+    /// on a fresh, initialised builder.
+    pub fn render(&self, width: PrintWidth, lang: Language, money: MoneyFormat) -> Vec<u8> {
+        let builder = ReceiptBuilder::new(width)
+            .language(lang)
+            .money_format(money)
+            .init();
+        self.write_to(builder).build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::*;
+
+    fn model() -> ReceiptModel {
+        let mut m = ReceiptModel::new();
+        m.add_item(ItemInput::new("Riz", 2, dec!(4500), None))
+            .add_item(ItemInput::new("Huile", 1, dec!(2000), Some(dec!(500))));
+        m
+    }
+
+    #[test]
+    fn totals_follow_items_discount_and_taxes() {
+        let mut m = model();
+        assert_eq!(m.subtotal(), dec!(10_500));
+        assert_eq!(m.total(), dec!(10_500));
+
+        m.add_tax(TaxEntry::new("TVA 18%", dec!(1890), false))
+            .add_tax(TaxEntry::new("Timbre", dec!(100), true));
+        assert_eq!(m.total(), dec!(12_390));
+
+        m.set_discount(dec!(1000), None);
+        assert_eq!(m.discount(), dec!(1000));
+        assert_eq!(m.total(), dec!(11_390));
+        assert_eq!(m.total_with(&[]), dec!(9500));
+
+        m.clear_discount();
+        assert_eq!(m.total(), dec!(12_390));
+    }
+
+    #[test]
+    fn render_matches_the_builder_sequence() {
+        let mut m = model();
+        m.add_tax(TaxEntry::new("TVA 18%", dec!(1890), false))
+            .set_discount(dec!(500), Some("FIDELE"));
+        let bytes = m.render(PrintWidth::Mm58, Language::En, MoneyFormat::default());
+
+        let expected = ReceiptBuilder::new(PrintWidth::Mm58)
+            .language(Language::En)
+            .init()
+            .items(m.items())
+            .divider('-')
+            .subtotal_ht(dec!(10_500))
+            .discount(dec!(500), Some("FIDELE"))
+            .taxes(m.taxes())
+            .total(dec!(11_890))
+            .build();
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn empty_model_totals_zero() {
+        let m = ReceiptModel::new();
+        assert_eq!(m.subtotal(), Decimal::ZERO);
+        assert_eq!(m.total(), Decimal::ZERO);
+        assert!(!m
+            .render(PrintWidth::Mm80, Language::Fr, MoneyFormat::default())
+            .is_empty());
+    }
+}
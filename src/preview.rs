@@ -0,0 +1,243 @@
+//! Human-readable preview of ESC/POS byte streams.
+//!
+//! Approximates what a receipt will look like on paper without a printer:
+//! control sequences are skipped, text is decoded from CP858, and
+//! non-text output is replaced with placeholders — `[BARCODE: value]`,
+//! `[QR: data]`, `[IMAGE wxh]` — and cuts with a dashed separator.
+//!
+//! ```rust
+//! use thermoprint::{ReceiptBuilder, PrintWidth};
+//! use thermoprint::preview::preview;
+//!
+//! let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+//!     .init()
+//!     .text_line("Café")
+//!     .barcode_code128("ORD-1")
+//!     .cut()
+//!     .build();
+//!
+//! let text = preview(&bytes, PrintWidth::Mm58);
+//! assert!(text.contains("Café\n"));
+//! assert!(text.contains("[BARCODE: ORD-1]"));
+//! ```
+
+use crate::debug::{tokenize, Token};
+use crate::types::PrintWidth;
+
+/// Render an ESC/POS byte stream as plain text.
+///
+/// `width` sets the length of cut and page separators. The stream is split
+/// with [`debug::tokenize`](crate::debug::tokenize), so unknown commands are
+/// skipped on the same best-effort basis, and output for foreign byte
+/// streams may contain stray characters.
+pub fn preview(bytes: &[u8], width: PrintWidth) -> String {
+    let cols = width.cols();
+    let mut out = String::new();
+    let mut qr_data = String::new();
+
+    for token in tokenize(bytes) {
+        let (name, params) = match token {
+            Token::Text(text) => {
+                out.push_str(&text);
+                continue;
+            }
+            Token::LineFeed => {
+                out.push('\n');
+                continue;
+            }
+            Token::FormFeed => {
+                start_line(&mut out);
+                out.push_str(&format!("{:=^cols$}\n", " PAGE "));
+                continue;
+            }
+            Token::Control(_) => continue,
+            Token::Command { name, params } => (name, params),
+        };
+        match name.as_str() {
+            "ESC d" => out.push_str(&"\n".repeat(params.first().copied().unwrap_or(0) as usize)),
+            "ESC *" => {
+                // One command per 24-dot stripe: show the image once
+                start_line(&mut out);
+                if !out.ends_with("[IMAGE]\n") {
+                    out.push_str("[IMAGE]");
+                }
+            }
+            "GS V" => {
+                start_line(&mut out);
+                out.push_str(&format!("{:-^cols$}\n", " CUT "));
+            }
+            "GS k" => {
+                let m = params.first().copied().unwrap_or(0);
+                let data = if m >= 65 {
+                    params.get(2..).unwrap_or(&[])
+                } else {
+                    // NUL-terminated
+                    let data = params.get(1..).unwrap_or(&[]);
+                    data.strip_suffix(&[0]).unwrap_or(data)
+                };
+                let value = if m == 73 {
+                    decode_code128(data)
+                } else {
+                    String::from_utf8_lossy(data).into_owned()
+                };
+                start_line(&mut out);
+                out.push_str(&format!("[BARCODE: {}]", value));
+            }
+            "GS ( k" => {
+                // pL pH, then cn=49 fn=80 m data: store QR data; fn=81: print it
+                let body = params.get(2..).unwrap_or(&[]);
+                if body.len() >= 3 && body[0] == 49 {
+                    match body[1] {
+                        80 => qr_data = String::from_utf8_lossy(&body[3..]).into_owned(),
+                        81 => {
+                            start_line(&mut out);
+                            out.push_str(&format!("[QR: {}]", qr_data));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            "GS v 0" => {
+                let u16_at = |i: usize| {
+                    u16::from_le_bytes([
+                        params.get(i).copied().unwrap_or(0),
+                        params.get(i + 1).copied().unwrap_or(0),
+                    ]) as usize
+                };
+                start_line(&mut out);
+                out.push_str(&format!("[IMAGE {}x{}]", u16_at(1) * 8, u16_at(3)));
+            }
+            _ => {}
+        }
+    }
+    out
+}
+
+/// Turn `GS k 73` data back into the barcode's text: drop code-set
+/// selectors, expand code C symbols to digit pairs and unescape `{{`.
+fn decode_code128(data: &[u8]) -> String {
+    let mut out = String::new();
+    let mut code_c = false;
+    let mut i = 0;
+    while i < data.len() {
+        match (data[i], data.get(i + 1)) {
+            (b'{', Some(b'{')) => {
+                out.push('{');
+                i += 2;
+            }
+            (b'{', Some(&set)) => {
+                code_c = set == b'C';
+                i += 2;
+            }
+            (b, _) if code_c => {
+                out.push_str(&format!("{:02}", b));
+                i += 1;
+            }
+            (b, _) => {
+                out.push(b as char);
+                i += 1;
+            }
+        }
+    }
+    out
+}
+
+/// Make sure the next placeholder starts on its own line.
+fn start_line(out: &mut String) {
+    if !out.is_empty() && !out.ends_with('\n') {
+        out.push('\n');
+    }
+}
+
+/// Decode a Shift-JIS double-byte pair shown inside Kanji mode.
+#[cfg(feature = "shift-jis")]
+pub(crate) fn kanji_char(hi: u8, lo: u8) -> char {
+    let code = u16::from_be_bytes([hi, lo]);
+    crate::sjis_table::SHIFT_JIS
+        .iter()
+        .find(|&&(_, sjis)| sjis == code)
+        .and_then(|&(u, _)| char::from_u32(u as u32))
+        .unwrap_or('?')
+}
+
+/// Decode a Shift-JIS double-byte pair shown inside Kanji mode.
+#[cfg(not(feature = "shift-jis"))]
+pub(crate) fn kanji_char(_hi: u8, _lo: u8) -> char {
+    '?'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::GS;
+    use crate::ReceiptBuilder;
+    use rust_decimal::dec;
+
+    #[test]
+    fn strips_commands_and_keeps_text() {
+        let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+            .init()
+            .bold(true)
+            .text_line("MA BOUTIQUE")
+            .bold(false)
+            .total(dec!(5000))
+            .build();
+        let text = preview(&bytes, PrintWidth::Mm58);
+        assert!(text.contains("MA BOUTIQUE\n"));
+        assert!(text.contains("TOTAL"));
+        assert!(text.contains("5000 FCFA"));
+        assert!(!text.contains('\u{1B}'));
+    }
+
+    #[test]
+    fn placeholders_for_barcodes_qr_and_cut() {
+        let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+            .barcode_code128("ORD-42")
+            .barcode_code128("INV{2024}0042")
+            .barcode_ean13("123456789012")
+            .qr_code("https://example.com", 4)
+            .cut()
+            .build();
+        let text = preview(&bytes, PrintWidth::Mm58);
+        assert!(text.contains("[BARCODE: ORD-42]\n"));
+        assert!(text.contains("[BARCODE: INV{2024}0042]\n"));
+        assert!(text.contains("[BARCODE: 123456789012]\n"));
+        assert!(text.contains("[QR: https://example.com]\n"));
+        assert!(text.contains("------------- CUT --------------"));
+    }
+
+    #[test]
+    fn truncated_commands_do_not_panic() {
+        for bytes in [
+            &[GS][..],
+            &[GS, b'k'],
+            &[GS, b'k', 73, 10, b'A'],
+            &[GS, b'(', b'k', 9],
+        ] {
+            preview(bytes, PrintWidth::Mm58);
+        }
+    }
+
+    #[test]
+    fn commands_skip_their_parameters_like_tokenize() {
+        // ESC $ nL nH takes two parameters, FS q an image payload
+        let bytes = [
+            &[crate::commands::ESC, b'$', b'A', b'B'][..],
+            &[crate::commands::FS, b'q', 1, 1, 0, 1, 0],
+            &[b'Z'; 8],
+            b"ok\n",
+        ]
+        .concat();
+        assert_eq!(preview(&bytes, PrintWidth::Mm58), "ok\n");
+    }
+
+    #[test]
+    fn raster_image_placeholder() {
+        let raster = crate::commands::raster_image(2, 3, &[0xFF; 6]);
+        let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+            .logo_raw(&raster)
+            .text_line("after")
+            .build();
+        assert_eq!(preview(&bytes, PrintWidth::Mm80), "[IMAGE 16x3]\nafter\n");
+    }
+}
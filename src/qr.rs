@@ -0,0 +1,169 @@
+//! Standard QR code payloads.
+//!
+//! Helpers that build the text payloads phone cameras recognise — contact
+//! cards, Wi-Fi credentials and payment links — with the field escaping each
+//! format requires. Feed the result to [`ReceiptBuilder::qr_code`](crate::ReceiptBuilder::qr_code)
+//! or use the `qr_vcard` / `qr_wifi` / `payment_qr` builder shortcuts.
+
+/// Wi-Fi network security type for [`wifi`] payloads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WifiEncryption {
+    /// WPA / WPA2 / WPA3 personal.
+    Wpa,
+    /// Legacy WEP.
+    Wep,
+    /// Open network, no password.
+    None,
+}
+
+/// Build a vCard 3.0 contact payload.
+///
+/// Empty `phone` or `email` fields are omitted. `\`, `,` and `;` in values
+/// are backslash-escaped as the vCard format requires.
+///
+/// ```rust
+/// use thermoprint::qr::vcard;
+/// let payload = vcard("Ma Boutique", "+221770000000", "");
+/// assert!(payload.starts_with("BEGIN:VCARD\n"));
+/// assert!(payload.contains("TEL:+221770000000\n"));
+/// ```
+pub fn vcard(name: &str, phone: &str, email: &str) -> String {
+    let name = escape(name, &['\\', ',', ';']);
+    let mut payload = format!("BEGIN:VCARD\nVERSION:3.0\nN:{};;;;\nFN:{}\n", name, name);
+    if !phone.is_empty() {
+        payload.push_str(&format!("TEL:{}\n", escape(phone, &['\\', ',', ';'])));
+    }
+    if !email.is_empty() {
+        payload.push_str(&format!("EMAIL:{}\n", escape(email, &['\\', ',', ';'])));
+    }
+    payload.push_str("END:VCARD");
+    payload
+}
+
+/// Build a Wi-Fi join payload (`WIFI:S:<ssid>;T:<type>;P:<password>;;`).
+///
+/// `\`, `;`, `,`, `:` and `"` in the SSID and password are backslash-escaped.
+/// The password is omitted for [`WifiEncryption::None`].
+///
+/// ```rust
+/// use thermoprint::qr::{wifi, WifiEncryption};
+/// assert_eq!(
+///     wifi("Shop;Guest", "p@ss:1", WifiEncryption::Wpa),
+///     r"WIFI:S:Shop\;Guest;T:WPA;P:p@ss\:1;;"
+/// );
+/// ```
+pub fn wifi(ssid: &str, password: &str, encryption: WifiEncryption) -> String {
+    const SPECIAL: &[char] = &['\\', ';', ',', ':', '"'];
+    let ssid = escape(ssid, SPECIAL);
+    match encryption {
+        WifiEncryption::Wpa => format!("WIFI:S:{};T:WPA;P:{};;", ssid, escape(password, SPECIAL)),
+        WifiEncryption::Wep => format!("WIFI:S:{};T:WEP;P:{};;", ssid, escape(password, SPECIAL)),
+        WifiEncryption::None => format!("WIFI:S:{};T:nopass;;", ssid),
+    }
+}
+
+/// Append `amount` and `reference` query parameters to a mobile money
+/// payment link (Wave, Orange Money, ...), percent-encoding both values.
+///
+/// Parameters are joined with `&` when `base_url` already has a query
+/// string, and with `?` otherwise.
+///
+/// ```rust
+/// use thermoprint::qr::payment_url;
+/// assert_eq!(
+///     payment_url("https://pay.wave.com/m/M_42", "1500", "CMD 12&13"),
+///     "https://pay.wave.com/m/M_42?amount=1500&reference=CMD%2012%2613"
+/// );
+/// ```
+pub fn payment_url(base_url: &str, amount: &str, reference: &str) -> String {
+    let sep = if !base_url.contains('?') {
+        "?"
+    } else if base_url.ends_with(['?', '&']) {
+        ""
+    } else {
+        "&"
+    };
+    format!(
+        "{}{}amount={}&reference={}",
+        base_url,
+        sep,
+        url_encode(amount),
+        url_encode(reference)
+    )
+}
+
+/// Percent-encode everything but RFC 3986 unreserved characters
+/// (`A-Z a-z 0-9 - . _ ~`), UTF-8 bytes included.
+fn url_encode(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for b in value.bytes() {
+        if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~') {
+            out.push(char::from(b));
+        } else {
+            out.push_str(&format!("%{:02X}", b));
+        }
+    }
+    out
+}
+
+/// Backslash-escape every occurrence of `special` characters, and turn
+/// newlines into a literal `\n`.
+fn escape(value: &str, special: &[char]) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        if c == '\n' {
+            out.push_str("\\n");
+            continue;
+        }
+        if special.contains(&c) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wifi_escapes_special_characters() {
+        let payload = wifi(r#"My"Net\1"#, "a;b,c:d", WifiEncryption::Wpa);
+        assert_eq!(payload, r#"WIFI:S:My\"Net\\1;T:WPA;P:a\;b\,c\:d;;"#);
+    }
+
+    #[test]
+    fn wifi_open_network_has_no_password() {
+        assert_eq!(
+            wifi("Guest", "ignored", WifiEncryption::None),
+            "WIFI:S:Guest;T:nopass;;"
+        );
+    }
+
+    #[test]
+    fn payment_url_encodes_values() {
+        assert_eq!(
+            payment_url(
+                "https://pay.example/p?merchant=7",
+                "12.50",
+                "Facture n°3 = payée"
+            ),
+            "https://pay.example/p?merchant=7&amount=12.50\
+             &reference=Facture%20n%C2%B03%20%3D%20pay%C3%A9e"
+        );
+        assert_eq!(
+            payment_url("https://pay.example/p?", "1", "a/b"),
+            "https://pay.example/p?amount=1&reference=a%2Fb"
+        );
+    }
+
+    #[test]
+    fn vcard_escapes_and_skips_empty_fields() {
+        let payload = vcard("Diop, Fils; Cie", "", "contact@shop.sn");
+        assert!(payload.contains(r"FN:Diop\, Fils\; Cie"));
+        assert!(!payload.contains("TEL:"));
+        assert!(payload.contains("EMAIL:contact@shop.sn\n"));
+        assert!(payload.ends_with("END:VCARD"));
+    }
+}
@@ -14,6 +14,7 @@
 //!   "elements": [
 //!     { "type": "shop_header", "name": "MA BOUTIQUE", "phone": "+221 77 000 00 00", "address": "Dakar" },
 //!     { "type": "divider", "char": "=" },
+//!     { "type": "money_format", "decimals": 0, "thousands_sep": " " },
 //!     { "type": "item", "name": "Polo shirt", "qty": 2, "unit_price": "15000" },
 //!     { "type": "divider", "char": "-" },
 //!     { "type": "subtotal", "amount": "30000" },
@@ -22,8 +23,9 @@
 //!     { "type": "received", "amount": "40000" },
 //!     { "type": "change", "amount": "4600" },
 //!     { "type": "divider", "char": "=" },
-//!     { "type": "barcode_code128", "value": "ORD-2024-001" },
+//!     { "type": "barcode", "symbology": "code128", "value": "ORD-2024-001" },
 //!     { "type": "qr_code", "data": "https://example.com", "size": 4 },
+//!     { "type": "qr_captioned", "data": "https://example.com/f/42", "caption": "Scannez pour votre facture" },
 //!     { "type": "served_by", "name": "Mamadou" },
 //!     { "type": "thank_you", "shop_name": "MA BOUTIQUE" },
 //!     { "type": "feed", "lines": 3 },
@@ -33,15 +35,20 @@
 //! ```
 
 use rust_decimal::Decimal;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 use std::str::FromStr;
 
 use crate::builder::ReceiptBuilder;
+use crate::dialect::{Dialect, Symbology};
 use crate::i18n::Language;
-use crate::types::{PrintWidth, TaxEntry};
+use crate::types::{Direction, HriPosition, ItemStyle, PrintWidth, TaxEntry};
 
 /// A complete receipt template that can be rendered to ESC/POS bytes.
-#[derive(Debug, Deserialize)]
+///
+/// Serializes back to the same JSON format, so layouts built in code or
+/// in a visual editor can be saved with [`to_json`](Self::to_json).
+#[derive(Debug, Deserialize, Serialize)]
 pub struct ReceiptTemplate {
     /// Paper width: `"58mm"`, `"80mm"`, or `"a4"`.
     #[serde(default = "default_width")]
@@ -55,6 +62,14 @@ pub struct ReceiptTemplate {
     #[serde(default = "default_language")]
     pub language: String,
 
+    /// Layout direction: `"ltr"` (default) or `"rtl"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub direction: Option<String>,
+
+    /// Printer command set: `"epson"` (default) or `"star"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dialect: Option<String>,
+
     /// Ordered list of receipt elements.
     pub elements: Vec<Element>,
 }
@@ -72,8 +87,8 @@ fn default_language() -> String {
 /// A single element in a receipt template.
 ///
 /// Each variant maps to a JSON object with `"type": "variant_name"`.
-/// Fields are deserialized from the same JSON object.
-#[derive(Debug, Deserialize)]
+/// Fields are deserialized from, and serialized to, the same JSON object.
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 #[allow(missing_docs)]
 pub enum Element {
@@ -107,6 +122,23 @@ pub enum Element {
         ch: String,
     },
 
+    /// Section title centred in a full-width rule, e.g. `--- PAIEMENT ---`.
+    Section {
+        title: String,
+        #[serde(default = "default_divider_char", rename = "char")]
+        ch: String,
+    },
+
+    /// Rule split at column boundaries, e.g. `-----+----------+----`.
+    ColumnDivider {
+        /// Segment widths; the last one stretches to fill the line.
+        widths: Vec<usize>,
+        #[serde(default = "default_divider_char", rename = "char")]
+        ch: String,
+        #[serde(default = "default_junction")]
+        junction: String,
+    },
+
     /// Blank line.
     Blank,
 
@@ -143,6 +175,20 @@ pub enum Element {
         value: String,
     },
 
+    /// Money formatting for all following amounts.
+    MoneyFormat {
+        #[serde(default)]
+        decimals: u8,
+        /// Single character, or `""` for no grouping.
+        #[serde(default)]
+        thousands_sep: String,
+        /// Single character.
+        #[serde(default = "default_decimal_sep")]
+        decimal_sep: String,
+        #[serde(default)]
+        symbol_before: bool,
+    },
+
     /// Line item.
     Item {
         name: String,
@@ -150,8 +196,18 @@ pub enum Element {
         /// Decimal string, e.g. `"15000"`.
         unit_price: String,
         /// Optional discount as decimal string.
-        #[serde(default)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
         discount: Option<String>,
+        /// Optional VAT rate in percent as decimal string, e.g. `"18"`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        vat_rate: Option<String>,
+    },
+
+    /// Print following items on one line each (`true`) or in the detailed
+    /// multi-line layout (`false`).
+    CompactItems {
+        #[serde(default = "default_true")]
+        on: bool,
     },
 
     /// Subtotal excluding tax.
@@ -169,11 +225,22 @@ pub enum Element {
         included: bool,
     },
 
+    /// Indented add-on or modifier under the previous item.
+    SubItem {
+        name: String,
+        /// Optional signed price change as decimal string, e.g. `"500"`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        price_delta: Option<String>,
+    },
+
+    /// VAT breakdown of all items that carried a `vat_rate`.
+    AutoVatSummary,
+
     /// Discount line.
     Discount {
         /// Decimal string.
         amount: String,
-        #[serde(default)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
         coupon_code: Option<String>,
     },
 
@@ -183,6 +250,12 @@ pub enum Element {
         amount: String,
     },
 
+    /// Amount written out in words in the template language.
+    TotalInWords {
+        /// Decimal string.
+        amount: String,
+    },
+
     /// Amount received.
     Received {
         /// Decimal string.
@@ -201,12 +274,60 @@ pub enum Element {
     /// Thank you footer.
     ThankYou { shop_name: String },
 
+    /// Order metadata block, e.g. `[["Commande", "#1042"], ["Caissier", "Awa"]]`.
+    Meta { rows: Vec<[String; 2]> },
+
+    /// Fiscal identifiers block, e.g. `[["NINEA", "005123456"], ["RCCM", "SN-DKR-..."]]`.
+    FiscalFooter { fields: Vec<[String; 2]> },
+
+    /// Barcode in any supported symbology.
+    Barcode {
+        /// `"code128"`, `"ean13"` or `"ean8"`.
+        symbology: String,
+        value: String,
+        /// Bar height in dots (default 60).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        height: Option<u8>,
+        /// Module width in dots (default 2).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        width: Option<u8>,
+        /// HRI text position for this barcode only: `"below"`, `"above"`,
+        /// `"both"` or `"none"`. Defaults to the builder's setting.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        hri: Option<String>,
+    },
+
     /// CODE128 barcode.
+    #[deprecated(note = "use `Element::Barcode` with `symbology: \"code128\"`")]
     BarcodeCode128 { value: String },
 
     /// EAN-13 barcode.
+    #[deprecated(note = "use `Element::Barcode` with `symbology: \"ean13\"`")]
     BarcodeEan13 { value: String },
 
+    /// Label centred above a centred barcode, e.g. for shelf labels.
+    LabeledBarcode {
+        label: String,
+        value: String,
+        /// `"code128"` (default), `"ean13"` or `"ean8"`.
+        #[serde(default = "default_symbology")]
+        symbology: String,
+    },
+
+    /// Line feed after following barcodes / QR codes (on by default).
+    CodeLineFeed {
+        #[serde(default = "default_true")]
+        on: bool,
+    },
+
+    /// Order number as bold centred text above a CODE128 barcode.
+    OrderReference {
+        number: String,
+        /// Append a Luhn check digit (numeric numbers only).
+        #[serde(default)]
+        check_digit: bool,
+    },
+
     /// QR code.
     QrCode {
         data: String,
@@ -214,17 +335,52 @@ pub enum Element {
         size: u8,
     },
 
+    /// Centred QR code with a centred caption underneath.
+    QrCaptioned {
+        data: String,
+        #[serde(default = "default_qr_size")]
+        size: u8,
+        caption: String,
+    },
+
+    /// Mobile money payment QR with the amount and reference in the link.
+    PaymentQr {
+        base_url: String,
+        amount: String,
+        reference: String,
+    },
+
+    /// Image file loaded from disk and rasterised to the paper width.
+    ///
+    /// Requires the `native` feature; other builds return
+    /// [`TemplateError::ImagePathUnsupported`]. The path is opened as
+    /// given, so only render templates from trusted sources.
+    ImagePath {
+        path: String,
+        /// `"threshold"`, `"floyd_steinberg"`, `"sierra_lite"` or `"jjn"`;
+        /// omitted uses a plain mid-grey threshold after a high-quality
+        /// resize.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        dither: Option<String>,
+    },
+
     /// Feed n lines.
     Feed {
         #[serde(default = "default_feed")]
         lines: u8,
     },
 
-    /// Partial cut.
-    Cut,
+    /// Partial cut, optionally after feeding `feed` motion units.
+    Cut {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        feed: Option<u8>,
+    },
 
-    /// Full cut.
-    CutFull,
+    /// Full cut, optionally after feeding `feed` motion units.
+    CutFull {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        feed: Option<u8>,
+    },
 
     /// Form feed (A4 page eject).
     FormFeed,
@@ -236,9 +392,18 @@ pub enum Element {
 fn default_divider_char() -> String {
     "-".to_owned()
 }
+fn default_junction() -> String {
+    "+".to_owned()
+}
+fn default_decimal_sep() -> String {
+    ".".to_owned()
+}
 fn default_true() -> bool {
     true
 }
+fn default_symbology() -> String {
+    "code128".to_owned()
+}
 fn default_qr_size() -> u8 {
     4
 }
@@ -273,6 +438,92 @@ pub enum TemplateError {
     /// An unknown alignment value was provided.
     #[error("Unknown alignment '{0}'. Use 'left', 'center', or 'right'.")]
     UnknownAlign(String),
+
+    /// An unknown layout direction was provided.
+    #[error("Unknown direction '{0}'. Use 'ltr' or 'rtl'.")]
+    UnknownDirection(String),
+
+    /// An unknown printer dialect was provided.
+    #[error("Unknown dialect '{0}'. Use 'epson' or 'star'.")]
+    UnknownDialect(String),
+
+    /// An unknown barcode symbology was provided.
+    #[error("Unknown symbology '{0}'. Use 'code128', 'ean13', or 'ean8'.")]
+    UnknownSymbology(String),
+
+    /// An unknown barcode HRI position was provided.
+    #[error("Unknown HRI position '{0}'. Use 'below', 'above', 'both', or 'none'.")]
+    UnknownHri(String),
+
+    /// A barcode value could not be encoded.
+    #[error("{0}")]
+    InvalidBarcode(String),
+
+    /// An unknown dithering method was provided.
+    #[error(
+        "Unknown dither method '{0}'. Use 'threshold', 'floyd_steinberg', 'sierra_lite', or 'jjn'."
+    )]
+    UnknownDither(String),
+
+    /// An image file could not be loaded.
+    #[error("{0}")]
+    ImageLoad(String),
+
+    /// Image paths were used in a build without filesystem access.
+    #[error(
+        "Image path '{0}' is not supported in this build. Enable the 'native' feature, or \
+         rasterise the image yourself and use logo_raw."
+    )]
+    ImagePathUnsupported(String),
+
+    /// A money format separator was not a single character.
+    #[error("Invalid separator '{0}'. Use a single character.")]
+    InvalidSeparator(String),
+
+    /// Rendering the element at `index` (0-based) in `elements` failed.
+    #[error("Element {index}: {source}")]
+    ElementError {
+        /// Position of the failing element in the template's `elements`.
+        index: usize,
+        /// What went wrong with it.
+        source: Box<TemplateError>,
+    },
+}
+
+impl TemplateError {
+    /// Stable, machine-readable identifier for this error kind, e.g.
+    /// `"unknown_width"`. Used to prefix WASM error messages so JavaScript
+    /// callers can branch without matching on the human-readable text.
+    ///
+    /// An [`ElementError`](Self::ElementError) reports the code of the
+    /// underlying error.
+    pub fn code(&self) -> &'static str {
+        match self {
+            TemplateError::ElementError { source, .. } => source.code(),
+            TemplateError::JsonError(_) => "invalid_json",
+            TemplateError::InvalidDecimal { .. } => "invalid_decimal",
+            TemplateError::UnknownWidth(_) => "unknown_width",
+            TemplateError::UnknownLanguage(_) => "unknown_language",
+            TemplateError::UnknownAlign(_) => "unknown_align",
+            TemplateError::UnknownDirection(_) => "unknown_direction",
+            TemplateError::UnknownDialect(_) => "unknown_dialect",
+            TemplateError::UnknownSymbology(_) => "unknown_symbology",
+            TemplateError::UnknownHri(_) => "unknown_hri",
+            TemplateError::InvalidBarcode(_) => "invalid_barcode",
+            TemplateError::UnknownDither(_) => "unknown_dither",
+            TemplateError::ImageLoad(_) => "image_load",
+            TemplateError::ImagePathUnsupported(_) => "image_path_unsupported",
+            TemplateError::InvalidSeparator(_) => "invalid_separator",
+        }
+    }
+
+    /// Index of the element that failed, if the error came from one.
+    pub fn element_index(&self) -> Option<usize> {
+        match self {
+            TemplateError::ElementError { index, .. } => Some(*index),
+            _ => None,
+        }
+    }
 }
 
 impl ReceiptTemplate {
@@ -281,20 +532,78 @@ impl ReceiptTemplate {
         Ok(serde_json::from_str(json)?)
     }
 
+    /// Serialize this template to pretty-printed JSON that
+    /// [`from_json`](Self::from_json) reads back unchanged. Optional fields
+    /// that are unset are left out.
+    ///
+    /// ```rust
+    /// use thermoprint::template::{Element, ReceiptTemplate};
+    /// let mut template = ReceiptTemplate::from_json(r#"{ "elements": [] }"#)?;
+    /// template.elements.push(Element::TextLine { text: "Bienvenue".to_owned() });
+    /// let json = template.to_json()?;
+    /// assert!(json.contains(r#""type": "text_line""#));
+    /// # Ok::<(), thermoprint::template::TemplateError>(())
+    /// ```
+    pub fn to_json(&self) -> Result<String, TemplateError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
     /// Render this template to ESC/POS bytes.
     pub fn render(&self) -> Result<Vec<u8>, TemplateError> {
+        Ok(ReceiptBuilder::from_template(self)?.build())
+    }
+
+    /// A builder set up with this template's width, currency, language,
+    /// dialect and direction, before any element is applied.
+    pub(crate) fn configured_builder(&self) -> Result<ReceiptBuilder, TemplateError> {
         let width = parse_width(&self.width)?;
         let lang = parse_language(&self.language)?;
 
         let mut builder = ReceiptBuilder::new(width)
             .currency(&self.currency)
             .language(lang);
+        if let Some(dialect) = &self.dialect {
+            builder = builder.dialect(parse_dialect(dialect)?);
+        }
+        if let Some(dir) = &self.direction {
+            builder = builder.direction(parse_direction(dir)?);
+        }
+        Ok(builder)
+    }
 
-        for element in &self.elements {
-            builder = apply_element(builder, element)?;
+    /// Apply this template's elements in order onto `builder`, wrapping
+    /// failures in [`TemplateError::ElementError`].
+    pub(crate) fn apply_elements(
+        &self,
+        builder: ReceiptBuilder,
+    ) -> Result<ReceiptBuilder, TemplateError> {
+        self.apply_elements_from(builder, 0)
+    }
+
+    /// [`apply_elements`](Self::apply_elements), skipping the first `skip`
+    /// elements; error indexes still count from the start of `elements`.
+    fn apply_elements_from(
+        &self,
+        mut builder: ReceiptBuilder,
+        skip: usize,
+    ) -> Result<ReceiptBuilder, TemplateError> {
+        for (index, element) in self.elements.iter().enumerate().skip(skip) {
+            builder = apply_element(builder, element).map_err(|e| TemplateError::ElementError {
+                index,
+                source: Box::new(e),
+            })?;
         }
+        Ok(builder)
+    }
 
-        Ok(builder.build())
+    /// Render this template to a plain-text preview of the printed receipt.
+    ///
+    /// Useful for designer preview panes and tests; see
+    /// [`preview`](crate::preview::preview) for how non-text output is shown.
+    pub fn render_preview(&self) -> Result<String, TemplateError> {
+        let width = parse_width(&self.width)?;
+        let bytes = self.render()?;
+        Ok(crate::preview::preview(&bytes, width))
     }
 }
 
@@ -322,6 +631,495 @@ pub fn render_json(json: &str) -> Result<Vec<u8>, TemplateError> {
     template.render()
 }
 
+/// Render several JSON templates back-to-back into one stream, e.g. for a
+/// batch job printing many small receipts.
+///
+/// With `cut_between`, a cut follows every receipt but the last (a form
+/// feed on A4); leave it off when the templates end with their own cut.
+/// When the first template starts with an `init` element, the leading
+/// `init` of each later template for the same dialect is replaced by the
+/// code page and style defaults alone: the printer is already initialised,
+/// and `ESC @` between receipts only costs paper feed. Bold, size,
+/// underline and alignment left on by one receipt are still reset before
+/// the next. A template for another dialect keeps its full `init`.
+///
+/// The first template that fails to parse or render aborts the batch.
+///
+/// ```rust
+/// use thermoprint::template::render_batch;
+///
+/// let a = r#"{ "elements": [{ "type": "init" }, { "type": "text_line", "text": "A" }] }"#;
+/// let b = r#"{ "elements": [{ "type": "init" }, { "type": "text_line", "text": "B" }] }"#;
+/// let bytes = render_batch(&[a, b], true).unwrap();
+/// assert_eq!(bytes.windows(2).filter(|w| w == b"\x1B@").count(), 2); // one init_with(2)
+/// ```
+pub fn render_batch(templates: &[&str], cut_between: bool) -> Result<Vec<u8>, TemplateError> {
+    let mut out = Vec::new();
+    // Dialect of the first template, if it initialised the printer
+    let mut initialised = None;
+    for (i, json) in templates.iter().enumerate() {
+        let template = ReceiptTemplate::from_json(json)?;
+        let starts_with_init = matches!(template.elements.first(), Some(Element::Init));
+        let dialect = template
+            .dialect
+            .as_deref()
+            .map(parse_dialect)
+            .transpose()?
+            .unwrap_or_default();
+        if i == 0 && starts_with_init {
+            initialised = Some(dialect);
+        }
+        let mut builder = template.configured_builder()?;
+        let skip = i > 0 && starts_with_init && initialised == Some(dialect);
+        if skip {
+            builder = builder.reset_text_state();
+        }
+        let mut builder = template.apply_elements_from(builder, usize::from(skip))?;
+        if cut_between && i + 1 < templates.len() {
+            builder = builder.cut();
+        }
+        out.extend(builder.build());
+    }
+    Ok(out)
+}
+
+/// JSON Schema (draft 2020-12) describing [`ReceiptTemplate`] and every
+/// [`Element`] type, for editor validation and autocomplete.
+///
+/// Amounts are decimal strings and enumerated settings list the values
+/// the renderer accepts. The schema is stricter than [`render_json`]:
+/// unknown fields are rejected, which catches misspelt field names.
+///
+/// ```rust
+/// let schema = thermoprint::template::json_schema();
+/// assert_eq!(schema["required"][0], "elements");
+/// let text = serde_json::to_string_pretty(&schema).unwrap(); // template.schema.json
+/// ```
+pub fn json_schema() -> Value {
+    let elements = element_schemas();
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "thermoprint receipt template",
+        "type": "object",
+        "properties": {
+            "width": {
+                "description": "Paper width",
+                "enum": ["58mm", "58", "80mm", "80", "a4"],
+                "default": "80mm"
+            },
+            "currency": {
+                "description": "Currency symbol",
+                "type": "string",
+                "default": "FCFA"
+            },
+            "language": {
+                "description": "Receipt label language",
+                "enum": [
+                    "fr", "french", "en", "english", "es", "spanish",
+                    "pt", "portuguese", "ar", "arabic", "wo", "wolof"
+                ],
+                "default": "fr"
+            },
+            "direction": { "description": "Layout direction", "enum": ["ltr", "rtl"] },
+            "dialect": { "description": "Printer command set", "enum": ["epson", "star"] },
+            "elements": {
+                "description": "Receipt elements, printed in order",
+                "type": "array",
+                "items": { "oneOf": elements }
+            }
+        },
+        "required": ["elements"],
+        "additionalProperties": false
+    })
+}
+
+/// One schema per [`Element`] variant, tagged by its `"type"`.
+fn element_schemas() -> Vec<Value> {
+    let text = || json!({ "type": "string" });
+    let amount = || {
+        json!({
+            "description": "Decimal string, e.g. \"15000\" or \"149.99\"",
+            "type": "string",
+            "pattern": "^-?[0-9]+(\\.[0-9]+)?$"
+        })
+    };
+    let opt_amount = || {
+        let mut s = amount();
+        s["type"] = json!(["string", "null"]);
+        s
+    };
+    let toggle = || json!({ "type": "boolean", "default": true });
+    let flag = || json!({ "type": "boolean", "default": false });
+    let byte = |default: u8| json!({ "type": "integer", "minimum": 0, "maximum": 255, "default": default });
+    let opt_feed = || {
+        json!({
+            "description": "Motion units to feed before cutting",
+            "type": ["integer", "null"],
+            "minimum": 0,
+            "maximum": 255
+        })
+    };
+    let rule_char = || json!({ "type": "string", "minLength": 1, "default": "-" });
+
+    vec![
+        element(
+            "init",
+            "Printer init (reset + code page). Should be first.",
+            &[],
+            &[],
+        ),
+        element(
+            "shop_header",
+            "Shop header block (centered, bold, large name).",
+            &[
+                ("name", text()),
+                ("phone", json!({ "type": "string", "default": "" })),
+                ("address", json!({ "type": "string", "default": "" })),
+            ],
+            &["name"],
+        ),
+        element(
+            "text_line",
+            "A single text line.",
+            &[("text", text())],
+            &["text"],
+        ),
+        element(
+            "centered",
+            "Centered text line.",
+            &[("text", text())],
+            &["text"],
+        ),
+        element(
+            "right",
+            "Right-aligned text line.",
+            &[("text", text())],
+            &["text"],
+        ),
+        element(
+            "row",
+            "Two-column row (label left, value right).",
+            &[("left", text()), ("right", text())],
+            &["left", "right"],
+        ),
+        element(
+            "divider",
+            "Full-width divider.",
+            &[("char", rule_char())],
+            &[],
+        ),
+        element(
+            "section",
+            "Section title centred in a full-width rule.",
+            &[("title", text()), ("char", rule_char())],
+            &["title"],
+        ),
+        element(
+            "column_divider",
+            "Rule split at column boundaries, e.g. `-----+----------+----`.",
+            &[
+                (
+                    "widths",
+                    json!({ "type": "array", "items": { "type": "integer", "minimum": 0 } }),
+                ),
+                ("char", rule_char()),
+                (
+                    "junction",
+                    json!({ "type": "string", "minLength": 1, "default": "+" }),
+                ),
+            ],
+            &["widths"],
+        ),
+        element("blank", "Blank line.", &[], &[]),
+        element("bold", "Toggle bold.", &[("on", toggle())], &[]),
+        element(
+            "double_size",
+            "Toggle double size.",
+            &[("on", toggle())],
+            &[],
+        ),
+        element(
+            "double_height",
+            "Toggle double height.",
+            &[("on", toggle())],
+            &[],
+        ),
+        element("normal_size", "Reset to normal size.", &[], &[]),
+        element("underline", "Toggle underline.", &[("on", toggle())], &[]),
+        element(
+            "align",
+            "Set alignment.",
+            &[("value", json!({ "enum": ["left", "center", "right"] }))],
+            &["value"],
+        ),
+        element(
+            "money_format",
+            "Money formatting for all following amounts.",
+            &[
+                ("decimals", byte(0)),
+                (
+                    "thousands_sep",
+                    json!({ "type": "string", "maxLength": 1, "default": "" }),
+                ),
+                (
+                    "decimal_sep",
+                    json!({ "type": "string", "minLength": 1, "maxLength": 1, "default": "." }),
+                ),
+                ("symbol_before", flag()),
+            ],
+            &[],
+        ),
+        element(
+            "item",
+            "Line item.",
+            &[
+                ("name", text()),
+                ("qty", json!({ "type": "integer" })),
+                ("unit_price", amount()),
+                ("discount", opt_amount()),
+                ("vat_rate", opt_amount()),
+            ],
+            &["name", "qty", "unit_price"],
+        ),
+        element(
+            "compact_items",
+            "Print following items on one line each.",
+            &[("on", toggle())],
+            &[],
+        ),
+        element(
+            "subtotal",
+            "Subtotal excluding tax.",
+            &[("amount", amount())],
+            &["amount"],
+        ),
+        element(
+            "tax",
+            "Single tax line.",
+            &[
+                ("label", text()),
+                ("amount", amount()),
+                ("included", flag()),
+            ],
+            &["label", "amount"],
+        ),
+        element(
+            "sub_item",
+            "Indented add-on or modifier under the previous item.",
+            &[("name", text()), ("price_delta", opt_amount())],
+            &["name"],
+        ),
+        element(
+            "auto_vat_summary",
+            "VAT breakdown of all items that carried a vat_rate.",
+            &[],
+            &[],
+        ),
+        element(
+            "discount",
+            "Discount line.",
+            &[
+                ("amount", amount()),
+                ("coupon_code", json!({ "type": ["string", "null"] })),
+            ],
+            &["amount"],
+        ),
+        element(
+            "total",
+            "Grand total.",
+            &[("amount", amount())],
+            &["amount"],
+        ),
+        element(
+            "total_in_words",
+            "Amount written out in words in the template language.",
+            &[("amount", amount())],
+            &["amount"],
+        ),
+        element(
+            "received",
+            "Amount received.",
+            &[("amount", amount())],
+            &["amount"],
+        ),
+        element(
+            "change",
+            "Change to return.",
+            &[("amount", amount())],
+            &["amount"],
+        ),
+        element(
+            "served_by",
+            "Served by footer.",
+            &[("name", text())],
+            &["name"],
+        ),
+        element(
+            "thank_you",
+            "Thank you footer.",
+            &[("shop_name", text())],
+            &["shop_name"],
+        ),
+        element(
+            "meta",
+            "Order metadata block (order number, date, cashier, ...) as [label, value] pairs.",
+            &[(
+                "rows",
+                json!({
+                    "type": "array",
+                    "items": {
+                        "type": "array",
+                        "prefixItems": [{ "type": "string" }, { "type": "string" }],
+                        "minItems": 2,
+                        "maxItems": 2
+                    }
+                }),
+            )],
+            &["rows"],
+        ),
+        element(
+            "fiscal_footer",
+            "Fiscal identifiers block as [label, value] pairs.",
+            &[(
+                "fields",
+                json!({
+                    "type": "array",
+                    "items": {
+                        "type": "array",
+                        "prefixItems": [{ "type": "string" }, { "type": "string" }],
+                        "minItems": 2,
+                        "maxItems": 2
+                    }
+                }),
+            )],
+            &["fields"],
+        ),
+        element(
+            "barcode",
+            "Barcode in any supported symbology.",
+            &[
+                ("symbology", json!({ "enum": ["code128", "ean13", "ean8"] })),
+                ("value", text()),
+                (
+                    "height",
+                    json!({ "type": "integer", "minimum": 1, "maximum": 255, "default": 60 }),
+                ),
+                (
+                    "width",
+                    json!({ "type": "integer", "minimum": 1, "maximum": 6, "default": 2 }),
+                ),
+                ("hri", json!({ "enum": ["below", "above", "both", "none"] })),
+            ],
+            &["symbology", "value"],
+        ),
+        deprecated(element(
+            "barcode_code128",
+            "CODE128 barcode. Deprecated: use `barcode` with `symbology: \"code128\"`.",
+            &[("value", text())],
+            &["value"],
+        )),
+        deprecated(element(
+            "barcode_ean13",
+            "EAN-13 barcode. Deprecated: use `barcode` with `symbology: \"ean13\"`.",
+            &[(
+                "value",
+                json!({ "type": "string", "pattern": "^[0-9]{12,13}$" }),
+            )],
+            &["value"],
+        )),
+        element(
+            "labeled_barcode",
+            "Label centred above a centred barcode, e.g. for shelf labels.",
+            &[
+                ("label", text()),
+                ("value", text()),
+                (
+                    "symbology",
+                    json!({ "enum": ["code128", "ean13", "ean8"], "default": "code128" }),
+                ),
+            ],
+            &["label", "value"],
+        ),
+        element(
+            "code_line_feed",
+            "Line feed after following barcodes / QR codes.",
+            &[("on", toggle())],
+            &[],
+        ),
+        element(
+            "order_reference",
+            "Order number as bold centred text above a CODE128 barcode.",
+            &[("number", text()), ("check_digit", flag())],
+            &["number"],
+        ),
+        element(
+            "qr_code",
+            "QR code.",
+            &[("data", text()), ("size", byte(4))],
+            &["data"],
+        ),
+        element(
+            "qr_captioned",
+            "Centred QR code with a centred caption underneath.",
+            &[("data", text()), ("size", byte(4)), ("caption", text())],
+            &["data", "caption"],
+        ),
+        element(
+            "payment_qr",
+            "Centred mobile money payment QR; amount and reference are appended to base_url.",
+            &[
+                ("base_url", text()),
+                ("amount", amount()),
+                ("reference", text()),
+            ],
+            &["base_url", "amount", "reference"],
+        ),
+        element(
+            "image_path",
+            "Image file rasterised to the paper width (native builds only).",
+            &[
+                ("path", text()),
+                (
+                    "dither",
+                    json!({ "enum": ["threshold", "floyd_steinberg", "sierra_lite", "jjn", null] }),
+                ),
+            ],
+            &["path"],
+        ),
+        element("feed", "Feed n lines.", &[("lines", byte(3))], &[]),
+        element("cut", "Partial cut.", &[("feed", opt_feed())], &[]),
+        element("cut_full", "Full cut.", &[("feed", opt_feed())], &[]),
+        element("form_feed", "Form feed (A4 page eject).", &[], &[]),
+        element("open_cash_drawer", "Open cash drawer.", &[], &[]),
+    ]
+}
+
+/// Schema for one element object: `"type": kind` plus `fields`.
+fn element(kind: &str, description: &str, fields: &[(&str, Value)], required: &[&str]) -> Value {
+    let mut properties = serde_json::Map::new();
+    properties.insert("type".to_owned(), json!({ "const": kind }));
+    for (name, schema) in fields {
+        properties.insert((*name).to_owned(), schema.clone());
+    }
+    let required: Vec<&str> = std::iter::once("type")
+        .chain(required.iter().copied())
+        .collect();
+    json!({
+        "title": kind,
+        "description": description,
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false
+    })
+}
+
+/// Mark an element schema as deprecated (JSON Schema 2019-09 `deprecated`).
+fn deprecated(mut schema: Value) -> Value {
+    schema["deprecated"] = json!(true);
+    schema
+}
+
 // ── Internal helpers ─────────────────────────────────────────────────────────
 
 fn parse_decimal(s: &str) -> Result<Decimal, TemplateError> {
@@ -361,10 +1159,98 @@ fn parse_align(s: &str) -> Result<crate::types::Align, TemplateError> {
     }
 }
 
+fn parse_dialect(s: &str) -> Result<Dialect, TemplateError> {
+    match s.to_lowercase().as_str() {
+        "epson" => Ok(Dialect::Epson),
+        "star" => Ok(Dialect::Star),
+        _ => Err(TemplateError::UnknownDialect(s.to_owned())),
+    }
+}
+
+fn parse_symbology(s: &str) -> Result<Symbology, TemplateError> {
+    match s {
+        "code128" => Ok(Symbology::Code128),
+        "ean13" => Ok(Symbology::Ean13),
+        "ean8" => Ok(Symbology::Ean8),
+        _ => Err(TemplateError::UnknownSymbology(s.to_owned())),
+    }
+}
+
+fn parse_hri(s: &str) -> Result<HriPosition, TemplateError> {
+    match s {
+        "below" => Ok(HriPosition::Below),
+        "above" => Ok(HriPosition::Above),
+        "both" => Ok(HriPosition::Both),
+        "none" => Ok(HriPosition::None),
+        _ => Err(TemplateError::UnknownHri(s.to_owned())),
+    }
+}
+
+fn parse_direction(s: &str) -> Result<Direction, TemplateError> {
+    match s.to_lowercase().as_str() {
+        "ltr" => Ok(Direction::Ltr),
+        "rtl" => Ok(Direction::Rtl),
+        _ => Err(TemplateError::UnknownDirection(s.to_owned())),
+    }
+}
+
+fn parse_dither(s: &str) -> Result<crate::dither::DitherMethod, TemplateError> {
+    match s {
+        "threshold" => Ok(crate::dither::DitherMethod::Threshold),
+        "floyd_steinberg" => Ok(crate::dither::DitherMethod::FloydSteinberg),
+        "sierra_lite" => Ok(crate::dither::DitherMethod::SierraLite),
+        "jjn" => Ok(crate::dither::DitherMethod::Jjn),
+        _ => Err(TemplateError::UnknownDither(s.to_owned())),
+    }
+}
+
+/// Load and rasterise an image file to at most `max_px` dots wide.
+#[cfg(feature = "native")]
+fn load_image(path: &str, max_px: u32, dither: Option<&str>) -> Result<Vec<u8>, TemplateError> {
+    let method = dither.map(parse_dither).transpose()?;
+    let Some(method) = method else {
+        return crate::image::load_and_rasterise(path, max_px)
+            .map_err(|e| TemplateError::ImageLoad(e.to_string()));
+    };
+    let img = ::image::open(path)
+        .map_err(|e| {
+            TemplateError::ImageLoad(
+                crate::error::ThermoprintError::LogoLoad {
+                    path: path.to_owned(),
+                    reason: e.to_string(),
+                }
+                .to_string(),
+            )
+        })?
+        .to_rgba8();
+    Ok(crate::dither::dither_rgba(
+        img.as_raw(),
+        img.width(),
+        img.height(),
+        max_px,
+        method,
+    ))
+}
+
+#[cfg(not(feature = "native"))]
+fn load_image(path: &str, _max_px: u32, dither: Option<&str>) -> Result<Vec<u8>, TemplateError> {
+    dither.map(parse_dither).transpose()?;
+    Err(TemplateError::ImagePathUnsupported(path.to_owned()))
+}
+
+fn parse_separator(s: &str) -> Result<char, TemplateError> {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(TemplateError::InvalidSeparator(s.to_owned())),
+    }
+}
+
 fn apply_element(
     builder: ReceiptBuilder,
     element: &Element,
 ) -> Result<ReceiptBuilder, TemplateError> {
+    let width = builder.paper_width();
     let b = match element {
         Element::Init => builder.init(),
 
@@ -384,6 +1270,21 @@ fn apply_element(
             builder.divider(c)
         }
 
+        Element::Section { title, ch } => {
+            let c = ch.chars().next().unwrap_or('-');
+            builder.section(title, c)
+        }
+
+        Element::ColumnDivider {
+            widths,
+            ch,
+            junction,
+        } => {
+            let c = ch.chars().next().unwrap_or('-');
+            let j = junction.chars().next().unwrap_or('+');
+            builder.column_divider(widths, c, j)
+        }
+
         Element::Blank => builder.blank(),
         Element::Bold { on } => builder.bold(*on),
         Element::DoubleSize { on } => builder.double_size(*on),
@@ -393,17 +1294,45 @@ fn apply_element(
 
         Element::Align { value } => builder.align(parse_align(value)?),
 
+        Element::MoneyFormat {
+            decimals,
+            thousands_sep,
+            decimal_sep,
+            symbol_before,
+        } => {
+            let thousands = match thousands_sep.as_str() {
+                "" => None,
+                sep => Some(parse_separator(sep)?),
+            };
+            builder.money_format(crate::types::MoneyFormat::new(
+                u32::from(*decimals),
+                thousands,
+                parse_separator(decimal_sep)?,
+                *symbol_before,
+            ))
+        }
+
         Element::Item {
             name,
             qty,
             unit_price,
             discount,
+            vat_rate,
         } => {
             let price = parse_decimal(unit_price)?;
             let disc = discount.as_deref().map(parse_decimal).transpose()?;
-            builder.item(name, *qty, price, disc)
+            match vat_rate.as_deref().map(parse_decimal).transpose()? {
+                Some(rate) => builder.item_vat(name, *qty, price, disc, rate),
+                None => builder.item(name, *qty, price, disc),
+            }
         }
 
+        Element::CompactItems { on } => builder.item_style(if *on {
+            ItemStyle::Compact
+        } else {
+            ItemStyle::Detailed
+        }),
+
         Element::Subtotal { amount } => builder.subtotal_ht(parse_decimal(amount)?),
 
         Element::Tax {
@@ -415,25 +1344,102 @@ fn apply_element(
             builder.taxes(&[entry])
         }
 
+        Element::SubItem { name, price_delta } => {
+            let delta = price_delta.as_deref().map(parse_decimal).transpose()?;
+            builder.sub_item(name, delta)
+        }
+
+        Element::AutoVatSummary => builder.auto_vat_summary(),
+
         Element::Discount {
             amount,
             coupon_code,
         } => builder.discount(parse_decimal(amount)?, coupon_code.as_deref()),
 
         Element::Total { amount } => builder.total(parse_decimal(amount)?),
+        Element::TotalInWords { amount } => builder.total_in_words(parse_decimal(amount)?),
         Element::Received { amount } => builder.received(parse_decimal(amount)?),
         Element::Change { amount } => builder.change(parse_decimal(amount)?),
 
         Element::ServedBy { name } => builder.served_by(name),
         Element::ThankYou { shop_name } => builder.thank_you(shop_name),
+        Element::Meta { rows } => {
+            let pairs: Vec<(&str, &str)> = rows
+                .iter()
+                .map(|[label, value]| (label.as_str(), value.as_str()))
+                .collect();
+            builder.meta(&pairs)
+        }
+        Element::FiscalFooter { fields } => {
+            let pairs: Vec<(&str, &str)> = fields
+                .iter()
+                .map(|[label, value]| (label.as_str(), value.as_str()))
+                .collect();
+            builder.fiscal_footer(&pairs)
+        }
 
+        Element::Barcode {
+            symbology,
+            value,
+            height,
+            width: bar_width,
+            hri,
+        } => {
+            let symbology = parse_symbology(symbology)?;
+            let (position, font) = builder.hri_setting();
+            let builder = match hri {
+                Some(hri) => builder.barcode_hri(parse_hri(hri)?, font),
+                None => builder,
+            };
+            builder
+                .barcode(
+                    symbology,
+                    value,
+                    bar_width.unwrap_or(2),
+                    height.unwrap_or(60),
+                )
+                .map_err(|e| TemplateError::InvalidBarcode(e.to_string()))?
+                .barcode_hri(position, font)
+        }
+        #[allow(deprecated)]
         Element::BarcodeCode128 { value } => builder.barcode_code128(value),
+        #[allow(deprecated)]
         Element::BarcodeEan13 { value } => builder.barcode_ean13(value),
+        Element::LabeledBarcode {
+            label,
+            value,
+            symbology,
+        } => builder
+            .labeled_barcode(label, value, parse_symbology(symbology)?)
+            .map_err(|e| TemplateError::InvalidBarcode(e.to_string()))?,
+        Element::CodeLineFeed { on } => builder.code_line_feed(*on),
+        Element::OrderReference {
+            number,
+            check_digit,
+        } => builder
+            .order_reference(number, *check_digit)
+            .map_err(|e| TemplateError::InvalidBarcode(e.to_string()))?,
         Element::QrCode { data, size } => builder.qr_code(data, *size),
+        Element::QrCaptioned {
+            data,
+            size,
+            caption,
+        } => builder.qr_with_caption(data, *size, caption),
+        Element::PaymentQr {
+            base_url,
+            amount,
+            reference,
+        } => builder.payment_qr(base_url, parse_decimal(amount)?, reference),
+
+        Element::ImagePath { path, dither } => {
+            builder.logo_raw(&load_image(path, width.max_image_px(), dither.as_deref())?)
+        }
 
         Element::Feed { lines } => builder.feed(*lines),
-        Element::Cut => builder.cut(),
-        Element::CutFull => builder.cut_full(),
+        Element::Cut { feed: None } => builder.cut(),
+        Element::Cut { feed: Some(n) } => builder.cut_with_feed(*n),
+        Element::CutFull { feed: None } => builder.cut_full(),
+        Element::CutFull { feed: Some(n) } => builder.cut_full_with_feed(*n),
         Element::FormFeed => builder.form_feed(),
         Element::OpenCashDrawer => builder.open_cash_drawer(),
     };
@@ -459,6 +1465,49 @@ mod tests {
         assert!(!bytes.is_empty());
     }
 
+    #[test]
+    fn render_batch_cuts_between_and_dedupes_init() {
+        let receipt =
+            r#"{ "elements": [{ "type": "init" }, { "type": "text_line", "text": "Ticket" }] }"#;
+        let batch = [receipt; 4];
+        let cuts = |bytes: &[u8]| bytes.windows(2).filter(|w| w == b"\x1DV").count();
+        let inits = |bytes: &[u8]| bytes.windows(2).filter(|w| w == b"\x1B@").count();
+
+        let bytes = render_batch(&batch, true).unwrap();
+        assert_eq!(cuts(&bytes), 3);
+        assert_eq!(inits(&bytes), 2); // the first template's init_with(2) only
+        assert_eq!(bytes.windows(6).filter(|w| w == b"Ticket").count(), 4);
+        assert!(bytes.ends_with(b"Ticket\n"));
+
+        // Styles left on by one receipt are reset before the next
+        let styled = r#"{ "elements": [{ "type": "init" }, { "type": "bold", "on": true },
+            { "type": "align", "value": "center" }, { "type": "text_line", "text": "A" }] }"#;
+        let bytes = render_batch(&[styled, receipt], false).unwrap();
+        let second = &bytes[bytes.windows(2).rposition(|w| w == b"A\n").unwrap() + 2..];
+        assert_eq!(
+            second,
+            b"\x1Bt\x13\x1Ba\x00\x1B!\x00\x1BE\x00\x1B-\x00Ticket\n"
+        );
+
+        // A template for another dialect keeps its own init
+        let star = r#"{ "dialect": "star", "elements": [{ "type": "init" }] }"#;
+        let bytes = render_batch(&[receipt, star], false).unwrap();
+        assert_eq!(inits(&bytes), 4);
+
+        let bytes = render_batch(&batch, false).unwrap();
+        assert_eq!(cuts(&bytes), 0);
+
+        // Later inits are kept when the batch doesn't start with one
+        let plain = r#"{ "elements": [{ "type": "text_line", "text": "Ticket" }] }"#;
+        let bytes = render_batch(&[plain, receipt], false).unwrap();
+        assert_eq!(inits(&bytes), 2);
+
+        // Skipping the init doesn't shift element indexes in errors
+        let bad = r#"{ "elements": [{ "type": "init" }, { "type": "total", "amount": "x" }] }"#;
+        let err = render_batch(&[receipt, bad], false).unwrap_err();
+        assert_eq!(err.element_index(), Some(1));
+    }
+
     #[test]
     fn full_receipt_template() {
         let json = r#"{
@@ -491,6 +1540,434 @@ mod tests {
         assert!(output.contains("TOTAL"));
     }
 
+    #[test]
+    fn to_json_round_trip_is_stable() {
+        let json = r#"{
+            "width": "58mm",
+            "currency": "EUR",
+            "language": "en",
+            "direction": "ltr",
+            "elements": [
+                { "type": "init" },
+                { "type": "shop_header", "name": "MA BOUTIQUE", "phone": "+221 77 000" },
+                { "type": "divider", "char": "=" },
+                { "type": "money_format", "decimals": 2, "thousands_sep": " ", "decimal_sep": "," },
+                { "type": "item", "name": "Polo shirt", "qty": 2, "unit_price": "15.00", "vat_rate": "18" },
+                { "type": "item", "name": "Jean", "qty": 1, "unit_price": "25", "discount": "2" },
+                { "type": "sub_item", "name": "Ourlet", "price_delta": "1.50" },
+                { "type": "subtotal", "amount": "53.50" },
+                { "type": "tax", "label": "TVA 18%", "amount": "9.54", "included": true },
+                { "type": "discount", "amount": "3", "coupon_code": "NOEL" },
+                { "type": "total", "amount": "62.54" },
+                { "type": "meta", "rows": [["Table", "12"]] },
+                { "type": "barcode", "symbology": "code128", "value": "ORD-1", "height": 80 },
+                { "type": "qr_captioned", "data": "https://example.com", "caption": "Scan" },
+                { "type": "underline", "on": false },
+                { "type": "cut", "feed": 3 }
+            ]
+        }"#;
+        let template = ReceiptTemplate::from_json(json).unwrap();
+        let saved = template.to_json().unwrap();
+        let reparsed = ReceiptTemplate::from_json(&saved).unwrap();
+
+        assert_eq!(reparsed.to_json().unwrap(), saved);
+        assert_eq!(reparsed.render().unwrap(), template.render().unwrap());
+        // Tagged back as { "type": ... }, with unset optional fields left out
+        let value: Value = serde_json::from_str(&saved).unwrap();
+        assert_eq!(value["elements"][6]["type"], "sub_item");
+        assert!(value["elements"][4].get("discount").is_none());
+        assert!(value.get("dialect").is_none());
+        assert!(!saved.contains("null"));
+    }
+
+    #[test]
+    fn money_format_element() {
+        let json = r#"{
+            "currency": "EUR",
+            "elements": [
+                { "type": "money_format", "decimals": 2, "thousands_sep": " ", "decimal_sep": "," },
+                { "type": "total", "amount": "1234.56" }
+            ]
+        }"#;
+        let output = String::from_utf8_lossy(&render_json(json).unwrap()).into_owned();
+        assert!(output.contains("1 234,56 EUR"));
+    }
+
+    #[test]
+    fn money_format_rejects_long_separator() {
+        let json = r#"{
+            "elements": [{ "type": "money_format", "decimal_sep": ",," }]
+        }"#;
+        assert!(matches!(
+            render_json(json),
+            Err(TemplateError::ElementError { index: 0, source })
+                if matches!(*source, TemplateError::InvalidSeparator(_))
+        ));
+    }
+
+    #[test]
+    fn fiscal_footer_element() {
+        let json = r#"{
+            "elements": [
+                { "type": "fiscal_footer", "fields": [["NINEA", "005123456 2G3"], ["RCCM", "SN-DKR-2024-B-123"]] }
+            ]
+        }"#;
+        let output = String::from_utf8_lossy(&render_json(json).unwrap()).into_owned();
+        assert!(output.contains("INFORMATIONS LEGALES"));
+        assert!(output.contains("NINEA"));
+        assert!(output.contains("SN-DKR-2024-B-123"));
+    }
+
+    #[test]
+    fn meta_element() {
+        let json = r#"{
+            "width": "58mm",
+            "elements": [
+                { "type": "meta", "rows": [["Commande", "N 1042"], ["Paiement", "Wave"]] }
+            ]
+        }"#;
+        let output = String::from_utf8_lossy(&render_json(json).unwrap()).into_owned();
+        assert!(output.contains(&format!("Commande{}N 1042", " ".repeat(32 - 14))));
+        assert!(output.contains("Wave"));
+    }
+
+    #[test]
+    fn error_codes_identify_variant() {
+        let code = |json: &str| render_json(json).unwrap_err().code();
+        assert_eq!(code("{"), "invalid_json");
+        assert_eq!(
+            code(r#"{ "width": "57mm", "elements": [] }"#),
+            "unknown_width"
+        );
+        assert_eq!(
+            code(r#"{ "language": "de", "elements": [] }"#),
+            "unknown_language"
+        );
+        assert_eq!(
+            code(r#"{ "elements": [{ "type": "total", "amount": "abc" }] }"#),
+            "invalid_decimal"
+        );
+        assert_eq!(
+            code(r#"{ "elements": [{ "type": "align", "value": "middle" }] }"#),
+            "unknown_align"
+        );
+        assert_eq!(
+            code(r#"{ "direction": "up", "elements": [] }"#),
+            "unknown_direction"
+        );
+        assert_eq!(
+            code(r#"{ "dialect": "citizen", "elements": [] }"#),
+            "unknown_dialect"
+        );
+        assert_eq!(
+            code(
+                r#"{ "elements": [{ "type": "order_reference", "number": "A-1", "check_digit": true }] }"#
+            ),
+            "invalid_barcode"
+        );
+    }
+
+    #[cfg(feature = "native")]
+    #[test]
+    fn image_path_rasterises_file() {
+        let path = std::env::temp_dir().join(format!("thermoprint-tpl-{}.png", std::process::id()));
+        ::image::GrayImage::from_pixel(16, 4, ::image::Luma([0]))
+            .save(&path)
+            .unwrap();
+        for dither in ["", r#", "dither": "floyd_steinberg""#] {
+            let json = format!(
+                r#"{{ "elements": [{{ "type": "image_path", "path": {:?}{} }}] }}"#,
+                path.to_str().unwrap(),
+                dither
+            );
+            let bytes = render_json(&json).unwrap();
+            assert!(bytes.starts_with(&[0x1D, b'v', b'0', 0, 2, 0, 4, 0]));
+            assert_eq!(bytes.len(), 8 + 8 + 1);
+        }
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[cfg(feature = "native")]
+    #[test]
+    fn image_path_errors() {
+        let code = |json: &str| render_json(json).unwrap_err().code();
+        assert_eq!(
+            code(r#"{ "elements": [{ "type": "image_path", "path": "/nonexistent/logo.png" }] }"#),
+            "image_load"
+        );
+        assert_eq!(
+            code(
+                r#"{ "elements": [{ "type": "image_path", "path": "logo.png", "dither": "atkinson" }] }"#
+            ),
+            "unknown_dither"
+        );
+    }
+
+    #[cfg(not(feature = "native"))]
+    #[test]
+    fn image_path_needs_native() {
+        let err = render_json(r#"{ "elements": [{ "type": "image_path", "path": "logo.png" }] }"#)
+            .unwrap_err();
+        assert_eq!(err.code(), "image_path_unsupported");
+        assert!(err.to_string().contains("logo.png"));
+    }
+
+    /// The `"type"` tag of an element. No wildcard arm: a new variant
+    /// fails to compile here until it is added to [`json_schema`] too.
+    fn kind(element: &Element) -> &'static str {
+        match element {
+            Element::Init => "init",
+            Element::ShopHeader { .. } => "shop_header",
+            Element::TextLine { .. } => "text_line",
+            Element::Centered { .. } => "centered",
+            Element::Right { .. } => "right",
+            Element::Row { .. } => "row",
+            Element::Divider { .. } => "divider",
+            Element::Section { .. } => "section",
+            Element::ColumnDivider { .. } => "column_divider",
+            Element::Blank => "blank",
+            Element::Bold { .. } => "bold",
+            Element::DoubleSize { .. } => "double_size",
+            Element::DoubleHeight { .. } => "double_height",
+            Element::NormalSize => "normal_size",
+            Element::Underline { .. } => "underline",
+            Element::Align { .. } => "align",
+            Element::MoneyFormat { .. } => "money_format",
+            Element::Item { .. } => "item",
+            Element::CompactItems { .. } => "compact_items",
+            Element::Subtotal { .. } => "subtotal",
+            Element::Tax { .. } => "tax",
+            Element::SubItem { .. } => "sub_item",
+            Element::AutoVatSummary => "auto_vat_summary",
+            Element::Discount { .. } => "discount",
+            Element::Total { .. } => "total",
+            Element::TotalInWords { .. } => "total_in_words",
+            Element::Received { .. } => "received",
+            Element::Change { .. } => "change",
+            Element::ServedBy { .. } => "served_by",
+            Element::ThankYou { .. } => "thank_you",
+            Element::Meta { .. } => "meta",
+            Element::FiscalFooter { .. } => "fiscal_footer",
+            Element::Barcode { .. } => "barcode",
+            #[allow(deprecated)]
+            Element::BarcodeCode128 { .. } => "barcode_code128",
+            #[allow(deprecated)]
+            Element::BarcodeEan13 { .. } => "barcode_ean13",
+            Element::LabeledBarcode { .. } => "labeled_barcode",
+            Element::CodeLineFeed { .. } => "code_line_feed",
+            Element::OrderReference { .. } => "order_reference",
+            Element::QrCode { .. } => "qr_code",
+            Element::QrCaptioned { .. } => "qr_captioned",
+            Element::PaymentQr { .. } => "payment_qr",
+            Element::ImagePath { .. } => "image_path",
+            Element::Feed { .. } => "feed",
+            Element::Cut { .. } => "cut",
+            Element::CutFull { .. } => "cut_full",
+            Element::FormFeed => "form_feed",
+            Element::OpenCashDrawer => "open_cash_drawer",
+        }
+    }
+
+    /// A valid sample value for an element property schema.
+    fn sample(name: &str, prop: &Value) -> Value {
+        if let Some(kind) = prop.get("const") {
+            return kind.clone();
+        }
+        if let Some(values) = prop.get("enum") {
+            return values[0].clone();
+        }
+        let ty = match &prop["type"] {
+            Value::Array(types) => types[0].clone(),
+            ty => ty.clone(),
+        };
+        match ty.as_str().unwrap() {
+            "integer" => json!(1),
+            "boolean" => json!(true),
+            "array" if prop["items"]["type"] == "integer" => json!([1]),
+            "array" => json!([["NINEA", "005123456"]]),
+            _ if prop.get("pattern").is_some() && name == "value" => json!("123456789012"),
+            _ => json!("1"),
+        }
+    }
+
+    #[test]
+    fn schema_covers_every_element() {
+        let schema = json_schema();
+        let entries = schema["properties"]["elements"]["items"]["oneOf"]
+            .as_array()
+            .unwrap();
+        assert_eq!(entries.len(), 47);
+
+        for entry in entries {
+            // Minimal object from the required fields
+            let mut obj = serde_json::Map::new();
+            for field in entry["required"].as_array().unwrap() {
+                let name = field.as_str().unwrap();
+                obj.insert(name.to_owned(), sample(name, &entry["properties"][name]));
+            }
+            let element: Element = serde_json::from_value(Value::Object(obj)).unwrap();
+            assert_eq!(kind(&element), entry["title"]);
+        }
+    }
+
+    #[test]
+    fn schema_round_trips_every_element() {
+        let schema = json_schema();
+        let entries = schema["properties"]["elements"]["items"]["oneOf"]
+            .as_array()
+            .unwrap();
+
+        for entry in entries {
+            let properties = entry["properties"].as_object().unwrap();
+            // Every property the schema allows, optional ones included
+            let full: serde_json::Map<String, Value> = properties
+                .iter()
+                .map(|(name, prop)| (name.clone(), sample(name, prop)))
+                .collect();
+            let element: Element = serde_json::from_value(Value::Object(full.clone()))
+                .unwrap_or_else(|e| panic!("{}: {}", entry["title"], e));
+            let back = serde_json::to_value(&element).unwrap();
+            let back = back.as_object().unwrap();
+
+            // Schema properties the element doesn't have would be dropped
+            for (name, value) in &full {
+                assert_eq!(back.get(name), Some(value), "{}.{}", entry["title"], name);
+            }
+            // Element fields the schema doesn't list would be rejected by
+            // `additionalProperties: false`
+            for name in back.keys() {
+                assert!(
+                    properties.contains_key(name),
+                    "{}.{} missing from the schema",
+                    entry["title"],
+                    name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn schema_lists_top_level_fields() {
+        let schema = json_schema();
+        assert_eq!(schema["required"], json!(["elements"]));
+        assert_eq!(
+            schema["properties"]["dialect"]["enum"],
+            json!(["epson", "star"])
+        );
+        assert_eq!(schema["properties"]["width"]["default"], "80mm");
+    }
+
+    #[test]
+    fn errors_report_element_index() {
+        let json = r#"{
+            "elements": [
+                { "type": "init" },
+                { "type": "item", "name": "A", "qty": 1, "unit_price": "100" },
+                { "type": "item", "name": "B", "qty": 1, "unit_price": "200" },
+                { "type": "divider" },
+                { "type": "subtotal", "amount": "300" },
+                { "type": "total", "amount": "3OO" },
+                { "type": "cut" }
+            ]
+        }"#;
+        let err = render_json(json).unwrap_err();
+        assert_eq!(err.element_index(), Some(5));
+        assert_eq!(err.code(), "invalid_decimal");
+        assert!(err
+            .to_string()
+            .starts_with("Element 5: Invalid decimal amount '3OO'"));
+
+        let err = render_json(r#"{ "width": "57mm", "elements": [] }"#).unwrap_err();
+        assert_eq!(err.element_index(), None);
+    }
+
+    #[test]
+    fn total_in_words_follows_template_language() {
+        let json = r#"{
+            "width": "58mm",
+            "language": "en",
+            "currency": "dollars",
+            "elements": [{ "type": "total_in_words", "amount": "1080" }]
+        }"#;
+        let output = String::from_utf8(render_json(json).unwrap()).unwrap();
+        assert_eq!(output, "One thousand eighty dollars\n");
+    }
+
+    #[test]
+    fn generic_barcode_element() {
+        let bytes = render_json(
+            r#"{ "elements": [
+                { "type": "barcode", "symbology": "ean8", "value": "9638507", "height": 80, "width": 3, "hri": "none" },
+                { "type": "barcode", "symbology": "code128", "value": "ORD-1" }
+            ] }"#,
+        )
+        .unwrap();
+        // EAN-8: 3-dot modules, 80 dots tall, no HRI, then the default restored
+        assert!(bytes.starts_with(&[0x1D, b'w', 3, 0x1D, b'h', 80, 0x1D, b'H', 0]));
+        assert!(bytes.windows(3).any(|w| w == [0x1D, b'k', 3]));
+        assert!(bytes
+            .windows(9)
+            .any(|w| w == [0x1D, b'w', 2, 0x1D, b'h', 60, 0x1D, b'H', 2]));
+
+        let code = |json: &str| render_json(json).unwrap_err().code();
+        assert_eq!(
+            code(r#"{ "elements": [{ "type": "barcode", "symbology": "upc", "value": "1" }] }"#),
+            "unknown_symbology"
+        );
+        assert_eq!(
+            code(
+                r#"{ "elements": [{ "type": "barcode", "symbology": "ean13", "value": "12AB" }] }"#
+            ),
+            "invalid_barcode"
+        );
+        assert_eq!(
+            code(
+                r#"{ "elements": [{ "type": "barcode", "symbology": "code128", "value": "1", "hri": "left" }] }"#
+            ),
+            "unknown_hri"
+        );
+    }
+
+    #[test]
+    fn labeled_barcode_element() {
+        let json = r#"{
+            "elements": [
+                { "type": "labeled_barcode", "label": "RIZ 5KG", "value": "1234567", "symbology": "ean8" }
+            ]
+        }"#;
+        let bytes = render_json(json).unwrap();
+        assert!(String::from_utf8_lossy(&bytes).contains("RIZ 5KG"));
+        assert!(bytes.windows(3).any(|w| w == [0x1D, b'k', 3]));
+
+        let code = |json: &str| render_json(json).unwrap_err().code();
+        assert_eq!(
+            code(
+                r#"{ "elements": [{ "type": "labeled_barcode", "label": "A", "value": "1", "symbology": "upc" }] }"#
+            ),
+            "unknown_symbology"
+        );
+        assert_eq!(
+            code(
+                r#"{ "elements": [{ "type": "labeled_barcode", "label": "A", "value": "1", "symbology": "ean8" }] }"#
+            ),
+            "invalid_barcode"
+        );
+    }
+
+    #[test]
+    fn rtl_direction_mirrors_rows() {
+        let json = r#"{
+            "width": "58mm",
+            "direction": "rtl",
+            "elements": [{ "type": "total", "amount": "500" }]
+        }"#;
+        let bytes = render_json(json).unwrap();
+        assert!(bytes.starts_with(&[0x1B, b'a', 2]));
+        let output = String::from_utf8_lossy(&bytes);
+        assert!(output.contains("500 FCFA"));
+        assert!(output.find("500 FCFA").unwrap() < output.find("TOTAL").unwrap());
+    }
+
     #[test]
     fn english_language_template() {
         let json = r#"{
@@ -514,6 +1991,26 @@ mod tests {
         assert!(!bytes.is_empty());
     }
 
+    #[test]
+    fn render_preview_shows_text_and_placeholders() {
+        let json = r#"{
+            "width": "58mm",
+            "elements": [
+                { "type": "init" },
+                { "type": "centered", "text": "MA BOUTIQUE" },
+                { "type": "qr_code", "data": "https://example.com" },
+                { "type": "cut" }
+            ]
+        }"#;
+        let preview = ReceiptTemplate::from_json(json)
+            .unwrap()
+            .render_preview()
+            .unwrap();
+        assert!(preview.contains("          MA BOUTIQUE\n"));
+        assert!(preview.contains("[QR: https://example.com]"));
+        assert!(preview.contains(" CUT "));
+    }
+
     #[test]
     fn invalid_json_returns_error() {
         let result = render_json("not json");
@@ -562,6 +2059,33 @@ mod tests {
         assert!(!bytes.is_empty());
     }
 
+    #[test]
+    fn qr_captioned_element() {
+        let json = r#"{
+            "elements": [
+                { "type": "qr_captioned", "data": "https://example.com/f/42", "caption": "Scannez pour votre facture" }
+            ]
+        }"#;
+        let bytes = render_json(json).unwrap();
+        let output = String::from_utf8_lossy(&bytes);
+        assert!(bytes.starts_with(&[0x1B, b'a', 1]));
+        assert!(output.contains("Scannez pour votre facture\n"));
+        assert!(bytes.ends_with(&[0x1B, b'a', 0]));
+    }
+
+    #[test]
+    fn payment_qr_element() {
+        let json = r#"{
+            "language": "en",
+            "elements": [
+                { "type": "payment_qr", "base_url": "https://pay.example/m/7", "amount": "1500", "reference": "Table 4" }
+            ]
+        }"#;
+        let output = String::from_utf8_lossy(&render_json(json).unwrap()).into_owned();
+        assert!(output.contains("https://pay.example/m/7?amount=1500&reference=Table%204"));
+        assert!(output.contains("Pay with your phone\n"));
+    }
+
     #[test]
     fn style_elements() {
         let json = r#"{
@@ -581,4 +2105,75 @@ mod tests {
         let bytes = render_json(json).unwrap();
         assert!(!bytes.is_empty());
     }
+
+    #[test]
+    fn vat_rate_items_feed_auto_summary() {
+        let json = r#"{
+            "elements": [
+                { "type": "item", "name": "Polo", "qty": 2, "unit_price": "10000", "vat_rate": "18" },
+                { "type": "item", "name": "Pain", "qty": 1, "unit_price": "500" },
+                { "type": "auto_vat_summary" }
+            ]
+        }"#;
+        let bytes = render_json(json).unwrap();
+        let output = String::from_utf8_lossy(&bytes);
+        assert!(output.contains("TVA 18%"));
+        assert!(output.contains("3600 FCFA"));
+        assert!(output.contains("20000 FCFA"));
+    }
+
+    #[test]
+    fn cut_elements_accept_feed() {
+        let json = r#"{
+            "elements": [
+                { "type": "cut" },
+                { "type": "cut", "feed": 48 },
+                { "type": "cut_full", "feed": 24 }
+            ]
+        }"#;
+        let bytes = render_json(json).unwrap();
+        assert_eq!(
+            bytes,
+            vec![0x1D, b'V', 66, 0, 0x1D, b'V', 66, 48, 0x1D, b'V', 65, 24]
+        );
+    }
+
+    #[test]
+    fn cut_elements_keep_their_pre_feed_json_form() {
+        // Templates stored before `feed` existed still parse and round-trip
+        let json =
+            r#"{"elements":[{"type":"cut"},{"type":"cut_full"},{"type":"cut","feed":null}]}"#;
+        let template: ReceiptTemplate = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            template.elements[..],
+            [
+                Element::Cut { feed: None },
+                Element::CutFull { feed: None },
+                Element::Cut { feed: None }
+            ]
+        ));
+        let out: serde_json::Value = serde_json::from_str(&template.to_json().unwrap()).unwrap();
+        assert_eq!(out["elements"][0], serde_json::json!({ "type": "cut" }));
+        assert_eq!(
+            out["elements"][1],
+            serde_json::json!({ "type": "cut_full" })
+        );
+    }
+
+    #[test]
+    fn sub_item_element() {
+        let json = r#"{
+            "width": "58mm",
+            "elements": [
+                { "type": "item", "name": "Burger", "qty": 1, "unit_price": "3000" },
+                { "type": "sub_item", "name": "Extra cheese", "price_delta": "500" },
+                { "type": "sub_item", "name": "Sans oignons" }
+            ]
+        }"#;
+        let bytes = render_json(json).unwrap();
+        let output = String::from_utf8_lossy(&bytes);
+        assert!(output.contains("  + Extra cheese"));
+        assert!(output.contains("+500 FCFA\n"));
+        assert!(output.contains("  + Sans oignons\n"));
+    }
 }
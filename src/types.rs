@@ -1,4 +1,6 @@
 use rust_decimal::Decimal;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::*;
@@ -6,14 +8,19 @@ use wasm_bindgen::prelude::*;
 /// Supported paper widths.
 ///
 /// Each variant carries the printable character width used for layout math.
+/// Serialized as `"58mm"`, `"80mm"` or `"a4"`, like template `width`s.
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PrintWidth {
     /// 58 mm thermal roll — 32 characters at standard font
+    #[cfg_attr(feature = "serde", serde(rename = "58mm", alias = "58"))]
     Mm58,
     /// 80 mm thermal roll — 48 characters at standard font
+    #[cfg_attr(feature = "serde", serde(rename = "80mm", alias = "80"))]
     Mm80,
     /// A4 / generic wide-carriage — 90 characters
+    #[cfg_attr(feature = "serde", serde(rename = "a4", alias = "A4"))]
     A4,
 }
 
@@ -33,7 +40,6 @@ impl PrintWidth {
     }
 
     /// Maximum raster image width in pixels for logo printing.
-    #[cfg(feature = "native")]
     pub fn max_image_px(self) -> u32 {
         match self {
             PrintWidth::Mm58 => 256,
@@ -41,10 +47,71 @@ impl PrintWidth {
             PrintWidth::A4 => 576,
         }
     }
+
+    /// Raster mode [`ReceiptBuilder::logo`](crate::ReceiptBuilder::logo)
+    /// uses unless overridden with
+    /// [`raster_strategy`](crate::ReceiptBuilder::raster_strategy):
+    /// [`RasterMode::EscStarSingle`] on 58mm, whose cheap print heads smear
+    /// dense `GS v 0` logos, and [`RasterMode::GsV0`] otherwise.
+    pub fn default_raster_mode(self) -> RasterMode {
+        match self {
+            PrintWidth::Mm58 => RasterMode::EscStarSingle,
+            PrintWidth::Mm80 | PrintWidth::A4 => RasterMode::GsV0,
+        }
+    }
+
+    /// Whether `feature` makes sense on this paper.
+    ///
+    /// Thermal rolls cut, kick a cash drawer and print raster logos and
+    /// barcodes. A4 / page printers get text with the usual ESC/POS reset,
+    /// code page, alignment, style and line-feed commands, which ESC/POS
+    /// page printers understand, but no paper-handling or graphics
+    /// commands; the builder substitutes safe equivalents there: cuts become a form feed, 1D
+    /// barcodes print their human-readable value as a text line, and QR /
+    /// Data Matrix codes, logos and drawer kicks are skipped and listed by
+    /// [`ReceiptBuilder::skipped`](crate::ReceiptBuilder::skipped).
+    /// [`form_feed`](crate::ReceiptBuilder::form_feed) is sent on every
+    /// width.
+    ///
+    /// ```rust
+    /// use thermoprint::{Feature, PrintWidth};
+    /// assert!(PrintWidth::Mm80.supports(Feature::Cut));
+    /// assert!(!PrintWidth::A4.supports(Feature::Cut));
+    /// assert!(PrintWidth::A4.supports(Feature::FormFeed));
+    /// assert!(!PrintWidth::A4.supports(Feature::Barcode));
+    /// ```
+    pub fn supports(self, feature: Feature) -> bool {
+        match feature {
+            Feature::Cut | Feature::CashDrawer | Feature::Logo | Feature::Barcode => {
+                self.is_thermal()
+            }
+            Feature::FormFeed => !self.is_thermal(),
+        }
+    }
 }
 
-/// Text alignment.
+/// Printer capabilities that depend on the paper width; see
+/// [`PrintWidth::supports`].
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feature {
+    /// Paper cut (`GS V`) — thermal rolls only; a form feed on A4
+    Cut,
+    /// Cash drawer kick (`ESC p`) — thermal receipt printers only
+    CashDrawer,
+    /// Raster and NV logos — thermal rolls only
+    Logo,
+    /// Barcodes, QR and Data Matrix codes — thermal rolls only; 1D barcodes
+    /// fall back to their human-readable value on A4
+    Barcode,
+    /// Page eject (`FF`) — A4 / page printers
+    FormFeed,
+}
+
+/// Text alignment. Serialized in lowercase (`"left"`, `"center"`, `"right"`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum Align {
     /// Left-align (default)
     Left,
@@ -54,8 +121,205 @@ pub enum Align {
     Right,
 }
 
+/// Reading direction of the receipt layout.
+///
+/// With [`Rtl`](Direction::Rtl), two-column rows put the value on the left
+/// and the label on the right, and default text alignment starts at the
+/// right edge. Text is still sent in logical order — use a printer code page
+/// that shapes right-to-left scripts (e.g. CP864) for Arabic text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Direction {
+    /// Left-to-right (default)
+    #[default]
+    Ltr,
+    /// Right-to-left
+    Rtl,
+}
+
+/// Which lines print the currency symbol; see
+/// [`ReceiptBuilder::currency_on`](crate::ReceiptBuilder::currency_on).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CurrencyPlacement {
+    /// Every amount carries the symbol (default)
+    #[default]
+    Always,
+    /// Item lines show bare numbers; subtotal, discount, tax, total and
+    /// payment lines keep the symbol, as do items in another currency
+    TotalsOnly,
+}
+
+/// Underline thickness; see
+/// [`ReceiptBuilder::underline`](crate::ReceiptBuilder::underline).
+///
+/// `true` and `false` convert to [`Single`](Self::Single) and
+/// [`None`](Self::None).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnderlineStyle {
+    /// No underline (`ESC - 0`, default)
+    #[default]
+    None,
+    /// 1-dot underline (`ESC - 1`)
+    Single,
+    /// 2-dot underline (`ESC - 2`), for headings
+    Double,
+}
+
+impl From<bool> for UnderlineStyle {
+    fn from(on: bool) -> Self {
+        if on {
+            UnderlineStyle::Single
+        } else {
+            UnderlineStyle::None
+        }
+    }
+}
+
+/// Bytes that end each printed line; see
+/// [`ReceiptBuilder::line_ending`](crate::ReceiptBuilder::line_ending).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineEnding {
+    /// `LF` only — what thermal printers expect (default)
+    #[default]
+    Lf,
+    /// `CR LF`, for impact and page printers that don't return the
+    /// carriage on a line feed
+    CrLf,
+    /// `CR` only, for printers that feed a line on carriage return
+    Cr,
+}
+
+impl LineEnding {
+    /// The byte sequence sent for one line ending.
+    pub fn bytes(self) -> &'static [u8] {
+        match self {
+            LineEnding::Lf => &[crate::commands::LF],
+            LineEnding::CrLf => &[crate::commands::CR, crate::commands::LF],
+            LineEnding::Cr => &[crate::commands::CR],
+        }
+    }
+}
+
+/// How [`ReceiptBuilder::item`](crate::ReceiptBuilder::item) and the other
+/// item methods lay out a line item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ItemStyle {
+    /// Name, `qty x price`, total and a blank line (default)
+    #[default]
+    Detailed,
+    /// One line: name on the left, `2x15000=30000` on the right
+    Compact,
+}
+
+/// Where a barcode's human-readable interpretation (HRI) text is printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HriPosition {
+    /// No HRI text.
+    None,
+    /// Above the barcode.
+    Above,
+    /// Below the barcode (default).
+    #[default]
+    Below,
+    /// Above and below the barcode.
+    Both,
+}
+
+impl HriPosition {
+    /// `GS H n` parameter for this position.
+    pub(crate) fn code(self) -> u8 {
+        match self {
+            HriPosition::None => 0,
+            HriPosition::Above => 1,
+            HriPosition::Below => 2,
+            HriPosition::Both => 3,
+        }
+    }
+}
+
+/// Font used for barcode HRI text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HriFont {
+    /// Font A, 12×24 (default).
+    #[default]
+    A,
+    /// Font B, 9×17 — narrower, fits long values.
+    B,
+}
+
+impl HriFont {
+    /// `GS f n` parameter for this font.
+    pub(crate) fn code(self) -> u8 {
+        match self {
+            HriFont::A => 0,
+            HriFont::B => 1,
+        }
+    }
+}
+
+/// QR code error correction level: the share of the symbol that can be
+/// damaged and still scan. Higher levels make larger symbols.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QrErrorCorrection {
+    /// ~7% recovery.
+    L,
+    /// ~15% recovery (default).
+    #[default]
+    M,
+    /// ~25% recovery.
+    Q,
+    /// ~30% recovery.
+    H,
+}
+
+impl QrErrorCorrection {
+    /// Level index, 0 (L) to 3 (H).
+    pub(crate) fn index(self) -> u8 {
+        match self {
+            QrErrorCorrection::L => 0,
+            QrErrorCorrection::M => 1,
+            QrErrorCorrection::Q => 2,
+            QrErrorCorrection::H => 3,
+        }
+    }
+}
+
+/// Bit-image command family used to send logos to the printer.
+///
+/// More modes may be added, so matches need a `_` arm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum RasterMode {
+    /// `GS v 0` raster bit image (default, supported by most printers).
+    #[default]
+    GsV0,
+    /// `ESC *` 24-dot column bit image, for older printers without `GS v 0`.
+    EscStar,
+    /// `ESC *` 24-dot column bit image at single horizontal density: every
+    /// column is two dots wide, so images are rasterised at half the width
+    /// to keep their printed size. Burns less ink per column, which keeps
+    /// logos from printing too dark and smeared on cheap 58mm printers.
+    EscStarSingle,
+}
+
+impl RasterMode {
+    /// Printed dots per image column: 2 for
+    /// [`EscStarSingle`](Self::EscStarSingle), 1 otherwise. Divide the
+    /// printable width by this to get the image width in pixels.
+    pub fn dot_width(self) -> u32 {
+        match self {
+            RasterMode::GsV0 | RasterMode::EscStar => 1,
+            RasterMode::EscStarSingle => 2,
+        }
+    }
+}
+
 /// A single tax line attached to a receipt.
+///
+/// Deserializes from order data such as
+/// `{"label": "TVA 18%", "amount": "2700", "included": false}`; `amount`
+/// may also be a JSON number and `included` defaults to `false`.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TaxEntry {
     /// Display label, e.g. `"TVA 18%"` or `"Taxe Municipale 2%"`
     pub label: String,
@@ -63,6 +327,7 @@ pub struct TaxEntry {
     pub amount: Decimal,
     /// `true`  → tax is already included in the item prices (shown for info only)
     /// `false` → tax is added on top of the subtotal
+    #[cfg_attr(feature = "serde", serde(default))]
     pub included: bool,
 }
 
@@ -76,3 +341,238 @@ impl TaxEntry {
         }
     }
 }
+
+/// How money amounts are rendered on the receipt.
+///
+/// The default matches the historical output: amounts rounded to whole
+/// units, no grouping, symbol after the amount (`"15000 FCFA"`).
+///
+/// ```rust
+/// use thermoprint::MoneyFormat;
+/// use rust_decimal::prelude::*;
+///
+/// let eur = MoneyFormat::new(2, Some(' '), ',', false);
+/// assert_eq!(eur.format(dec!(1149.99), "€"), "1 149,99 €");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MoneyFormat {
+    /// Digits kept after the decimal separator (amounts are rounded to this)
+    pub decimals: u32,
+    /// Separator inserted every three integer digits, if any
+    pub thousands_sep: Option<char>,
+    /// Separator between the integer and fractional parts
+    pub decimal_sep: char,
+    /// `true` → `"$12.50"`, `false` → `"12.50 $"`
+    pub symbol_before: bool,
+}
+
+impl Default for MoneyFormat {
+    fn default() -> Self {
+        Self {
+            decimals: 0,
+            thousands_sep: None,
+            decimal_sep: '.',
+            symbol_before: false,
+        }
+    }
+}
+
+impl MoneyFormat {
+    /// Convenience constructor.
+    pub fn new(
+        decimals: u32,
+        thousands_sep: Option<char>,
+        decimal_sep: char,
+        symbol_before: bool,
+    ) -> Self {
+        Self {
+            decimals,
+            thousands_sep,
+            decimal_sep,
+            symbol_before,
+        }
+    }
+
+    /// Render `amount` with the currency `symbol`.
+    pub fn format(&self, amount: Decimal, symbol: &str) -> String {
+        let mut rounded = amount.round_dp(self.decimals);
+        rounded.rescale(self.decimals);
+        let digits = rounded.abs().to_string();
+        let (int_part, frac_part) = match digits.split_once('.') {
+            Some((i, f)) => (i, Some(f)),
+            None => (digits.as_str(), None),
+        };
+
+        let mut number = String::new();
+        if rounded.is_sign_negative() && !rounded.is_zero() {
+            number.push('-');
+        }
+        for (i, c) in int_part.chars().enumerate() {
+            if i > 0 && (int_part.len() - i) % 3 == 0 {
+                if let Some(sep) = self.thousands_sep {
+                    number.push(sep);
+                }
+            }
+            number.push(c);
+        }
+        if let Some(frac) = frac_part {
+            number.push(self.decimal_sep);
+            number.push_str(frac);
+        }
+
+        if self.symbol_before {
+            match number.strip_prefix('-') {
+                Some(abs) => format!("-{}{}", symbol, abs),
+                None => format!("{}{}", symbol, number),
+            }
+        } else {
+            format!("{} {}", number, symbol)
+        }
+    }
+}
+
+/// A line item held in memory before rendering, so it can be pre-processed
+/// (e.g. with [`merge_items`]) and then printed via
+/// [`ReceiptBuilder::items`](crate::ReceiptBuilder::items).
+///
+/// Deserializes from JSON such as
+/// `{"name": "Polo", "qty": 2, "unit_price": "15000", "discount": "500"}`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+pub struct ItemInput {
+    /// Display name of the item
+    pub name: String,
+    /// Quantity sold
+    pub qty: i32,
+    /// Price of one unit
+    pub unit_price: Decimal,
+    /// Optional discount on the line total
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub discount: Option<Decimal>,
+    /// Optional VAT rate in percent (e.g. `18`), accumulated for
+    /// [`ReceiptBuilder::auto_vat_summary`](crate::ReceiptBuilder::auto_vat_summary)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub vat_rate: Option<Decimal>,
+}
+
+impl ItemInput {
+    /// Convenience constructor.
+    pub fn new(
+        name: impl Into<String>,
+        qty: i32,
+        unit_price: Decimal,
+        discount: Option<Decimal>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            qty,
+            unit_price,
+            discount,
+            vat_rate: None,
+        }
+    }
+
+    /// Set the VAT rate (percent) of this item.
+    pub fn with_vat(mut self, rate: Decimal) -> Self {
+        self.vat_rate = Some(rate);
+        self
+    }
+}
+
+/// Collapse items with the same name, unit price, discount and VAT rate into
+/// a single line with the summed quantity (and summed discount).
+///
+/// Items whose discounts differ are kept on separate lines. The order of
+/// first appearance is preserved.
+pub fn merge_items(items: &[ItemInput]) -> Vec<ItemInput> {
+    let mut merged: Vec<ItemInput> = Vec::with_capacity(items.len());
+    // Per-unit discount of each merged line, kept apart because the merged
+    // line's own discount grows as duplicates are folded in.
+    let mut keys: Vec<Option<Decimal>> = Vec::with_capacity(items.len());
+    for item in items {
+        let found = merged.iter().zip(&keys).position(|(m, k)| {
+            m.name == item.name
+                && m.unit_price == item.unit_price
+                && m.vat_rate == item.vat_rate
+                && *k == item.discount
+        });
+        match found {
+            Some(i) => {
+                let m = &mut merged[i];
+                m.qty += item.qty;
+                m.discount = m.discount.zip(item.discount).map(|(a, b)| a + b);
+            }
+            None => {
+                merged.push(item.clone());
+                keys.push(item.discount);
+            }
+        }
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::*;
+
+    #[test]
+    fn default_money_format_rounds_to_units() {
+        let f = MoneyFormat::default();
+        assert_eq!(f.format(Decimal::from(15000), "FCFA"), "15000 FCFA");
+        assert_eq!(f.format(dec!(149.99), "FCFA"), "150 FCFA");
+    }
+
+    #[test]
+    fn money_format_keeps_cents() {
+        let f = MoneyFormat::new(2, None, ',', false);
+        assert_eq!(f.format(dec!(149.99), "€"), "149,99 €");
+        assert_eq!(f.format(dec!(5), "€"), "5,00 €");
+    }
+
+    #[test]
+    fn money_format_groups_and_prefixes() {
+        let f = MoneyFormat::new(2, Some(','), '.', true);
+        assert_eq!(f.format(dec!(1234567.5), "$"), "$1,234,567.50");
+        assert_eq!(f.format(dec!(-12.5), "$"), "-$12.50");
+        assert_eq!(f.format(dec!(999), "$"), "$999.00");
+    }
+
+    #[test]
+    fn merges_identical_items() {
+        let items = [
+            ItemInput::new("Coca", 1, Decimal::from(500), None),
+            ItemInput::new("Pain", 2, Decimal::from(150), None),
+            ItemInput::new("Coca", 2, Decimal::from(500), None),
+        ];
+        let merged = merge_items(&items);
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0].name, "Coca");
+        assert_eq!(merged[0].qty, 3);
+        assert_eq!(merged[1].name, "Pain");
+    }
+
+    #[test]
+    fn does_not_merge_different_price_or_discount() {
+        let items = [
+            ItemInput::new("Coca", 1, Decimal::from(500), None),
+            ItemInput::new("Coca", 1, Decimal::from(600), None),
+            ItemInput::new("Coca", 1, Decimal::from(500), Some(Decimal::from(50))),
+        ];
+        assert_eq!(merge_items(&items).len(), 3);
+    }
+
+    #[test]
+    fn equal_discounts_are_summed() {
+        let disc = Some(Decimal::from(50));
+        let items = [
+            ItemInput::new("Coca", 1, Decimal::from(500), disc),
+            ItemInput::new("Coca", 1, Decimal::from(500), disc),
+            ItemInput::new("Coca", 1, Decimal::from(500), disc),
+        ];
+        let merged = merge_items(&items);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].qty, 3);
+        assert_eq!(merged[0].discount, Some(Decimal::from(150)));
+    }
+}
@@ -0,0 +1,363 @@
+//! Amounts written out in words, as printed on formal invoices
+//! ("Arrêtée la présente facture à la somme de ...").
+//!
+//! French follows the traditional spelling: hyphens only below one hundred,
+//! "et" for 21–71 (`vingt et un`, `soixante et onze`), and the plural `s`
+//! of `vingts` / `cents` only when nothing follows them — except before
+//! `millions` and `milliards`, which are nouns (`quatre-vingts millions`,
+//! but `quatre-vingt mille`). English uses US cheque style without "and".
+//!
+//! Languages without a converter yet fall back to French.
+
+use rust_decimal::prelude::*;
+
+use crate::i18n::Language;
+
+const FR_UNITS: [&str; 17] = [
+    "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf", "dix", "onze",
+    "douze", "treize", "quatorze", "quinze", "seize",
+];
+
+const FR_TENS: [&str; 5] = ["vingt", "trente", "quarante", "cinquante", "soixante"];
+
+/// Powers of a thousand above `mille`, with their singular names.
+const FR_SCALES: [(u64, &str); 5] = [
+    (1_000_000_000_000_000_000, "trillion"),
+    (1_000_000_000_000_000, "billiard"),
+    (1_000_000_000_000, "billion"),
+    (1_000_000_000, "milliard"),
+    (1_000_000, "million"),
+];
+
+const EN_UNITS: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const EN_TENS: [&str; 8] = [
+    "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+const EN_SCALES: [(u64, &str); 6] = [
+    (1_000_000_000_000_000_000, "quintillion"),
+    (1_000_000_000_000_000, "quadrillion"),
+    (1_000_000_000_000, "trillion"),
+    (1_000_000_000, "billion"),
+    (1_000_000, "million"),
+    (1_000, "thousand"),
+];
+
+/// Write `n` out in words.
+///
+/// ```rust
+/// use thermoprint::{words::number_in_words, Language};
+/// assert_eq!(number_in_words(62_540, Language::Fr), "soixante-deux mille cinq cent quarante");
+/// assert_eq!(number_in_words(80, Language::Fr), "quatre-vingts");
+/// assert_eq!(number_in_words(82, Language::Fr), "quatre-vingt-deux");
+/// assert_eq!(number_in_words(62_540, Language::En), "sixty-two thousand five hundred forty");
+/// ```
+pub fn number_in_words(n: u64, lang: Language) -> String {
+    match lang {
+        Language::En => en_number(n),
+        _ => fr_number(n),
+    }
+}
+
+/// Fraction digits [`amount_in_words`] can spell out: `10^19` still fits a
+/// `u64`.
+const MAX_FRACTION_DIGITS: u32 = 19;
+
+/// Write a money amount out in words: the integer part, then `currency`,
+/// then any fraction (rounded to `decimals` digits) as hundredths, e.g.
+/// `"douze EUR et cinq centimes"` for `12.05`. Negative amounts start with
+/// "moins" / "minus".
+///
+/// Amounts beyond `u64::MAX` are printed in digits. `decimals` above 19
+/// (the most digits a `u64` fraction holds) are treated as 19.
+///
+/// ```rust
+/// use rust_decimal::Decimal;
+/// use thermoprint::{words::amount_in_words, Language};
+/// assert_eq!(
+///     amount_in_words(Decimal::from(62_540), 0, "francs", Language::Fr),
+///     "soixante-deux mille cinq cent quarante francs"
+/// );
+/// ```
+pub fn amount_in_words(amount: Decimal, decimals: u32, currency: &str, lang: Language) -> String {
+    let decimals = decimals.min(MAX_FRACTION_DIGITS);
+    let rounded = amount.abs().round_dp(decimals);
+    let Some(units) = rounded.trunc().to_u64() else {
+        return format!("{} {}", amount.round_dp(decimals), currency)
+            .trim_end()
+            .to_owned();
+    };
+    let fraction = (rounded.fract() * Decimal::from(10u64.pow(decimals)))
+        .to_u64()
+        .unwrap_or(0);
+
+    let (minus, and, cents) = match lang {
+        Language::En => ("minus", "and", "cents"),
+        _ => ("moins", "et", "centimes"),
+    };
+    let mut parts = Vec::new();
+    if amount.is_sign_negative() && !rounded.is_zero() {
+        parts.push(minus.to_owned());
+    }
+    parts.push(number_in_words(units, lang));
+    if !currency.is_empty() {
+        parts.push(currency.to_owned());
+    }
+    if fraction > 0 {
+        parts.push(and.to_owned());
+        parts.push(number_in_words(fraction, lang));
+        if decimals == 2 {
+            parts.push(cents.to_owned());
+        }
+    }
+    parts.join(" ")
+}
+
+// ── French ────────────────────────────────────────────────────────────────────
+
+fn fr_number(n: u64) -> String {
+    if n == 0 {
+        return FR_UNITS[0].to_owned();
+    }
+    let mut parts = Vec::new();
+    let mut rest = n;
+    for (scale, name) in FR_SCALES {
+        let count = rest / scale;
+        rest %= scale;
+        if count > 0 {
+            // Scale names are nouns: `vingts` / `cents` before them keep the s
+            let s = if count > 1 { "s" } else { "" };
+            parts.push(format!("{} {}{}", fr_number(count), name, s));
+        }
+    }
+    let thousands = rest / 1000;
+    rest %= 1000;
+    match thousands {
+        0 => {}
+        1 => parts.push("mille".to_owned()),
+        // `mille` is an adjective: `deux cent mille`, `quatre-vingt mille`
+        _ => parts.push(format!("{} mille", fr_below_1000(thousands, false))),
+    }
+    if rest > 0 {
+        parts.push(fr_below_1000(rest, true));
+    }
+    parts.join(" ")
+}
+
+/// `n` in 1..1000. `plural` allows the final `s` of `cents` / `vingts`.
+fn fr_below_1000(n: u64, plural: bool) -> String {
+    let (hundreds, rest) = (n / 100, n % 100);
+    let mut out = match hundreds {
+        0 => String::new(),
+        1 => "cent".to_owned(),
+        h if rest == 0 && plural => format!("{} cents", FR_UNITS[h as usize]),
+        h => format!("{} cent", FR_UNITS[h as usize]),
+    };
+    if rest > 0 {
+        if !out.is_empty() {
+            out.push(' ');
+        }
+        out.push_str(&fr_below_100(rest, plural));
+    }
+    out
+}
+
+/// `n` in 1..100.
+fn fr_below_100(n: u64, plural: bool) -> String {
+    let (tens, units) = (n / 10, n % 10);
+    match tens {
+        _ if n <= 16 => FR_UNITS[n as usize].to_owned(),
+        1 => format!("dix-{}", FR_UNITS[units as usize]),
+        2..=6 => {
+            let base = FR_TENS[tens as usize - 2];
+            match units {
+                0 => base.to_owned(),
+                1 => format!("{} et un", base),
+                u => format!("{}-{}", base, FR_UNITS[u as usize]),
+            }
+        }
+        // 70–79: soixante-dix, soixante et onze, soixante-douze, ...
+        7 if units == 1 => "soixante et onze".to_owned(),
+        7 => format!("soixante-{}", fr_below_100(10 + units, plural)),
+        // 80–99: quatre-vingts, quatre-vingt-un, ..., quatre-vingt-dix-neuf
+        8 if units == 0 && plural => "quatre-vingts".to_owned(),
+        8 if units == 0 => "quatre-vingt".to_owned(),
+        8 => format!("quatre-vingt-{}", FR_UNITS[units as usize]),
+        _ => format!("quatre-vingt-{}", fr_below_100(10 + units, plural)),
+    }
+}
+
+// ── English ───────────────────────────────────────────────────────────────────
+
+fn en_number(n: u64) -> String {
+    if n == 0 {
+        return EN_UNITS[0].to_owned();
+    }
+    let mut parts = Vec::new();
+    let mut rest = n;
+    for (scale, name) in EN_SCALES {
+        let count = rest / scale;
+        rest %= scale;
+        if count > 0 {
+            parts.push(format!("{} {}", en_below_1000(count), name));
+        }
+    }
+    if rest > 0 {
+        parts.push(en_below_1000(rest));
+    }
+    parts.join(" ")
+}
+
+/// `n` in 1..1000.
+fn en_below_1000(n: u64) -> String {
+    let (hundreds, rest) = (n / 100, n % 100);
+    let mut parts = Vec::new();
+    if hundreds > 0 {
+        parts.push(format!("{} hundred", EN_UNITS[hundreds as usize]));
+    }
+    match rest {
+        0 => {}
+        1..=19 => parts.push(EN_UNITS[rest as usize].to_owned()),
+        _ => {
+            let tens = EN_TENS[rest as usize / 10 - 2];
+            parts.push(match rest % 10 {
+                0 => tens.to_owned(),
+                u => format!("{}-{}", tens, EN_UNITS[u as usize]),
+            });
+        }
+    }
+    parts.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fr(n: u64) -> String {
+        number_in_words(n, Language::Fr)
+    }
+
+    #[test]
+    fn french_below_one_hundred() {
+        let cases = [
+            (0, "zéro"),
+            (1, "un"),
+            (16, "seize"),
+            (17, "dix-sept"),
+            (21, "vingt et un"),
+            (22, "vingt-deux"),
+            (61, "soixante et un"),
+            (70, "soixante-dix"),
+            (71, "soixante et onze"),
+            (72, "soixante-douze"),
+            (77, "soixante-dix-sept"),
+            (80, "quatre-vingts"),
+            (81, "quatre-vingt-un"),
+            (82, "quatre-vingt-deux"),
+            (90, "quatre-vingt-dix"),
+            (91, "quatre-vingt-onze"),
+            (99, "quatre-vingt-dix-neuf"),
+        ];
+        for (n, words) in cases {
+            assert_eq!(fr(n), words, "{}", n);
+        }
+    }
+
+    #[test]
+    fn french_hundreds_and_thousands() {
+        let cases = [
+            (100, "cent"),
+            (101, "cent un"),
+            (200, "deux cents"),
+            (201, "deux cent un"),
+            (280, "deux cent quatre-vingts"),
+            (1000, "mille"),
+            (1001, "mille un"),
+            (2000, "deux mille"),
+            (21_000, "vingt et un mille"),
+            (80_000, "quatre-vingt mille"),
+            (200_000, "deux cent mille"),
+            (62_540, "soixante-deux mille cinq cent quarante"),
+        ];
+        for (n, words) in cases {
+            assert_eq!(fr(n), words, "{}", n);
+        }
+    }
+
+    #[test]
+    fn french_millions_are_nouns() {
+        assert_eq!(fr(1_000_000), "un million");
+        assert_eq!(fr(2_000_000), "deux millions");
+        assert_eq!(fr(80_000_000), "quatre-vingts millions");
+        assert_eq!(fr(200_000_000), "deux cents millions");
+        assert_eq!(
+            fr(1_250_300),
+            "un million deux cent cinquante mille trois cents"
+        );
+        assert_eq!(fr(3_000_000_001), "trois milliards un");
+    }
+
+    #[test]
+    fn english_numbers() {
+        let en = |n| number_in_words(n, Language::En);
+        assert_eq!(en(0), "zero");
+        assert_eq!(en(15), "fifteen");
+        assert_eq!(en(40), "forty");
+        assert_eq!(en(99), "ninety-nine");
+        assert_eq!(en(100), "one hundred");
+        assert_eq!(en(1_000_001), "one million one");
+        assert_eq!(en(u64::MAX).split(' ').next(), Some("eighteen"));
+    }
+
+    #[test]
+    fn amounts_with_fraction_and_sign() {
+        assert_eq!(
+            amount_in_words(dec!(12.05), 2, "EUR", Language::Fr),
+            "douze EUR et cinq centimes"
+        );
+        assert_eq!(
+            amount_in_words(dec!(-3.5), 2, "USD", Language::En),
+            "minus three USD and fifty cents"
+        );
+        // Rounded to the money format first
+        assert_eq!(
+            amount_in_words(dec!(99.6), 0, "FCFA", Language::Fr),
+            "cent FCFA"
+        );
+        assert_eq!(amount_in_words(dec!(1), 0, "", Language::Wo), "un");
+    }
+
+    #[test]
+    fn fraction_digits_are_capped() {
+        assert_eq!(
+            amount_in_words(dec!(1.5), 28, "", Language::En),
+            amount_in_words(dec!(1.5), 19, "", Language::En)
+        );
+        assert_eq!(
+            amount_in_words(dec!(0.05), u32::MAX, "", Language::En),
+            "zero and five hundred quadrillion"
+        );
+    }
+}
@@ -0,0 +1,16 @@
+const COMMANDS: &[&str] = &[
+    "list_ports",
+    "print_serial",
+    "print_template",
+    "print_test_page",
+    "reset_printer",
+    "print_bluetooth",
+    "list_system_printers",
+    "print_system",
+    "print_serial_job",
+    "cancel_job",
+];
+
+fn main() {
+    tauri_plugin::Builder::new(COMMANDS).build();
+}
@@ -0,0 +1,102 @@
+//! Bluetooth RFCOMM helpers.
+//!
+//! Bluetooth thermal printers expose the Serial Port Profile (SPP). Once the
+//! link is bound to a serial device, printing is plain serial I/O:
+//!
+//! - **Linux**: the link must be bound to `/dev/rfcommN`. [`bind_rfcomm`]
+//!   does this through BlueZ's `rfcomm` tool so apps don't have to.
+//! - **macOS / Windows**: paired SPP printers already appear as serial ports
+//!   (`/dev/cu.<Name>-SerialPort`, `COMn`); [`find_port_by_name`] looks them
+//!   up by device name.
+
+#[cfg(target_os = "linux")]
+use std::{path::Path, process::Command};
+
+use crate::error::PrintError;
+
+/// Highest `/dev/rfcommN` index tried when looking for a free device.
+#[cfg(target_os = "linux")]
+const MAX_RFCOMM_DEVICES: u8 = 32;
+
+/// Whether `s` looks like a Bluetooth MAC address (`AA:BB:CC:DD:EE:FF`).
+pub fn is_mac_address(s: &str) -> bool {
+    let parts: Vec<&str> = s.split(':').collect();
+    parts.len() == 6
+        && parts
+            .iter()
+            .all(|p| p.len() == 2 && p.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Bind a Bluetooth printer to the first free `/dev/rfcommN` device and
+/// return its path.
+///
+/// Equivalent to `rfcomm bind N <mac> <channel>`; requires BlueZ and
+/// permission to bind RFCOMM devices. Release with [`release_rfcomm`].
+#[cfg(target_os = "linux")]
+pub fn bind_rfcomm(mac: &str, channel: u8) -> Result<String, String> {
+    if !is_mac_address(mac) {
+        return Err(format!("Invalid Bluetooth address '{}'", mac));
+    }
+    let index = (0..MAX_RFCOMM_DEVICES)
+        .find(|n| !Path::new(&format!("/dev/rfcomm{}", n)).exists())
+        .ok_or_else(|| "No free /dev/rfcomm device".to_owned())?;
+
+    let output = Command::new("rfcomm")
+        .args(["bind", &index.to_string(), mac, &channel.to_string()])
+        .output()
+        .map_err(|e| format!("Failed to run 'rfcomm': {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "rfcomm bind {} failed: {}",
+            mac,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(format!("/dev/rfcomm{}", index))
+}
+
+/// Bind a Bluetooth printer to an RFCOMM device (Linux only).
+#[cfg(not(target_os = "linux"))]
+pub fn bind_rfcomm(_mac: &str, _channel: u8) -> Result<String, String> {
+    Err("RFCOMM binding is only supported on Linux; use the paired serial port instead".to_owned())
+}
+
+/// Release a device bound with [`bind_rfcomm`] (`rfcomm release N`).
+#[cfg(target_os = "linux")]
+pub fn release_rfcomm(device: &str) -> Result<(), String> {
+    let index = device.trim_start_matches("/dev/rfcomm");
+    let status = Command::new("rfcomm")
+        .args(["release", index])
+        .status()
+        .map_err(|e| format!("Failed to run 'rfcomm': {}", e))?;
+    if !status.success() {
+        return Err(format!("rfcomm release {} failed", device));
+    }
+    Ok(())
+}
+
+/// Release an RFCOMM device (Linux only).
+#[cfg(not(target_os = "linux"))]
+pub fn release_rfcomm(_device: &str) -> Result<(), String> {
+    Ok(())
+}
+
+/// Find the serial port of a paired Bluetooth printer by (partial) device
+/// name, case-insensitive.
+///
+/// Matches against the port name, so it works where the OS names the port
+/// after the device (macOS). On Windows pass the `COMn` port itself.
+pub fn find_port_by_name(name: &str) -> Result<String, PrintError> {
+    let needle = name.to_lowercase();
+    serialport::available_ports()
+        .map_err(|e| PrintError::Io(e.to_string()))?
+        .into_iter()
+        .find(|p| {
+            let bluetooth = matches!(p.port_type, serialport::SerialPortType::BluetoothPort)
+                || p.port_name.contains("rfcomm")
+                || p.port_name.contains("SerialPort");
+            bluetooth && p.port_name.to_lowercase().contains(&needle)
+        })
+        .map(|p| p.port_name)
+        .ok_or_else(|| PrintError::PortNotFound(name.to_owned()))
+}
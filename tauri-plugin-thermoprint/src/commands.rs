@@ -1,13 +1,23 @@
 use serde::Deserialize;
 use std::io::Write;
+use std::sync::atomic::Ordering;
 use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager, Runtime, State};
 
-use crate::PortInfo;
+use crate::bluetooth;
+use crate::error::PrintError;
+use crate::jobs::{
+    JobFinished, JobProgress, JobRegistry, JobStatus, FINISHED_EVENT, PROGRESS_EVENT,
+};
+use crate::system;
+use crate::{PortInfo, SystemPrinterInfo};
+use thermoprint::template::Element;
+use thermoprint::{PrintWidth, ReceiptBuilder, ReceiptTemplate};
 
 /// List available serial ports on the system.
 #[tauri::command]
-pub async fn list_ports() -> Result<Vec<PortInfo>, String> {
-    let ports = serialport::available_ports().map_err(|e| e.to_string())?;
+pub async fn list_ports() -> Result<Vec<PortInfo>, PrintError> {
+    let ports = serialport::available_ports().map_err(|e| PrintError::Io(e.to_string()))?;
 
     Ok(ports
         .into_iter()
@@ -46,36 +56,140 @@ pub struct PrintSerialArgs {
     pub baud_rate: u32,
     /// ESC/POS bytes to send. Passed as a JSON array of numbers.
     pub data: Vec<u8>,
+    /// Bytes written per chunk (default: 4096; `0` also means 4096).
+    /// Lower it for printers with a small receive buffer.
+    #[serde(default = "default_chunk_size")]
+    pub chunk_size: usize,
+    /// Pause between chunks in milliseconds (default: 0). A few ms lets a
+    /// slow printer drain its buffer before the next chunk arrives.
+    #[serde(default)]
+    pub chunk_delay_ms: u64,
 }
 
 fn default_baud() -> u32 {
     9600
 }
 
+/// Serial write chunk size used unless `chunkSize` is given.
+const DEFAULT_CHUNK_SIZE: usize = 4096;
+
+fn default_chunk_size() -> usize {
+    DEFAULT_CHUNK_SIZE
+}
+
 /// Send raw ESC/POS bytes to a serial port.
+///
+/// The write blocks (and sleeps between chunks), so it runs on the blocking
+/// thread pool rather than an async worker.
 #[tauri::command]
-pub async fn print_serial(args: PrintSerialArgs) -> Result<(), String> {
-    let mut port = serialport::new(&args.port, args.baud_rate)
+pub async fn print_serial(args: PrintSerialArgs) -> Result<(), PrintError> {
+    let args = tauri::async_runtime::spawn_blocking(move || {
+        write_serial(&args, |_| true)?;
+        Ok::<_, PrintError>(args)
+    })
+    .await
+    .map_err(|e| PrintError::Io(e.to_string()))??;
+
+    log::info!(
+        "thermoprint: sent {} bytes to {}",
+        args.data.len(),
+        args.port
+    );
+
+    Ok(())
+}
+
+/// Open `args.port` and write `args.data` in chunks, calling `on_chunk`
+/// with the number of bytes sent so far after each one. Returning `false`
+/// from `on_chunk` stops the write; the result is then `Ok(false)`.
+fn write_serial(
+    args: &PrintSerialArgs,
+    mut on_chunk: impl FnMut(usize) -> bool,
+) -> Result<bool, PrintError> {
+    let port_name = args.port.as_str();
+    let mut port = serialport::new(port_name, args.baud_rate)
         .timeout(Duration::from_secs(10))
         .open()
-        .map_err(|e| format!("Failed to open port '{}': {}", args.port, e))?;
+        .map_err(|e| PrintError::from_serial(port_name, e))?;
 
     // Write in chunks to avoid overwhelming the printer buffer
-    let chunk_size = 4096;
+    let chunk_size = match args.chunk_size {
+        0 => DEFAULT_CHUNK_SIZE,
+        n => n,
+    };
+    let delay = Duration::from_millis(args.chunk_delay_ms);
+    let mut sent = 0;
     for chunk in args.data.chunks(chunk_size) {
+        if sent > 0 && !delay.is_zero() {
+            std::thread::sleep(delay);
+        }
         port.write_all(chunk)
-            .map_err(|e| format!("Write error on '{}': {}", args.port, e))?;
+            .map_err(|e| PrintError::from_io(port_name, e))?;
         port.flush()
-            .map_err(|e| format!("Flush error on '{}': {}", args.port, e))?;
+            .map_err(|e| PrintError::from_io(port_name, e))?;
+        sent += chunk.len();
+        if !on_chunk(sent) {
+            return Ok(false);
+        }
     }
 
-    log::info!(
-        "thermoprint: sent {} bytes to {}",
-        args.data.len(),
-        args.port
-    );
+    Ok(true)
+}
 
-    Ok(())
+/// Start sending raw ESC/POS bytes to a serial port in the background.
+///
+/// Returns a job id immediately. Progress is reported through
+/// `thermoprint://progress` events and the outcome through a single
+/// `thermoprint://finished` event. Stop the job with `cancel_job`.
+#[tauri::command]
+pub async fn print_serial_job<R: Runtime>(
+    app: AppHandle<R>,
+    jobs: State<'_, JobRegistry>,
+    args: PrintSerialArgs,
+) -> Result<u64, PrintError> {
+    let (job_id, cancel) = jobs.start();
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let total = args.data.len();
+        let result = write_serial(&args, |sent| {
+            let _ = app.emit(
+                PROGRESS_EVENT,
+                JobProgress {
+                    job_id,
+                    sent,
+                    total,
+                },
+            );
+            !cancel.load(Ordering::Relaxed)
+        });
+
+        let (status, error) = match result {
+            Ok(true) => (JobStatus::Completed, None),
+            Ok(false) => (JobStatus::Cancelled, None),
+            Err(e) => (JobStatus::Failed, Some(e)),
+        };
+        log::info!("thermoprint: job {} on {} {:?}", job_id, args.port, status);
+
+        app.state::<JobRegistry>().finish(job_id);
+        let _ = app.emit(
+            FINISHED_EVENT,
+            JobFinished {
+                job_id,
+                status,
+                error,
+            },
+        );
+    });
+
+    Ok(job_id)
+}
+
+/// Cancel a background print job after its current chunk.
+///
+/// Returns `false` if the job has already finished or never existed.
+#[tauri::command]
+pub async fn cancel_job(jobs: State<'_, JobRegistry>, job_id: u64) -> Result<bool, PrintError> {
+    Ok(jobs.cancel(job_id))
 }
 
 #[derive(Debug, Deserialize)]
@@ -93,15 +207,204 @@ pub struct PrintTemplateArgs {
 /// Render a JSON receipt template and send the bytes to a serial port.
 ///
 /// This combines the template engine with serial printing in a single call.
+/// `image_path` elements are rejected: the template comes from the webview,
+/// which must not get the app's filesystem access through them. Dither
+/// images in the frontend and pass the raster bytes instead.
 #[tauri::command]
-pub async fn print_template(args: PrintTemplateArgs) -> Result<(), String> {
-    let bytes = thermoprint::render_json(&args.template).map_err(|e| e.to_string())?;
+pub async fn print_template(args: PrintTemplateArgs) -> Result<(), PrintError> {
+    let template = ReceiptTemplate::from_json(&args.template)
+        .map_err(|e| PrintError::TemplateError(e.to_string()))?;
+    if let Some(index) = template
+        .elements
+        .iter()
+        .position(|e| matches!(e, Element::ImagePath { .. }))
+    {
+        return Err(PrintError::TemplateError(format!(
+            "Element {}: image_path is not allowed in print_template; \
+             dither the image in the frontend and print the raster bytes",
+            index
+        )));
+    }
+    let bytes = template
+        .render()
+        .map_err(|e| PrintError::TemplateError(e.to_string()))?;
 
     let print_args = PrintSerialArgs {
         port: args.port,
         baud_rate: args.baud_rate,
         data: bytes,
+        chunk_size: DEFAULT_CHUNK_SIZE,
+        chunk_delay_ms: 0,
     };
 
     print_serial(print_args).await
 }
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrintTestPageArgs {
+    /// Serial port path.
+    pub port: String,
+    /// Baud rate (default: 9600).
+    #[serde(default = "default_baud")]
+    pub baud_rate: u32,
+    /// Paper width: `"58mm"` or `"80mm"` (default: `"80mm"`).
+    #[serde(default = "default_width")]
+    pub width: String,
+}
+
+fn default_width() -> String {
+    "80mm".to_owned()
+}
+
+/// Print the self-test page (ruler, styles, barcode, QR) to a serial port.
+#[tauri::command]
+pub async fn print_test_page(args: PrintTestPageArgs) -> Result<(), PrintError> {
+    let width = match args.width.to_lowercase().as_str() {
+        "58mm" | "58" => PrintWidth::Mm58,
+        "80mm" | "80" => PrintWidth::Mm80,
+        other => {
+            return Err(PrintError::InvalidArgument(format!(
+                "Unknown paper width '{}'. Use '58mm' or '80mm'.",
+                other
+            )))
+        }
+    };
+
+    print_serial(PrintSerialArgs {
+        port: args.port,
+        baud_rate: args.baud_rate,
+        data: ReceiptBuilder::test_page(width).build(),
+        chunk_size: DEFAULT_CHUNK_SIZE,
+        chunk_delay_ms: 0,
+    })
+    .await
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResetPrinterArgs {
+    /// Serial port path.
+    pub port: String,
+    /// Baud rate (default: 9600).
+    #[serde(default = "default_baud")]
+    pub baud_rate: u32,
+}
+
+/// Send [`thermoprint::reset_bytes`] to a serial port to recover a printer
+/// stuck in double-size, bold or another leftover mode. Prints nothing.
+#[tauri::command]
+pub async fn reset_printer(args: ResetPrinterArgs) -> Result<(), PrintError> {
+    print_serial(PrintSerialArgs {
+        port: args.port,
+        baud_rate: args.baud_rate,
+        data: thermoprint::reset_bytes(),
+        chunk_size: DEFAULT_CHUNK_SIZE,
+        chunk_delay_ms: 0,
+    })
+    .await
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrintBluetoothArgs {
+    /// Printer MAC address (`AA:BB:CC:DD:EE:FF`) or paired device name.
+    pub address: String,
+    /// RFCOMM channel used when binding by MAC address (default: 1).
+    #[serde(default = "default_channel")]
+    pub channel: u8,
+    /// Baud rate (default: 9600). Ignored by most Bluetooth links.
+    #[serde(default = "default_baud")]
+    pub baud_rate: u32,
+    /// ESC/POS bytes to send.
+    pub data: Vec<u8>,
+}
+
+fn default_channel() -> u8 {
+    1
+}
+
+/// Send raw ESC/POS bytes to a Bluetooth (RFCOMM / SPP) printer.
+///
+/// A MAC address is bound to a temporary `/dev/rfcommN` device on Linux and
+/// released afterwards. Anything else is treated as a device name and looked
+/// up among the paired Bluetooth serial ports.
+///
+/// `rfcomm` and the write block, so they run on the blocking thread pool.
+#[tauri::command]
+pub async fn print_bluetooth(args: PrintBluetoothArgs) -> Result<(), PrintError> {
+    let serial = tauri::async_runtime::spawn_blocking(move || {
+        let (port, bound) = if bluetooth::is_mac_address(&args.address) {
+            (bluetooth::bind_rfcomm(&args.address, args.channel)?, true)
+        } else {
+            (bluetooth::find_port_by_name(&args.address)?, false)
+        };
+
+        let serial = PrintSerialArgs {
+            port,
+            baud_rate: args.baud_rate,
+            data: args.data,
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            chunk_delay_ms: 0,
+        };
+        let result = write_serial(&serial, |_| true);
+
+        if bound {
+            if let Err(e) = bluetooth::release_rfcomm(&serial.port) {
+                log::warn!("thermoprint: {}", e);
+            }
+        }
+        result?;
+        Ok::<_, PrintError>(serial)
+    })
+    .await
+    .map_err(|e| PrintError::Io(e.to_string()))??;
+
+    log::info!(
+        "thermoprint: sent {} bytes to {}",
+        serial.data.len(),
+        serial.port
+    );
+
+    Ok(())
+}
+
+/// List printers installed in the OS spooler (CUPS / Windows).
+///
+/// `lpstat` / PowerShell run on the blocking thread pool.
+#[tauri::command]
+pub async fn list_system_printers() -> Result<Vec<SystemPrinterInfo>, PrintError> {
+    tauri::async_runtime::spawn_blocking(|| Ok::<_, PrintError>(system::list_printers()?))
+        .await
+        .map_err(|e| PrintError::Io(e.to_string()))?
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrintSystemArgs {
+    /// Spooler printer name, as returned by `list_system_printers`.
+    pub printer_name: String,
+    /// ESC/POS bytes to send.
+    pub data: Vec<u8>,
+}
+
+/// Send raw ESC/POS bytes to an OS-managed printer by name.
+///
+/// The spooler call blocks, so it runs on the blocking thread pool.
+#[tauri::command]
+pub async fn print_system(args: PrintSystemArgs) -> Result<(), PrintError> {
+    let args = tauri::async_runtime::spawn_blocking(move || {
+        system::print_raw(&args.printer_name, &args.data)?;
+        Ok::<_, PrintError>(args)
+    })
+    .await
+    .map_err(|e| PrintError::Io(e.to_string()))??;
+
+    log::info!(
+        "thermoprint: sent {} bytes to system printer {}",
+        args.data.len(),
+        args.printer_name
+    );
+
+    Ok(())
+}
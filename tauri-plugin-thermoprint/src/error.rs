@@ -0,0 +1,94 @@
+//! Structured errors returned by the plugin commands.
+//!
+//! Serialised as `{ "kind": "portBusy", "message": "/dev/ttyUSB0" }` so the
+//! frontend can branch on `kind` (e.g. retry on `portBusy`) instead of
+//! matching error strings. `timeout` has no `message`.
+
+use serde::Serialize;
+use std::fmt;
+use std::io;
+
+/// Why a print command failed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", content = "message", rename_all = "camelCase")]
+pub enum PrintError {
+    /// The port or printer (named in the payload) does not exist.
+    PortNotFound(String),
+    /// The port (named in the payload) is open in another process.
+    PortBusy(String),
+    /// Any other I/O or OS failure, with a human-readable description.
+    Io(String),
+    /// The printer stopped accepting data within the write timeout.
+    Timeout,
+    /// The JSON template could not be rendered.
+    TemplateError(String),
+    /// A command argument (e.g. the paper width) is not a valid value.
+    InvalidArgument(String),
+}
+
+impl PrintError {
+    /// Classify an error from opening or configuring serial port `port`.
+    pub fn from_serial(port: &str, e: serialport::Error) -> Self {
+        use serialport::ErrorKind;
+
+        // Linux reports EBUSY as `ErrorKind::Unknown` ("Device or resource
+        // busy"); Windows reports a port open elsewhere as ERROR_ACCESS_DENIED,
+        // which serialport maps to `NoDevice` ("Access is denied.")
+        let busy = {
+            let d = e.description.to_lowercase();
+            d.contains("busy")
+                || d.contains("in use")
+                || cfg!(windows) && d.contains("access is denied")
+        };
+        match e.kind {
+            _ if busy => PrintError::PortBusy(port.to_owned()),
+            ErrorKind::NoDevice | ErrorKind::Io(io::ErrorKind::NotFound) => {
+                PrintError::PortNotFound(port.to_owned())
+            }
+            ErrorKind::Io(kind) => Self::from_io(port, io::Error::new(kind, e.description)),
+            _ => PrintError::Io(format!("Failed to open port '{}': {}", port, e)),
+        }
+    }
+
+    /// Classify an I/O error from writing to `target`.
+    pub fn from_io(target: &str, e: io::Error) -> Self {
+        match e.kind() {
+            io::ErrorKind::TimedOut | io::ErrorKind::WouldBlock => PrintError::Timeout,
+            io::ErrorKind::NotFound => PrintError::PortNotFound(target.to_owned()),
+            _ if is_busy_os_error(&e) => PrintError::PortBusy(target.to_owned()),
+            _ => PrintError::Io(format!("I/O error on '{}': {}", target, e)),
+        }
+    }
+}
+
+/// EBUSY on Unix; ERROR_ACCESS_DENIED or ERROR_SHARING_VIOLATION on Windows,
+/// where a port held by another process fails with those instead.
+fn is_busy_os_error(e: &io::Error) -> bool {
+    match e.raw_os_error() {
+        Some(16) => cfg!(unix),
+        Some(5 | 32) => cfg!(windows),
+        _ => false,
+    }
+}
+
+impl fmt::Display for PrintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PrintError::PortNotFound(port) => write!(f, "Port '{}' not found", port),
+            PrintError::PortBusy(port) => write!(f, "Port '{}' is busy", port),
+            PrintError::Io(msg) => f.write_str(msg),
+            PrintError::Timeout => f.write_str("Timed out writing to the printer"),
+            PrintError::TemplateError(msg) => write!(f, "Template error: {}", msg),
+            PrintError::InvalidArgument(msg) => f.write_str(msg),
+        }
+    }
+}
+
+impl std::error::Error for PrintError {}
+
+/// Errors from spawned tools (`rfcomm`, `lp`, ...) are already descriptive.
+impl From<String> for PrintError {
+    fn from(msg: String) -> Self {
+        PrintError::Io(msg)
+    }
+}
@@ -0,0 +1,86 @@
+//! Background print jobs with progress reporting and cancellation.
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::error::PrintError;
+
+/// Event emitted after every chunk written by a background job.
+pub const PROGRESS_EVENT: &str = "thermoprint://progress";
+/// Event emitted once when a background job ends.
+pub const FINISHED_EVENT: &str = "thermoprint://finished";
+
+/// Running background print jobs, keyed by job id. Managed as plugin state.
+#[derive(Default)]
+pub struct JobRegistry {
+    next_id: AtomicU64,
+    jobs: Mutex<HashMap<u64, Arc<AtomicBool>>>,
+}
+
+impl JobRegistry {
+    /// Register a new job and return its id and cancellation flag.
+    pub fn start(&self) -> (u64, Arc<AtomicBool>) {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed) + 1;
+        let cancel = Arc::new(AtomicBool::new(false));
+        self.jobs
+            .lock()
+            .expect("job registry poisoned")
+            .insert(id, cancel.clone());
+        (id, cancel)
+    }
+
+    /// Ask a running job to stop after its current chunk.
+    /// Returns `false` if no such job is running.
+    pub fn cancel(&self, id: u64) -> bool {
+        match self.jobs.lock().expect("job registry poisoned").get(&id) {
+            Some(flag) => {
+                flag.store(true, Ordering::Relaxed);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Forget a job once it has ended.
+    pub fn finish(&self, id: u64) {
+        self.jobs.lock().expect("job registry poisoned").remove(&id);
+    }
+}
+
+/// Payload of [`PROGRESS_EVENT`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobProgress {
+    /// Job id returned by `print_serial_job`.
+    pub job_id: u64,
+    /// Bytes written so far.
+    pub sent: usize,
+    /// Total bytes in the job.
+    pub total: usize,
+}
+
+/// How a background job ended.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JobStatus {
+    /// All bytes were written.
+    Completed,
+    /// Stopped by `cancel_job`.
+    Cancelled,
+    /// Stopped by an I/O error.
+    Failed,
+}
+
+/// Payload of [`FINISHED_EVENT`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobFinished {
+    /// Job id returned by `print_serial_job`.
+    pub job_id: u64,
+    /// How the job ended.
+    pub status: JobStatus,
+    /// Why the job failed when `status` is `failed`.
+    pub error: Option<PrintError>,
+}
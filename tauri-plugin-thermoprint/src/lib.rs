@@ -36,18 +36,56 @@
 //!   baudRate: 9600,
 //!   template: JSON.stringify({ width: "80mm", elements: [...] }),
 //! });
+//!
+//! // Long reports: print in the background with progress and cancel
+//! import { listen } from '@tauri-apps/api/event';
+//! await listen('thermoprint://progress', (e) => showProgress(e.payload.sent / e.payload.total));
+//! const jobId = await invoke('plugin:thermoprint|print_serial_job', {
+//!   port: '/dev/ttyUSB0',
+//!   data: Array.from(reportBytes),
+//! });
+//! await invoke('plugin:thermoprint|cancel_job', { jobId });
+//!
+//! // Installation check: print the self-test page
+//! await invoke('plugin:thermoprint|print_test_page', { port: '/dev/ttyUSB0' });
+//!
+//! // Support: recover a printer stuck in double-size / bold mode
+//! await invoke('plugin:thermoprint|reset_printer', { port: '/dev/ttyUSB0' });
+//!
+//! // Print to a printer installed in CUPS / the Windows spooler
+//! const printers = await invoke('plugin:thermoprint|list_system_printers');
+//! await invoke('plugin:thermoprint|print_system', {
+//!   printerName: printers[0].name,
+//!   data: Array.from(receiptBytes),
+//! });
+//!
+//! // Print to a Bluetooth printer by MAC address (Linux) or paired name
+//! await invoke('plugin:thermoprint|print_bluetooth', {
+//!   address: '66:22:B3:4C:10:9F',
+//!   data: Array.from(receiptBytes),
+//! });
 //! ```
 
 use serde::{Deserialize, Serialize};
 use tauri::{
     plugin::{Builder, TauriPlugin},
-    Runtime,
+    Manager, Runtime,
 };
 
+pub mod bluetooth;
 mod commands;
+pub mod error;
+pub mod jobs;
+mod system;
+
+pub use error::PrintError;
 
 /// Initialise the thermoprint plugin.
 ///
+/// Commands are gated by the permissions in `permissions/` (generated per
+/// command by `build.rs`); grant them to a window through its capability,
+/// e.g. `"thermoprint:default"` or `"thermoprint:allow-print-serial"`.
+///
 /// ```rust,ignore
 /// tauri::Builder::default()
 ///     .plugin(tauri_plugin_thermoprint::init())
@@ -58,7 +96,18 @@ pub fn init<R: Runtime>() -> TauriPlugin<R> {
             commands::list_ports,
             commands::print_serial,
             commands::print_template,
+            commands::print_test_page,
+            commands::reset_printer,
+            commands::print_bluetooth,
+            commands::list_system_printers,
+            commands::print_system,
+            commands::print_serial_job,
+            commands::cancel_job,
         ])
+        .setup(|app, _api| {
+            app.manage(jobs::JobRegistry::default());
+            Ok(())
+        })
         .build()
 }
 
@@ -70,3 +119,12 @@ pub struct PortInfo {
     /// Port type description.
     pub port_type: String,
 }
+
+/// A printer installed in the OS spooler (CUPS / Windows).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemPrinterInfo {
+    /// Spooler printer name (e.g. `EPSON_TM_T20III`).
+    pub name: String,
+    /// Whether this is the system default printer.
+    pub is_default: bool,
+}
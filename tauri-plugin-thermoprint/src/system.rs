@@ -0,0 +1,169 @@
+//! OS-managed (spooler) printers.
+//!
+//! For thermal printers installed as a CUPS queue or a Windows printer
+//! rather than opened as a raw serial port. Bytes are submitted as a raw
+//! job so the spooler passes the ESC/POS stream through untouched.
+//!
+//! - **Unix (CUPS)**: `lpstat` to list, `lp -d <name> -o raw` to print.
+//! - **Windows**: PowerShell `Win32_Printer` to list; printing goes through
+//!   the spooler API (`OpenPrinter` / `StartDocPrinter` / `WritePrinter`)
+//!   with the `RAW` datatype, so no shell sees the printer name.
+
+#[cfg(unix)]
+use std::io::Write;
+use std::process::Command;
+#[cfg(unix)]
+use std::process::Stdio;
+
+use crate::SystemPrinterInfo;
+
+/// List printers known to the OS spooler.
+#[cfg(unix)]
+pub fn list_printers() -> Result<Vec<SystemPrinterInfo>, String> {
+    let names = run("lpstat", &["-e"])?;
+    // "system default destination: NAME" — absent when no default is set
+    let default = run("lpstat", &["-d"]).unwrap_or_default();
+    let default = default.rsplit(':').next().unwrap_or("").trim().to_owned();
+
+    Ok(names
+        .lines()
+        .map(str::trim)
+        .filter(|n| !n.is_empty())
+        .map(|name| SystemPrinterInfo {
+            name: name.to_owned(),
+            is_default: name == default,
+        })
+        .collect())
+}
+
+/// List printers known to the OS spooler.
+#[cfg(windows)]
+pub fn list_printers() -> Result<Vec<SystemPrinterInfo>, String> {
+    let output = run(
+        "powershell",
+        &[
+            "-NoProfile",
+            "-Command",
+            "Get-CimInstance Win32_Printer | ForEach-Object { \"$($_.Default)|$($_.Name)\" }",
+        ],
+    )?;
+    Ok(output
+        .lines()
+        .filter_map(|line| line.trim().split_once('|'))
+        .map(|(default, name)| SystemPrinterInfo {
+            name: name.to_owned(),
+            is_default: default.eq_ignore_ascii_case("true"),
+        })
+        .collect())
+}
+
+/// Submit `data` as a raw job to the named printer.
+#[cfg(unix)]
+pub fn print_raw(printer_name: &str, data: &[u8]) -> Result<(), String> {
+    let mut child = Command::new("lp")
+        .args(["-d", printer_name, "-o", "raw"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to run 'lp': {}", e))?;
+
+    child
+        .stdin
+        .take()
+        .ok_or_else(|| "Failed to open 'lp' stdin".to_owned())?
+        .write_all(data)
+        .map_err(|e| format!("Write error to 'lp': {}", e))?;
+
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("Failed to wait for 'lp': {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "lp -d {} failed: {}",
+            printer_name,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(())
+}
+
+/// Submit `data` as a raw job to the named printer.
+#[cfg(windows)]
+pub fn print_raw(printer_name: &str, data: &[u8]) -> Result<(), String> {
+    use windows_sys::Win32::Graphics::Printing::{
+        ClosePrinter, EndDocPrinter, EndPagePrinter, OpenPrinterW, StartDocPrinterW,
+        StartPagePrinter, WritePrinter, DOC_INFO_1W,
+    };
+
+    if printer_name.contains('\0') {
+        return Err(format!("Invalid printer name '{}'", printer_name));
+    }
+    let wide = |s: &str| s.encode_utf16().chain(Some(0)).collect::<Vec<u16>>();
+    let name = wide(printer_name);
+    let mut doc_name = wide("thermoprint");
+    let mut datatype = wide("RAW");
+    let os_error = |what: &str| format!("{} failed: {}", what, std::io::Error::last_os_error());
+
+    // SAFETY: every pointer passed below outlives the call it is passed to,
+    // the wide strings are NUL-terminated, and the handle is closed exactly
+    // once, on every path after a successful OpenPrinterW.
+    unsafe {
+        let mut handle = std::mem::zeroed();
+        if OpenPrinterW(name.as_ptr(), &mut handle, std::ptr::null()) == 0 {
+            return Err(format!(
+                "Printer '{}' not found: {}",
+                printer_name,
+                std::io::Error::last_os_error()
+            ));
+        }
+        let doc = DOC_INFO_1W {
+            pDocName: doc_name.as_mut_ptr(),
+            pOutputFile: std::ptr::null_mut(),
+            pDatatype: datatype.as_mut_ptr(),
+        };
+        let result = if StartDocPrinterW(handle, 1, &doc) == 0 {
+            Err(os_error("StartDocPrinter"))
+        } else {
+            let mut result = Ok(());
+            if StartPagePrinter(handle) == 0 {
+                result = Err(os_error("StartPagePrinter"));
+            } else {
+                let mut offset = 0;
+                while offset < data.len() {
+                    let chunk = &data[offset..];
+                    let len = chunk.len().min(u32::MAX as usize) as u32;
+                    let mut written = 0u32;
+                    if WritePrinter(handle, chunk.as_ptr().cast(), len, &mut written) == 0
+                        || written == 0
+                    {
+                        result = Err(os_error("WritePrinter"));
+                        break;
+                    }
+                    offset += written as usize;
+                }
+                EndPagePrinter(handle);
+            }
+            EndDocPrinter(handle);
+            result
+        };
+        ClosePrinter(handle);
+        result
+    }
+}
+
+/// Run a command and return its stdout, or its stderr as the error.
+fn run(program: &str, args: &[&str]) -> Result<String, String> {
+    let output = Command::new(program)
+        .args(args)
+        .output()
+        .map_err(|e| format!("Failed to run '{}': {}", program, e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "'{}' failed: {}",
+            program,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
@@ -1,5 +1,9 @@
 use rust_decimal::prelude::*;
-use thermoprint::{PrintWidth, ReceiptBuilder, TaxEntry};
+use thermoprint::{
+    merge_items, Align, CurrencyPlacement, Dialect, Direction, Feature, HriFont, HriPosition,
+    ItemInput, ItemStyle, Language, LineEnding, MoneyFormat, PrintWidth, ReceiptBuilder,
+    ReceiptTemplate, Symbology, TaxEntry,
+};
 
 // ── Smoke tests: make sure builds don't panic and produce non-empty output ───
 
@@ -144,6 +148,516 @@ fn qr_code_bytes_present() {
     assert!(has_qr, "QR code command block must be present");
 }
 
+#[test]
+fn data_matrix_block_present() {
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+        .data_matrix("0103453120000011", 5)
+        .build();
+
+    // GS ( k with cn = 54 selects the Data Matrix function block
+    let has_dm = bytes.windows(6).any(|w| w == [0x1D, b'(', b'k', 3, 0, 54]);
+    assert!(has_dm, "Data Matrix command block must be present");
+    assert!(bytes.ends_with(b"0103453120000011\x1D(k\x03\x006Q0\n"));
+}
+
+#[test]
+fn width_gates_unsupported_features() {
+    assert!(PrintWidth::Mm58.supports(Feature::CashDrawer));
+    assert!(!PrintWidth::Mm58.supports(Feature::FormFeed));
+    assert!(!PrintWidth::A4.supports(Feature::CashDrawer));
+    assert!(!PrintWidth::A4.supports(Feature::Logo));
+    assert!(!PrintWidth::A4.supports(Feature::Barcode));
+
+    // A4: no drawer kick, cuts eject the page without doubling up
+    let a4 = ReceiptBuilder::new(PrintWidth::A4)
+        .text_line("Facture")
+        .open_cash_drawer()
+        .cut()
+        .feed_and_cut(2)
+        .cut_full_with_feed(40)
+        .build();
+    assert_eq!(a4, b"Facture\n\x0C\x1Bd\x02\x0C");
+    let a4 = ReceiptBuilder::new(PrintWidth::A4)
+        .open_cash_drawer()
+        .open_cash_drawer_custom(2, 50, 50)
+        .logo_raw(&[0x1D, b'v', b'0', 0, 1, 0, 1, 0, 0xFF]);
+    assert_eq!(a4.skipped(), &[Feature::CashDrawer, Feature::Logo]);
+
+    // Thermal: form feed, cut and drawer all go through
+    let roll = ReceiptBuilder::new(PrintWidth::Mm80)
+        .form_feed()
+        .open_cash_drawer()
+        .cut();
+    assert!(roll.skipped().is_empty());
+    let roll = roll.build();
+    assert!(roll.starts_with(&[0x0C]));
+    assert!(roll.ends_with(&[0x1D, b'V', 66, 0]));
+
+    // A4 copies start on a new page rather than being cut apart
+    let copies = ReceiptBuilder::new(PrintWidth::A4)
+        .text_line("A")
+        .repeat(2)
+        .build();
+    assert_eq!(copies, b"A\n\x0CA\n");
+}
+
+#[test]
+fn a4_replaces_codes_and_logos_with_text() {
+    let captioned = ReceiptBuilder::new(PrintWidth::A4).qr_with_caption(
+        "https://example.com/f/1042",
+        4,
+        "Scannez-moi",
+    );
+    assert_eq!(captioned.skipped(), &[Feature::Barcode]);
+
+    let bytes = ReceiptBuilder::new(PrintWidth::A4)
+        .logo_raw(&[0x1D, b'v', b'0', 0, 1, 0, 1, 0, 0xFF])
+        .nv_logo(1, 0)
+        .text_line("Facture 1042")
+        .barcode_code128("INV-1042")
+        .barcode_hri(HriPosition::None, HriFont::A)
+        .barcode_ean13("590123412345")
+        .barcode_with_text("590123412345", Symbology::Ean13, HriFont::A)
+        .unwrap()
+        .qr_code("https://example.com/f/1042", 4)
+        .qr_with_caption("https://example.com/f/1042", 4, "Scannez-moi")
+        .data_matrix("LOT 4411", 4)
+        .cut()
+        .build();
+    assert_eq!(
+        bytes,
+        b"Facture 1042\nINV-1042\n\x1Ba\x01\x1BM\x00590123412345\n\x1BM\x00\x1Ba\x00\x0C"
+    );
+}
+
+#[test]
+fn totals_block_aligns_labels_and_amounts() {
+    let block = |direction| {
+        let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+            .language(Language::En)
+            .direction(direction)
+            .totals()
+            .subtotal(dec!(30_000))
+            .discount(dec!(1_500))
+            .discount(dec!(0))
+            .tax(&TaxEntry::new("VAT 18%", dec!(5_130), true))
+            .line("Delivery to the customer's door", dec!(500))
+            .grand_total(dec!(29_000))
+            .render()
+            .build();
+        thermoprint::preview::preview(&bytes, PrintWidth::Mm58)
+            .lines()
+            .map(str::to_owned)
+            .collect::<Vec<_>>()
+    };
+
+    let lines = block(Direction::Ltr);
+    assert_eq!(lines.len(), 5, "zero discount is skipped: {:?}", lines);
+    for line in &lines {
+        assert_eq!(line.chars().count(), 32);
+        assert!(line.ends_with(" FCFA"));
+    }
+    let label_col = lines[0].find(|c: char| c != ' ').unwrap();
+    assert!(lines
+        .iter()
+        .all(|l| l[label_col..].starts_with(|c: char| c != ' ')));
+    assert!(lines[1].ends_with(" -1500 FCFA"));
+    assert_eq!(lines[3], "Delivery to the cu...   500 FCFA");
+
+    // RTL mirrors the block: amounts against the left edge
+    let rtl = block(Direction::Rtl);
+    assert!(rtl[0].starts_with("30000 FCFA "));
+    assert!(rtl[1].starts_with("-1500 FCFA "));
+}
+
+#[test]
+fn amounts_wider_than_the_line_print_whole() {
+    let amount = dec!(123_456_789_012_345_678_901_234);
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+        .language(Language::En)
+        .row("Reference", "0123456789012345678901234567890123")
+        .total(amount)
+        .totals()
+        .subtotal(amount)
+        .render()
+        .build();
+    let preview = thermoprint::preview::preview(&bytes, PrintWidth::Mm58);
+    let lines: Vec<&str> = preview.lines().collect();
+    // No room left for the label: it gets its own line, the value follows
+    assert_eq!(
+        lines,
+        [
+            "Reference",
+            "0123456789012345678901234567890123",
+            "TOTAL",
+            "   123456789012345678901234 FCFA",
+            "SUBTOTAL",
+            "   123456789012345678901234 FCFA",
+        ]
+    );
+}
+
+#[test]
+fn currency_totals_only_leaves_item_lines_bare() {
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+        .currency_on(CurrencyPlacement::TotalsOnly)
+        .item("Polo", 2, dec!(15_000), Some(dec!(2_000)))
+        .sub_item("Brodure", Some(dec!(500)))
+        .subtotal_ht(dec!(28_500))
+        .total(dec!(28_500))
+        .build();
+    let text = thermoprint::preview::preview(&bytes, PrintWidth::Mm58);
+    let lines: Vec<&str> = text.lines().collect();
+    assert!(lines.contains(&"2 x 15000"));
+    assert!(lines.contains(&"  Remise: -2000"));
+    assert!(lines.iter().any(|l| l.ends_with(" 28000")));
+    assert!(lines.iter().any(|l| l.ends_with("+500")));
+    assert_eq!(text.matches("28500 FCFA").count(), 2);
+}
+
+#[test]
+fn currency_totals_only_keeps_foreign_currency_on_items() {
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+        .currency_on(CurrencyPlacement::TotalsOnly)
+        .item("Pain", 2, dec!(250), None)
+        .item_in("Carte SIM", 1, dec!(5), None, "USD")
+        .item("Lait", 1, dec!(600), None)
+        .build();
+    let text = thermoprint::preview::preview(&bytes, PrintWidth::Mm58);
+    let lines: Vec<&str> = text.lines().collect();
+    assert!(lines.contains(&"2 x 250"));
+    assert!(lines.contains(&"1 x 5 USD"));
+    assert!(lines.contains(&"1 x 600"));
+    assert!(!text.contains("FCFA"));
+}
+
+#[test]
+fn total_in_words_wraps_french_amount() {
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+        .currency("francs")
+        .total_in_words(dec!(62_540))
+        .total_in_words(dec!(280_080))
+        .build();
+    let text = String::from_utf8(bytes).unwrap();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(
+        lines,
+        [
+            "Soixante-deux mille cinq cent",
+            "quarante francs",
+            "Deux cent quatre-vingt mille",
+            "quatre-vingts francs",
+        ]
+    );
+
+    let en = ReceiptBuilder::new(PrintWidth::Mm80)
+        .language(Language::En)
+        .currency("USD")
+        .money_format(MoneyFormat::new(2, Some(','), '.', true))
+        .total_in_words(dec!(1250.5))
+        .build();
+    assert_eq!(en, b"One thousand two hundred fifty USD and fifty\ncents\n");
+}
+
+#[test]
+fn one_off_currency_lines() {
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+        .item("Riz 5kg", 1, dec!(4500), None)
+        .item_in("Recharge", 2, dec!(10), None, "USD")
+        .total(dec!(62_540))
+        .total_in(dec!(95), "USD")
+        .row("Rendu", "0 FCFA")
+        .build();
+    let text = String::from_utf8_lossy(&bytes);
+    assert!(text.contains("4500 FCFA"));
+    assert!(text.contains("20 USD"));
+    assert!(text.contains("62540 FCFA"));
+    assert!(text.contains("95 USD"));
+    assert_eq!(text.matches("USD").count(), 3);
+
+    // The builder currency is restored after each one-off line
+    let after = ReceiptBuilder::new(PrintWidth::Mm58)
+        .total_in(dec!(1), "EUR")
+        .change(dec!(500))
+        .build();
+    assert!(String::from_utf8_lossy(&after).contains("500 FCFA"));
+}
+
+#[test]
+fn barcode_with_text_prints_value_instead_of_hri() {
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+        .barcode_with_text("ORD-42", Symbology::Code128, HriFont::B)
+        .unwrap()
+        .barcode_code128("NEXT")
+        .build();
+
+    // HRI off for this barcode, value in font B, then back to font A
+    let hri_off = bytes.windows(3).position(|w| w == [0x1D, b'H', 0]).unwrap();
+    let text = bytes
+        .windows(10)
+        .position(|w| w == b"\x1BM\x01ORD-42\n")
+        .unwrap();
+    assert!(hri_off < text);
+    assert_eq!(&bytes[text + 10..text + 13], [0x1B, b'M', 0]);
+    // The next barcode gets the default HRI below again
+    assert!(bytes.windows(3).skip(text).any(|w| w == [0x1D, b'H', 2]));
+
+    let err =
+        ReceiptBuilder::new(PrintWidth::Mm80).barcode_with_text("12", Symbology::Ean8, HriFont::A);
+    assert!(err.is_err());
+}
+
+#[test]
+fn boxed_block_is_sized_to_the_longest_line() {
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+        .boxed(&["CODE PROMO", "TABASKI25"])
+        .build();
+    let text = thermoprint::encoding::decode_cp858(&bytes);
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(
+        lines,
+        [
+            "         ┌────────────┐",
+            "         │ CODE PROMO │",
+            "         │ TABASKI25  │",
+            "         └────────────┘",
+        ]
+    );
+    assert_eq!(&bytes[9..11], [0xDA, 0xC4]);
+
+    // Clamped to the paper: a long line is cut to fit inside the border
+    let long = ReceiptBuilder::new(PrintWidth::Mm58)
+        .boxed(&["Offre valable jusqu'au 31 decembre 2026"])
+        .build();
+    let text = thermoprint::encoding::decode_cp858(&long);
+    assert!(text.lines().all(|l| l.chars().count() == 32));
+    assert!(text.contains("│ Offre valable jusqu'au 31... │"));
+}
+
+#[test]
+fn double_size_shop_name_fits_the_paper() {
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+        .shop_header(
+            "Boulangerie Patisserie du Plateau",
+            "+221 77 000 00 00",
+            "Dakar",
+        )
+        .build();
+    let text = String::from_utf8_lossy(&bytes);
+    // 32 columns at double width leave room for 16 characters
+    assert!(text.contains("\x1B!0Boulangerie P...\n"));
+    assert!(text.contains("+221 77 000 00 00\n"));
+
+    let short = ReceiptBuilder::new(PrintWidth::Mm80)
+        .shop_header("MA BOUTIQUE", "", "")
+        .build();
+    assert!(String::from_utf8_lossy(&short).contains("MA BOUTIQUE\n"));
+}
+
+#[test]
+fn template_elements_continue_in_the_fluent_api() {
+    let template = ReceiptTemplate::from_json(
+        r#"{
+            "width": "58mm",
+            "language": "en",
+            "elements": [
+                { "type": "text_line", "text": "MA BOUTIQUE" },
+                { "type": "total", "amount": "1500" }
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    let bytes = ReceiptBuilder::from_template(&template)
+        .unwrap()
+        .text_line("12/06/2024 14:30")
+        .feed(2)
+        .cut()
+        .build();
+    let rendered = template.render().unwrap();
+    assert!(bytes.starts_with(&rendered));
+    assert!(bytes.ends_with(b"12/06/2024 14:30\n\x1Bd\x02\x1DVB\0"));
+
+    // Applied onto an existing builder, the builder's settings win
+    let applied = ReceiptBuilder::new(PrintWidth::Mm80)
+        .text_line("Commande #42")
+        .apply_template(&template)
+        .unwrap()
+        .feed(1)
+        .cut()
+        .build();
+    let text = String::from_utf8_lossy(&applied);
+    assert!(text.starts_with("Commande #42\nMA BOUTIQUE\n"));
+    assert!(text.contains(&format!("TOTAL{}1500 FCFA", " ".repeat(48 - 14))));
+
+    let bad = ReceiptTemplate::from_json(r#"{ "elements": [{ "type": "total", "amount": "x" }] }"#)
+        .unwrap();
+    let err = ReceiptBuilder::new(PrintWidth::Mm58).apply_template(&bad);
+    assert_eq!(err.err().and_then(|e| e.element_index()), Some(0));
+}
+
+#[test]
+fn line_ending_modes() {
+    let lines = |ending| {
+        ReceiptBuilder::new(PrintWidth::Mm58)
+            .line_ending(ending)
+            .cut_feed(1)
+            .text_line("A")
+            .blank()
+            .cut()
+            .build()
+    };
+    assert_eq!(lines(LineEnding::Lf), b"A\n\n\n\x1DVB\0");
+    assert_eq!(lines(LineEnding::CrLf), b"A\r\n\r\n\r\n\x1DVB\0");
+    assert_eq!(lines(LineEnding::Cr), b"A\r\r\r\x1DVB\0");
+}
+
+#[test]
+fn layouts_narrower_than_two_columns_do_not_panic() {
+    let narrow = [
+        ReceiptBuilder::new(PrintWidth::Mm58).indent(60),
+        ReceiptBuilder::new(PrintWidth::Mm58).content_width(0),
+        ReceiptBuilder::new(PrintWidth::Mm58).content_width(1),
+    ];
+    for b in narrow {
+        let bytes = b
+            .item("Cafe", 1, dec!(1500), Some(dec!(100)))
+            .item_compact("Cafe", 1, dec!(1500), None)
+            .row("Total", "1500")
+            .divider('-')
+            .total(dec!(1500))
+            .build();
+        let text = String::from_utf8_lossy(&bytes);
+        assert!(text.contains("1500"));
+    }
+}
+
+#[test]
+fn indented_rows_keep_the_right_edge() {
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+        .row("Boissons", "3000")
+        .indent(2)
+        .row("Bissap", "1000")
+        .indent(2)
+        .divider('-')
+        .dedent()
+        .centered("ok")
+        .dedent()
+        .row("Total", "3000")
+        .build();
+    let text = String::from_utf8(bytes).unwrap();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(
+        lines,
+        [
+            "Boissons                    3000",
+            "  Bissap                    1000",
+            "    ----------------------------",
+            "                ok",
+            "Total                       3000",
+        ]
+    );
+    assert!(lines[..3].iter().all(|l| l.chars().count() == 32));
+
+    let b = ReceiptBuilder::new(PrintWidth::Mm80).indent(4);
+    assert_eq!(b.effective_cols(), 44);
+    assert_eq!(b.double_size(true).effective_cols(), 20);
+}
+
+#[test]
+fn price_embedded_ean13_payload() {
+    let payload = |b: ReceiptBuilder| {
+        let bytes = b.build();
+        let start = bytes.windows(3).position(|w| w == [0x1D, b'k', 2]).unwrap() + 3;
+        String::from_utf8(bytes[start..start + 12].to_vec()).unwrap()
+    };
+
+    // Whole francs with the default money format
+    let fcfa = ReceiptBuilder::new(PrintWidth::Mm58)
+        .barcode_price_ean13("04217", dec!(1500))
+        .unwrap();
+    assert_eq!(payload(fcfa), "200421701500");
+
+    // Cents with two decimals; a 7-digit code keeps its own 2x prefix
+    let eur = ReceiptBuilder::new(PrintWidth::Mm58)
+        .money_format(MoneyFormat::new(2, None, ',', false))
+        .barcode_price_ean13("2312345", dec!(3.5))
+        .unwrap();
+    assert_eq!(payload(eur), "231234500350");
+
+    let err = |code: &str, price: Decimal| {
+        ReceiptBuilder::new(PrintWidth::Mm58)
+            .barcode_price_ean13(code, price)
+            .is_err()
+    };
+    assert!(err("1234", dec!(10)));
+    assert!(err("1234567", dec!(10)));
+    assert!(err("12a45", dec!(10)));
+    assert!(err("12345", dec!(100_000)));
+    assert!(err("12345", dec!(-5)));
+    assert!(err("12345", dec!(12.5)));
+}
+
+#[test]
+fn meta_rows_fit_the_line() {
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+        .meta(&[
+            ("Commande", "#1042"),
+            ("Mode de paiement", "Carte bancaire"),
+            ("Mode de paiement", "Carte bancaire VISA"),
+            ("Client", "Mamadou Diop, Parcelles Assainies"),
+        ])
+        .build();
+    let text = String::from_utf8(bytes).unwrap();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(
+        lines,
+        [
+            "Commande                   #1042",
+            "Mode de paiement  Carte bancaire",
+            "Mode de p... Carte bancaire VISA",
+            "Client",
+            "         Mamadou Diop, Parcelles",
+            "                       Assainies",
+        ]
+    );
+}
+
+#[test]
+fn domain_types_round_trip_through_serde() {
+    let taxes: Vec<TaxEntry> = serde_json::from_str(
+        r#"[{"label": "TVA 18%", "amount": "2700", "included": false},
+            {"label": "Timbre", "amount": 100, "included": true},
+            {"label": "Taxe", "amount": 1.5}]"#,
+    )
+    .unwrap();
+    assert_eq!(taxes[0].amount, dec!(2700));
+    assert!(taxes[1].included);
+    assert!(!taxes[2].included);
+    assert_eq!(taxes[2].amount, dec!(1.5));
+
+    let json = serde_json::to_string(&taxes[0]).unwrap();
+    let back: TaxEntry = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        (back.label, back.amount),
+        (taxes[0].label.clone(), taxes[0].amount)
+    );
+
+    assert_eq!(
+        serde_json::to_string(&PrintWidth::Mm58).unwrap(),
+        r#""58mm""#
+    );
+    assert_eq!(serde_json::to_string(&PrintWidth::A4).unwrap(), r#""a4""#);
+    let w: PrintWidth = serde_json::from_str(r#""80mm""#).unwrap();
+    assert_eq!(w, PrintWidth::Mm80);
+    assert_eq!(
+        serde_json::to_string(&Align::Center).unwrap(),
+        r#""center""#
+    );
+    let a: Align = serde_json::from_str(r#""right""#).unwrap();
+    assert_eq!(a, Align::Right);
+}
+
 #[test]
 fn width_cols() {
     assert_eq!(PrintWidth::Mm58.cols(), 32);
@@ -168,3 +682,1000 @@ fn multiple_taxes_additional_sum() {
     // Additional taxes total should be shown
     assert!(output.contains("additionnelles"));
 }
+
+#[test]
+fn logo_raw_centered_pads_to_page() {
+    // 8 px wide, 1 line, on an 80mm page (384 px = 48 bytes)
+    let raster = thermoprint::commands::raster_image(1, 1, &[0xFF]);
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+        .logo_raw_centered(&raster)
+        .build();
+
+    assert_eq!(bytes[4], 24); // 23 pad bytes + 1 image byte
+    assert_eq!(bytes[8 + 23], 0xFF);
+}
+
+#[test]
+fn tip_and_signature_lines() {
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+        .tip_line()
+        .signature_line()
+        .build();
+
+    let output = String::from_utf8_lossy(&bytes);
+    let tip = output.lines().next().unwrap();
+    assert!(tip.starts_with("POURBOIRE: ___"));
+    assert_eq!(tip.chars().count(), 32);
+    assert!(output.contains("SIGNATURE:\n\n"));
+    assert!(output.contains(&"_".repeat(32)));
+}
+
+#[test]
+fn auto_barcode_picks_symbology() {
+    let gs = 0x1Du8;
+
+    let ean = ReceiptBuilder::new(PrintWidth::Mm80)
+        .auto_barcode("123456789012")
+        .unwrap()
+        .build();
+    assert!(ean.windows(3).any(|w| w == [gs, b'k', 2]));
+
+    let code128 = ReceiptBuilder::new(PrintWidth::Mm80)
+        .auto_barcode("ORD-2024-001")
+        .unwrap()
+        .build();
+    assert!(code128.windows(3).any(|w| w == [gs, b'k', 73]));
+
+    assert!(ReceiptBuilder::new(PrintWidth::Mm80)
+        .auto_barcode("")
+        .is_err());
+    assert!(ReceiptBuilder::new(PrintWidth::Mm80)
+        .auto_barcode("BAD\tVALUE")
+        .is_err());
+}
+
+#[test]
+fn barcode_hri_setting_applies_to_barcodes() {
+    let gs = 0x1Du8;
+
+    let default = ReceiptBuilder::new(PrintWidth::Mm80)
+        .barcode_code128("A1")
+        .build();
+    assert!(default.windows(3).any(|w| w == [gs, b'H', 2]));
+    assert!(default.windows(3).any(|w| w == [gs, b'f', 0]));
+
+    let custom = ReceiptBuilder::new(PrintWidth::Mm80)
+        .barcode_hri(HriPosition::None, HriFont::B)
+        .barcode_code128("A1")
+        .barcode_ean13("123456789012")
+        .build();
+    assert_eq!(custom.windows(3).filter(|w| *w == [gs, b'H', 0]).count(), 2);
+    assert_eq!(custom.windows(3).filter(|w| *w == [gs, b'f', 1]).count(), 2);
+}
+
+#[test]
+fn paragraph_is_justified_to_width() {
+    let text =
+        "Les articles vendus ne sont ni repris ni echanges sans le ticket de caisse original.";
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+        .paragraph(text)
+        .build();
+
+    let output = String::from_utf8_lossy(&bytes);
+    let lines: Vec<&str> = output.lines().collect();
+    assert!(lines.len() > 1);
+    for line in &lines[..lines.len() - 1] {
+        assert_eq!(line.chars().count(), 32);
+    }
+}
+
+#[test]
+fn bar_width_is_proportional_in_quarter_columns() {
+    let bar = |value: i64, max: i64, width: usize| {
+        let b = ReceiptBuilder::new(PrintWidth::Mm58).bar(
+            Decimal::from(value),
+            Decimal::from(max),
+            width,
+        );
+        thermoprint::preview::preview(b.as_bytes(), PrintWidth::Mm58)
+    };
+    assert_eq!(bar(50, 100, 10), "█████\n");
+    assert_eq!(bar(1, 3, 12), "████\n");
+    assert_eq!(bar(33, 100, 10), "███░\n"); // 13.2 quarters → 13
+    assert_eq!(bar(7, 8, 2), "█▓\n");
+    assert_eq!(bar(150, 100, 8), "████████\n");
+    assert_eq!(bar(100, 100, 99), format!("{}\n", "█".repeat(32)));
+    assert_eq!(bar(0, 100, 10), "\n");
+    assert_eq!(bar(-5, 100, 10), "\n");
+    assert_eq!(bar(5, 0, 10), "\n");
+}
+
+#[test]
+fn rating_clamps_stars_and_width() {
+    let rating = |stars, out_of| {
+        ReceiptBuilder::new(PrintWidth::Mm58)
+            .rating(stars, out_of)
+            .build()
+    };
+    assert_eq!(rating(3, 5), b"***--\n");
+    assert_eq!(rating(9, 5), b"*****\n");
+    assert_eq!(rating(0, 0), b"\n");
+    assert_eq!(rating(40, 40).len(), 32 + 1);
+}
+
+#[test]
+fn content_width_narrows_layout_on_80mm() {
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+        .content_width(40)
+        .divider('-')
+        .row("Total", "5000")
+        .centered("Merci")
+        .build();
+    let text = String::from_utf8(bytes).unwrap();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines[0], "-".repeat(40));
+    assert_eq!(lines[1].len(), 40);
+    assert!(lines[1].ends_with("5000"));
+    assert_eq!(lines[2].trim_end(), format!("{}Merci", " ".repeat(17)));
+
+    // Wider than the paper clamps to it
+    let b = ReceiptBuilder::new(PrintWidth::Mm58).content_width(100);
+    assert_eq!(b.effective_cols(), 32);
+    let b = ReceiptBuilder::new(PrintWidth::Mm80)
+        .content_width(40)
+        .double_size(true);
+    assert_eq!(b.effective_cols(), 20);
+}
+
+#[test]
+fn paginate_splits_long_orders_across_cuts() {
+    let mut b = ReceiptBuilder::new(PrintWidth::Mm80);
+    for i in 0..80 {
+        b = b.text_line(&format!("Article {}", i));
+    }
+    let bytes = b
+        .logo_raw(&[0x1D, b'v', b'0', 0, 1, 0, 1, 0, 0x0A])
+        .cut()
+        .build();
+
+    let pages = thermoprint::paginate(&bytes, 30, "Suite...", LineEnding::Lf);
+    let cuts = pages.windows(2).filter(|w| w == b"\x1DV").count();
+    assert_eq!(cuts, 3); // breaks after lines 30 and 60, plus the final cut
+    let text = thermoprint::preview::preview(&pages, PrintWidth::Mm80);
+    let page_break = format!("Suite...\n{:-^48}\nSuite...\n", " CUT ");
+    assert!(text.contains(&format!("Article 29\n{}Article 30\n", page_break)));
+    assert!(text.contains(&format!("Article 59\n{}Article 60\n", page_break)));
+    assert_eq!(text.matches("Suite...").count(), 4);
+
+    // Shorter receipts, and the LF byte inside the raster, stay untouched
+    assert_eq!(
+        thermoprint::paginate(&bytes, 81, "Suite...", LineEnding::Lf),
+        bytes
+    );
+    assert_eq!(
+        thermoprint::paginate(&bytes, 0, "Suite...", LineEnding::Lf),
+        bytes
+    );
+}
+
+#[test]
+fn paginate_counts_carriage_returns_as_configured() {
+    let mut b = ReceiptBuilder::new(PrintWidth::Mm80).line_ending(LineEnding::Cr);
+    for i in 0..5 {
+        b = b.text_line(&format!("Article {}", i));
+    }
+    let bytes = b.build();
+
+    let pages = thermoprint::paginate(&bytes, 2, "Suite...", LineEnding::Cr);
+    assert_eq!(pages.windows(2).filter(|w| w == b"\x1DV").count(), 2);
+    let page_break = b"Article 1\rSuite...\r\x1DVB\0Suite...\rArticle 2\r";
+    assert!(pages.windows(page_break.len()).any(|w| w == page_break));
+    assert!(!pages.contains(&b'\n'));
+
+    // Counted as LF, the same stream has no lines to split
+    assert_eq!(
+        thermoprint::paginate(&bytes, 2, "Suite...", LineEnding::Lf),
+        bytes
+    );
+}
+
+#[cfg(feature = "native")]
+#[test]
+fn logo_defaults_to_single_density_columns_on_58mm() {
+    let path = std::env::temp_dir().join(format!("thermoprint-logo-{}.png", std::process::id()));
+    image::GrayImage::from_pixel(16, 4, image::Luma([0]))
+        .save(&path)
+        .unwrap();
+    let path = path.to_str().unwrap();
+
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+        .logo(path)
+        .unwrap()
+        .build();
+    assert!(bytes.windows(3).any(|w| w == b"\x1B*\x20")); // ESC * 32
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+        .logo(path)
+        .unwrap()
+        .build();
+    assert!(bytes.starts_with(b"\x1Dv0"));
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+        .raster_strategy(thermoprint::RasterMode::GsV0)
+        .logo(path)
+        .unwrap()
+        .build();
+    assert!(bytes.starts_with(b"\x1Dv0"));
+    std::fs::remove_file(path).unwrap();
+}
+
+#[cfg(feature = "shift-jis")]
+#[test]
+fn shift_jis_header_uses_kanji_mode() {
+    use thermoprint::encoding::MultibyteEncoding;
+
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+        .init()
+        .multibyte(MultibyteEncoding::ShiftJis)
+        .text_line("MISE ミセ")
+        .build();
+
+    let fs = 0x1Cu8;
+    assert!(bytes.windows(3).any(|w| w == [fs, b'C', 1]));
+    assert!(bytes
+        .windows(8)
+        .any(|w| w == [fs, b'&', 0x83, 0x7E, 0x83, 0x5A, fs, b'.']));
+}
+
+#[test]
+fn merged_items_print_once() {
+    let scans = [
+        ItemInput::new("Coca", 1, dec!(500), None),
+        ItemInput::new("Coca", 1, dec!(500), None),
+    ];
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+        .init()
+        .items(merge_items(&scans))
+        .build();
+    let s = String::from_utf8_lossy(&bytes);
+    assert_eq!(s.matches("Coca").count(), 1);
+    assert!(s.contains("2 x 500"));
+}
+
+#[test]
+fn ean8_validates_length() {
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+        .barcode_ean8("9638507")
+        .unwrap()
+        .build();
+    assert!(bytes.windows(3).any(|w| w == [0x1D, b'k', 3]));
+
+    for bad in ["963850", "96385074", "96385O7"] {
+        assert!(ReceiptBuilder::new(PrintWidth::Mm58)
+            .barcode_ean8(bad)
+            .is_err());
+    }
+}
+
+#[test]
+fn money_format_preserves_cents() {
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+        .currency("€")
+        .money_format(MoneyFormat::new(2, None, ',', false))
+        .item("Casque", 1, dec!(149.99), None)
+        .build();
+    let s = String::from_utf8_lossy(&bytes);
+    assert!(s.contains("149,99"));
+    assert!(!s.contains("150"));
+}
+
+#[test]
+fn kitchen_ticket_layout() {
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+        .init()
+        .order_header("12", "1042", "14:30")
+        .kitchen_item("Thieboudienne", 2, &["sans piment", "riz a part"])
+        .cut()
+        .build();
+    let s = String::from_utf8_lossy(&bytes);
+    assert!(s.contains("TABLE 12"));
+    assert!(s.contains("COMMANDE #1042"));
+    assert!(s.contains("2 x Thieboudienne"));
+    assert!(s.contains("   - sans piment"));
+    assert!(!s.contains("FCFA"));
+}
+
+#[test]
+fn repeat_prints_copies_with_one_cut_each() {
+    let cuts = |bytes: &[u8]| bytes.windows(2).filter(|w| w == &[0x1D, b'V']).count();
+
+    let once = ReceiptBuilder::new(PrintWidth::Mm58)
+        .text_line("TOTAL 5000")
+        .cut()
+        .build();
+    let twice = ReceiptBuilder::new(PrintWidth::Mm58)
+        .text_line("TOTAL 5000")
+        .cut()
+        .repeat(2)
+        .build();
+    assert_eq!(twice, [once.clone(), once.clone()].concat());
+    assert_eq!(cuts(&twice), 2);
+
+    // A drawer kick or style reset after the cut prints nothing, so no
+    // second cut is added
+    let kicked = ReceiptBuilder::new(PrintWidth::Mm58)
+        .text_line("TOTAL 5000")
+        .cut()
+        .open_cash_drawer()
+        .bold(false)
+        .repeat(2)
+        .build();
+    assert_eq!(cuts(&kicked), 2);
+    assert!(kicked.ends_with(&[0x1B, b'E', 0]));
+
+    // Text after the cut does need one
+    let trailing = ReceiptBuilder::new(PrintWidth::Mm58)
+        .cut()
+        .text_line("Merci")
+        .repeat(2)
+        .build();
+    assert_eq!(cuts(&trailing), 3);
+
+    // No trailing cut: one is inserted between the copies only
+    let uncut = thermoprint::repeat_receipt(b"TOTAL 5000\n", 2, true);
+    assert_eq!(cuts(&uncut), 1);
+    assert!(uncut.ends_with(b"TOTAL 5000\n"));
+    assert_eq!(thermoprint::repeat_receipt(b"A\n", 3, false), b"A\nA\nA\n");
+}
+
+#[test]
+fn reset_bytes_restores_defaults_without_printing() {
+    assert_eq!(
+        thermoprint::reset_bytes(),
+        [0x1B, b'@', 0x1B, b't', 19, 0x1B, b'!', 0, 0x1B, b'E', 0, 0x1B, b'a', 0]
+    );
+}
+
+#[test]
+fn cut_feed_applies_to_every_cut() {
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+        .cut_feed(2)
+        .text_line("A")
+        .cut()
+        .text_line("B")
+        .cut_full()
+        .build();
+    assert_eq!(bytes, b"A\n\n\n\x1DVB\0B\n\n\n\x1DV\0");
+
+    // Default: no extra feed; explicit feeds are left alone
+    let plain = ReceiptBuilder::new(PrintWidth::Mm58).cut().build();
+    assert_eq!(plain, [0x1D, b'V', 66, 0]);
+    let explicit = ReceiptBuilder::new(PrintWidth::Mm58)
+        .cut_feed(2)
+        .feed_and_cut(3)
+        .build();
+    assert_eq!(explicit, [0x1B, b'd', 3, 0x1D, b'V', 66, 0]);
+}
+
+#[test]
+fn section_centres_title_in_rule() {
+    let line = |title: &str| {
+        let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+            .section(title, '-')
+            .build();
+        String::from_utf8(bytes).unwrap().trim_end().to_owned()
+    };
+
+    assert_eq!(
+        line("PAIEMENT"),
+        format!("{0} PAIEMENT {0}", "-".repeat(11))
+    );
+    // Odd fill: the extra character goes right
+    assert_eq!(
+        line("TOTAL"),
+        format!("{} TOTAL {}", "-".repeat(12), "-".repeat(13))
+    );
+    assert_eq!(line("TOTAL").chars().count(), 32);
+
+    let long = "CONDITIONS GENERALES DE VENTE ET RETOURS";
+    assert_eq!(line(long), long);
+}
+
+#[test]
+fn column_divider_is_exactly_one_line_wide() {
+    let line = |width: PrintWidth, widths: &[usize]| {
+        let bytes = ReceiptBuilder::new(width)
+            .column_divider(widths, '-', '+')
+            .build();
+        String::from_utf8(bytes).unwrap().trim_end().to_owned()
+    };
+
+    // 10 + 1 + 19 + 1 + 17 = 48
+    let exact = line(PrintWidth::Mm80, &[10, 19, 17]);
+    assert_eq!(
+        exact,
+        format!("{}+{}+{}", "-".repeat(10), "-".repeat(19), "-".repeat(17))
+    );
+    assert_eq!(exact.chars().count(), 48);
+
+    // Short: the last segment stretches; too wide: cut at the paper edge
+    assert_eq!(
+        line(PrintWidth::Mm58, &[4, 6]),
+        format!("----+{}", "-".repeat(27))
+    );
+    let wide = line(PrintWidth::Mm58, &[20, 20]);
+    assert_eq!(wide, format!("{}+{}", "-".repeat(20), "-".repeat(11)));
+    assert_eq!(line(PrintWidth::Mm58, &[]), "-".repeat(32));
+
+    // Double size halves the line
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+        .double_size(true)
+        .column_divider(&[7, 8], '=', '|')
+        .build();
+    assert!(String::from_utf8_lossy(&bytes).contains("=======|========\n"));
+}
+
+#[test]
+fn double_size_layout_uses_half_the_columns() {
+    let text = |b: ReceiptBuilder| String::from_utf8_lossy(&b.build()).into_owned();
+
+    let b = ReceiptBuilder::new(PrintWidth::Mm58).double_size(true);
+    assert_eq!(b.effective_cols(), 16);
+
+    // 16 double-width columns: "MA BOUTIQUE" gets 2 leading spaces, not 10
+    let header = text(b.centered("MA BOUTIQUE"));
+    assert!(header.contains("\n") && header.contains("  MA BOUTIQUE"));
+    assert!(!header.contains("   MA BOUTIQUE"));
+
+    let row = text(
+        ReceiptBuilder::new(PrintWidth::Mm58)
+            .double_size(true)
+            .row("TOTAL", "500"),
+    );
+    let line = row.split('\n').next().unwrap();
+    assert!(line.ends_with("TOTAL        500"));
+
+    let reset = ReceiptBuilder::new(PrintWidth::Mm58)
+        .double_size(true)
+        .double_height(true);
+    assert_eq!(reset.effective_cols(), 32);
+}
+
+#[test]
+fn divider_width_follows_font() {
+    let count = |bytes: Vec<u8>| bytes.iter().filter(|&&b| b == b'-').count();
+
+    // Font B fits 42 characters on 58 mm paper instead of 32
+    let font_b = ReceiptBuilder::new(PrintWidth::Mm58)
+        .divider_width('-', 42)
+        .build();
+    assert_eq!(count(font_b), 42);
+
+    let doubled = ReceiptBuilder::new(PrintWidth::Mm58)
+        .double_size(true)
+        .divider('-')
+        .build();
+    assert_eq!(count(doubled), 16);
+
+    let reset = ReceiptBuilder::new(PrintWidth::Mm58)
+        .double_size(true)
+        .normal_size()
+        .divider('-')
+        .build();
+    assert_eq!(count(reset), 32);
+
+    // Box-drawing rules are sent in CP858, not as UTF-8, and control
+    // characters cannot slip through as commands
+    let boxed = ReceiptBuilder::new(PrintWidth::Mm58)
+        .divider_width('─', 4)
+        .divider_width('\x1B', 2)
+        .build();
+    assert_eq!(boxed, b"\xC4\xC4\xC4\xC4\n  \n");
+}
+
+#[test]
+fn datetime_line_is_localized() {
+    let fr = ReceiptBuilder::new(PrintWidth::Mm80)
+        .datetime_str("12/06/2024 14:30")
+        .build();
+    assert!(String::from_utf8_lossy(&fr).contains("Date: 12/06/2024 14:30"));
+
+    let es = ReceiptBuilder::new(PrintWidth::Mm80)
+        .language(Language::Es)
+        .datetime_str("12/06/2024 14:30")
+        .build();
+    assert!(String::from_utf8_lossy(&es).contains("Fecha: 12/06/2024 14:30"));
+}
+
+#[test]
+fn weighted_item_keeps_quantity_precision() {
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+        .item_weighted("Tomates", dec!(1.250), "kg", dec!(3500), None)
+        .build();
+    let s = String::from_utf8_lossy(&bytes);
+    assert!(s.contains("1.250 kg x 3500 FCFA"));
+    assert!(s.contains("4375 FCFA")); // 1.250 × 3500
+}
+
+#[test]
+fn init_once_sends_single_reset() {
+    let resets = |bytes: Vec<u8>| bytes.windows(2).filter(|w| *w == [0x1B, b'@']).count();
+    assert_eq!(
+        resets(ReceiptBuilder::new(PrintWidth::Mm80).init().build()),
+        2
+    );
+    assert_eq!(
+        resets(ReceiptBuilder::new(PrintWidth::Mm80).init_once().build()),
+        1
+    );
+    assert_eq!(
+        resets(ReceiptBuilder::new(PrintWidth::Mm80).init_with(3).build()),
+        3
+    );
+}
+
+#[test]
+fn items_iterator_matches_chained_calls() {
+    let order: Vec<ItemInput> = serde_json::from_str(
+        r#"[
+            { "name": "Polo", "qty": 2, "unit_price": "15000" },
+            { "name": "Jean", "qty": 1, "unit_price": "25000", "discount": "2000" },
+            { "name": "Casquette", "qty": 3, "unit_price": "3500" }
+        ]"#,
+    )
+    .unwrap();
+
+    let chained = ReceiptBuilder::new(PrintWidth::Mm80)
+        .item("Polo", 2, dec!(15000), None)
+        .item("Jean", 1, dec!(25000), Some(dec!(2000)))
+        .item("Casquette", 3, dec!(3500), None)
+        .build();
+    let iterated = ReceiptBuilder::new(PrintWidth::Mm80).items(order).build();
+    assert_eq!(iterated, chained);
+}
+
+#[test]
+fn feed_and_cut_variants() {
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+        .feed_and_cut(4)
+        .build();
+    assert_eq!(bytes, vec![0x1B, b'd', 4, 0x1D, b'V', 66, 0]);
+
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+        .cut_with_feed(60)
+        .build();
+    assert_eq!(bytes, vec![0x1D, b'V', 66, 60]);
+}
+
+#[test]
+fn test_page_shows_ruler_and_codes() {
+    let bytes = ReceiptBuilder::test_page(PrintWidth::Mm58).build();
+    let s = String::from_utf8_lossy(&bytes);
+    assert!(s.contains("Paper: 58mm / 32 columns"));
+    assert!(s.contains("....+....1....+....2....+....3.."));
+    assert!(bytes.windows(3).any(|w| w == [0x1D, b'k', 73])); // CODE128
+    assert!(bytes.windows(3).any(|w| w == [0x1D, b'(', b'k'])); // QR
+    assert!(bytes.ends_with(&[0x1D, b'V', 66, 0]));
+}
+
+#[test]
+fn auto_vat_summary_groups_by_rate() {
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+        .item_vat("Polo", 2, dec!(10000), None, dec!(18))
+        .item_vat("Pain", 4, dec!(250), None, dec!(10))
+        .item_vat("Jean", 1, dec!(25000), Some(dec!(5000)), dec!(18.0))
+        .auto_vat_summary()
+        .build();
+    let s = String::from_utf8_lossy(&bytes);
+    // 18% base = 20000 + 20000, one row even though the rate was given as 18.0
+    assert_eq!(s.matches("TVA 18%").count(), 1);
+    assert!(s.contains("40000 FCFA"));
+    assert!(s.contains("7200 FCFA"));
+    assert!(s.contains("TVA 10%"));
+    assert!(s.contains("100 FCFA"));
+    assert!(s.contains("7300 FCFA"));
+    assert!(s.find("TVA 10%").unwrap() < s.find("TVA 18%").unwrap());
+}
+
+#[test]
+fn auto_vat_summary_rounds_once_per_rate() {
+    // VAT is taken on the summed base (99.99 * 18% = 17.9982 → 18.00),
+    // not on each rounded line.
+    let mut b =
+        ReceiptBuilder::new(PrintWidth::Mm80).money_format(MoneyFormat::new(2, None, '.', false));
+    for _ in 0..3 {
+        b = b.item_vat("Stylo", 1, dec!(33.33), None, dec!(18));
+    }
+    let s = String::from_utf8_lossy(&b.auto_vat_summary().build()).into_owned();
+    assert!(s.contains("99.99 FCFA"));
+    assert!(s.contains("18.00 FCFA"));
+
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+        .item_vat("Cahier", 1, dec!(333.33), None, dec!(18))
+        .auto_vat_summary()
+        .build();
+    let s = String::from_utf8_lossy(&bytes);
+    assert!(s.contains("60 FCFA")); // 59.9994 rounded to units
+}
+
+#[test]
+fn items_with_vat_rate_feed_summary() {
+    let order = vec![
+        ItemInput::new("Polo", 1, dec!(10000), None).with_vat(dec!(18)),
+        ItemInput::new("Pain", 1, dec!(500), None),
+    ];
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+        .items(&order)
+        .auto_vat_summary()
+        .build();
+    let s = String::from_utf8_lossy(&bytes);
+    assert!(s.contains("1800 FCFA"));
+    assert_eq!(s.matches("Base HT").count(), 1);
+}
+
+#[test]
+fn auto_vat_summary_empty_without_rates() {
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+        .item("Pain", 1, dec!(500), None)
+        .auto_vat_summary()
+        .build();
+    let plain = ReceiptBuilder::new(PrintWidth::Mm80)
+        .item("Pain", 1, dec!(500), None)
+        .build();
+    assert_eq!(bytes, plain);
+}
+
+#[test]
+fn cut_full_with_feed_emits_feed_byte() {
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+        .cut_full_with_feed(32)
+        .build();
+    assert_eq!(bytes, vec![0x1D, b'V', 65, 32]);
+}
+
+#[test]
+fn tokenize_total_line_without_lossy_utf8() {
+    use thermoprint::debug::{tokenize, Token};
+
+    let b = ReceiptBuilder::new(PrintWidth::Mm58).item("Café crème", 2, dec!(1500), None);
+    let tokens = tokenize(b.as_bytes());
+    let texts: Vec<&str> = tokens.iter().filter_map(Token::text).collect();
+    assert!(texts.iter().any(|t| t.starts_with("Café crème")));
+    assert!(texts.iter().any(|t| t.contains("3000 FCFA")));
+
+    let bytes = b.cut().build();
+    assert_eq!(
+        tokenize(&bytes).last(),
+        Some(&Token::command("GS V", &[66, 0]))
+    );
+}
+
+#[test]
+fn control_characters_in_text_cannot_inject_commands() {
+    use thermoprint::debug::{tokenize, Token};
+
+    // An ESC @ and a line feed smuggled into a product name
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+        .item("Pain\x1B@\n\x1D!\x11", 1, dec!(500), None)
+        .bold(true)
+        .text_line("TOTAL")
+        .build();
+    let tokens = tokenize(&bytes);
+    assert!(tokens
+        .iter()
+        .all(|t| !matches!(t, Token::Command { name, .. } if name != "ESC E")));
+    assert!(tokens.contains(&Token::Text("Pain @  ! ".to_owned())));
+    assert!(tokens.ends_with(&[
+        Token::command("ESC E", &[1]),
+        Token::Text("TOTAL".to_owned()),
+        Token::LineFeed,
+    ]));
+}
+
+#[test]
+fn rtl_direction_mirrors_layout() {
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+        .language(Language::Ar)
+        .direction(Direction::Rtl)
+        .init()
+        .item("Khubz", 2, dec!(250), None)
+        .total(dec!(500))
+        .thank_you("DUKKAN")
+        .build();
+    let s = String::from_utf8_lossy(&bytes);
+    // Start alignment is the right edge, restored after centred sections
+    assert!(bytes.starts_with(&[0x1B, b'a', 2]));
+    assert!(bytes.ends_with(&[0x1B, b'a', 2]));
+    let total = Language::Ar.labels().total;
+    let row = s.lines().find(|l| l.contains(total)).unwrap();
+    assert!(row.find("500 FCFA").unwrap() < row.find(total).unwrap());
+    assert!(row.ends_with(total));
+
+    let ltr = ReceiptBuilder::new(PrintWidth::Mm58)
+        .total(dec!(500))
+        .build();
+    let explicit_ltr = ReceiptBuilder::new(PrintWidth::Mm58)
+        .direction(Direction::Ltr)
+        .total(dec!(500))
+        .build();
+    assert_eq!(explicit_ltr, ltr);
+
+    // Re-stating the direction keeps an alignment the caller already set
+    let centred = ReceiptBuilder::new(PrintWidth::Mm58)
+        .align_center()
+        .direction(Direction::Ltr)
+        .build();
+    assert_eq!(centred, b"\x1Ba\x01");
+    let back = ReceiptBuilder::new(PrintWidth::Mm58)
+        .direction(Direction::Rtl)
+        .direction(Direction::Ltr)
+        .build();
+    assert_eq!(back, b"\x1Ba\x02\x1Ba\x00");
+}
+
+#[test]
+fn sub_item_value_aligns_to_right_edge() {
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+        .sub_item("Extra cheese", Some(dec!(500)))
+        .sub_item("Sans frites", Some(dec!(-300)))
+        .sub_item(
+            "A very long modifier name that cannot fit",
+            Some(dec!(1000)),
+        )
+        .build();
+    let s = String::from_utf8_lossy(&bytes);
+    let lines: Vec<&str> = s.lines().collect();
+    assert_eq!(lines.len(), 3);
+    for line in &lines {
+        assert_eq!(line.chars().count(), 32);
+        assert!(line.starts_with("  + "));
+    }
+    assert!(lines[0].ends_with("+500 FCFA"));
+    assert!(lines[1].ends_with(" -300 FCFA"));
+    assert!(lines[2].contains("..."));
+    assert!(lines[2].ends_with("+1000 FCFA"));
+}
+
+#[test]
+fn code_line_feed_can_be_disabled() {
+    let with_lf = ReceiptBuilder::new(PrintWidth::Mm80)
+        .qr_code("https://example.com", 4)
+        .barcode_code128("ORD-1")
+        .build();
+    let without_lf = ReceiptBuilder::new(PrintWidth::Mm80)
+        .code_line_feed(false)
+        .qr_code("https://example.com", 4)
+        .barcode_code128("ORD-1")
+        .build();
+    // Default stays unchanged: one LF after each code
+    assert_eq!(with_lf.len(), without_lf.len() + 2);
+    assert_eq!(with_lf.last(), Some(&b'\n'));
+    assert_ne!(without_lf.last(), Some(&b'\n'));
+
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+        .code_line_feed(false)
+        .qr_with_caption("https://example.com", 4, "Scan me")
+        .build();
+    let qr_end = bytes
+        .windows(3)
+        .rposition(|w| w == [0x1D, b'(', b'k'])
+        .unwrap()
+        + 8;
+    assert_eq!(&bytes[qr_end..qr_end + 7], b"Scan me");
+}
+
+#[test]
+fn star_dialect_routes_cut_and_codes() {
+    let epson = ReceiptBuilder::new(PrintWidth::Mm80)
+        .text_line("Hello")
+        .barcode_code128("ORD-1")
+        .cut()
+        .build();
+    let star = ReceiptBuilder::new(PrintWidth::Mm80)
+        .dialect(Dialect::Star)
+        .text_line("Hello")
+        .barcode_code128("ORD-1")
+        .cut()
+        .build();
+    // Default stays Epson
+    assert!(epson.ends_with(&[0x1D, b'V', 66, 0]));
+    assert!(epson.windows(3).any(|w| w == [0x1D, b'k', 73]));
+
+    assert!(star.starts_with(b"Hello\n"));
+    assert!(star.ends_with(&[0x1B, b'd', 3]));
+    assert!(!star.windows(2).any(|w| w == [0x1D, b'k']));
+    let mut code = vec![0x1B, b'b', b'6', b'4', b'1', 60];
+    code.extend_from_slice(b"ORD-1\x1E\n");
+    assert!(star.windows(code.len()).any(|w| w == code.as_slice()));
+}
+
+#[test]
+fn star_dialect_routes_bold_and_alignment() {
+    let star = ReceiptBuilder::new(PrintWidth::Mm80)
+        .dialect(Dialect::Star)
+        .align_center()
+        .bold(true)
+        .text_line("TOTAL")
+        .bold(false)
+        .align_right()
+        .build();
+    assert_eq!(
+        star,
+        b"\x1B\x1Da\x01\x1BETOTAL\n\x1BF\x1B\x1Da\x02".to_vec()
+    );
+}
+
+#[test]
+fn star_dialect_feeds_without_cutting() {
+    let star = || ReceiptBuilder::new(PrintWidth::Mm80).dialect(Dialect::Star);
+    // `ESC d 3` would be a partial cut on Star
+    assert_eq!(star().feed(3).build(), b"\x1Ba\x03");
+    assert_eq!(star().feed_and_cut(3).build(), b"\x1Ba\x03\x1Bd\x03");
+}
+
+#[test]
+fn qr_code_checked_rejects_overlong_data() {
+    let long = "x".repeat(thermoprint::commands::QR_MAX_BYTES + 1);
+    let err = ReceiptBuilder::new(PrintWidth::Mm80)
+        .qr_code_checked(&long, 4)
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains("exceeds the QR capacity"));
+    assert!(ReceiptBuilder::new(PrintWidth::Mm80)
+        .qr_code_checked("", 4)
+        .is_err());
+
+    let max = "x".repeat(thermoprint::commands::QR_MAX_BYTES);
+    let checked = ReceiptBuilder::new(PrintWidth::Mm80)
+        .qr_code_checked(&max, 4)
+        .unwrap()
+        .build();
+    let plain = ReceiptBuilder::new(PrintWidth::Mm80)
+        .qr_code(&max, 4)
+        .build();
+    assert_eq!(checked, plain);
+}
+
+#[test]
+fn order_reference_prints_text_and_barcode() {
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+        .order_reference("2024001", true)
+        .unwrap()
+        .build();
+    let s = String::from_utf8_lossy(&bytes);
+    assert!(bytes.starts_with(&[0x1B, b'a', 1]));
+    assert!(s.contains("20240016\n"));
+    let code = thermoprint::commands::barcode_code128("20240016");
+    assert!(bytes.windows(code.len()).any(|w| w == code.as_slice()));
+    assert!(bytes.ends_with(&[0x1B, b'a', 0]));
+
+    let plain = ReceiptBuilder::new(PrintWidth::Mm80)
+        .order_reference("ORD-42", false)
+        .unwrap()
+        .build();
+    assert!(String::from_utf8_lossy(&plain).contains("ORD-42\n"));
+
+    assert!(ReceiptBuilder::new(PrintWidth::Mm80)
+        .order_reference("ORD-42", true)
+        .is_err());
+    assert!(ReceiptBuilder::new(PrintWidth::Mm80)
+        .order_reference("", false)
+        .is_err());
+}
+
+#[test]
+fn compact_items_fill_one_line_exactly() {
+    let lines = |b: ReceiptBuilder| -> Vec<String> {
+        String::from_utf8_lossy(&b.build())
+            .lines()
+            .map(str::to_owned)
+            .collect()
+    };
+
+    for width in [PrintWidth::Mm58, PrintWidth::Mm80] {
+        let out = lines(
+            ReceiptBuilder::new(width)
+                .item_compact("Polo shirt", 2, dec!(15000), None)
+                .item_compact(
+                    "Jean Levis 501 coupe droite bleu delave",
+                    1,
+                    dec!(25000),
+                    Some(dec!(2000)),
+                ),
+        );
+        assert_eq!(out.len(), 2);
+        assert!(out[0].starts_with("Polo shirt ") && out[0].ends_with(" 2x15000=30000"));
+        assert!(out[1].ends_with("... 1x25000-2000=23000"));
+        for line in &out {
+            assert_eq!(line.chars().count(), width.cols());
+        }
+    }
+
+    // item() follows the builder-level style
+    let styled = lines(
+        ReceiptBuilder::new(PrintWidth::Mm58)
+            .item_style(ItemStyle::Compact)
+            .item("Polo shirt", 2, dec!(15000), None),
+    );
+    assert_eq!(styled.len(), 1);
+    assert_eq!(styled[0].chars().count(), 32);
+
+    // No room for the name beside the amounts: it moves to its own line
+    let narrow = lines(
+        ReceiptBuilder::new(PrintWidth::Mm58)
+            .money_format(MoneyFormat::new(2, Some(' '), ',', false))
+            .item_compact("Sac de riz", 1000, dec!(12500.50), None),
+    );
+    assert_eq!(narrow.len(), 2);
+    assert_eq!(narrow[0], "Sac de riz");
+    assert_eq!(narrow[1].chars().count(), 32);
+}
+
+#[test]
+fn two_column_items_pairs_items_per_row() {
+    let menu = [
+        ItemInput::new("Thieboudienne au poisson frais", 1, dec!(3500), None),
+        ItemInput::new("Yassa poulet", 1, dec!(3000), None),
+        ItemInput::new("Bissap", 2, dec!(500), None),
+        ItemInput::new("Mafe", 1, dec!(2500), Some(dec!(500))),
+    ];
+    let bytes = ReceiptBuilder::new(PrintWidth::A4)
+        .two_column_items(&menu)
+        .build();
+    let text = String::from_utf8_lossy(&bytes);
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines.len(), 2);
+    for line in &lines {
+        assert_eq!(line.chars().count(), 90);
+    }
+    // 44-column cells with a 2-space gutter
+    assert!(lines[0].starts_with("Thieboudienne au poisson frais"));
+    assert_eq!(&lines[0][44..46], "  ");
+    assert!(lines[0].ends_with("3000 FCFA"));
+    assert!(lines[1].starts_with("2x Bissap"));
+    assert!(lines[1].ends_with("Mafe                               2000 FCFA"));
+
+    // Odd count: the last row has a single cell
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+        .two_column_items(&menu[..3])
+        .build();
+    let text = String::from_utf8_lossy(&bytes);
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[1].chars().count(), 23);
+    assert!(lines[0].starts_with("Thieboudie... 3500 FCFA"));
+}
+
+#[test]
+fn labeled_barcode_groups_label_and_code() {
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm58)
+        .labeled_barcode("RIZ 5KG", "590123412345", Symbology::Ean13)
+        .unwrap()
+        .build();
+    assert!(bytes.starts_with(b"\x1Ba\x01RIZ 5KG\n"));
+    let code = thermoprint::commands::barcode_ean13("590123412345");
+    assert!(bytes.windows(code.len()).any(|w| w == code.as_slice()));
+    assert!(bytes.ends_with(&[0x1B, b'a', 0]));
+
+    let invalid = [
+        ("59012341234", Symbology::Ean13),
+        ("59012341234X", Symbology::Ean13),
+        ("12345678", Symbology::Ean8),
+        ("", Symbology::Code128),
+    ];
+    for (value, symbology) in invalid {
+        assert!(ReceiptBuilder::new(PrintWidth::Mm58)
+            .labeled_barcode("X", value, symbology)
+            .is_err());
+    }
+}
+
+#[test]
+fn qr_code_bytes_stores_exact_payload() {
+    let payload = [0x00u8, 0x30, 0x02, 0xFF, 0x00];
+    let bytes = ReceiptBuilder::new(PrintWidth::Mm80)
+        .qr_code_bytes(&payload, 5)
+        .build();
+    let mut store = vec![0x1D, b'(', b'k', 8, 0, 49, 80, 48];
+    store.extend_from_slice(&payload);
+    assert!(bytes.starts_with(&store));
+    assert!(bytes.ends_with(&[0x1D, b'(', b'k', 3, 0, 49, 81, 48, b'\n']));
+}
@@ -0,0 +1,143 @@
+//! Golden-file tests: render known receipts and compare the exact bytes with
+//! the fixtures in `tests/snapshots/`, so any change to a command sequence
+//! shows up as a failing test.
+//!
+//! After an intended output change, regenerate the fixtures and review the
+//! diff before committing:
+//!
+//! ```sh
+//! THERMOPRINT_UPDATE_SNAPSHOTS=1 cargo test --test snapshots
+//! ```
+
+use std::path::PathBuf;
+
+use rust_decimal::prelude::*;
+use thermoprint::debug::tokenize;
+use thermoprint::{Language, PrintWidth, ReceiptBuilder, Symbology, TaxEntry};
+
+/// Compare `bytes` with `tests/snapshots/<name>.bin`, or overwrite the
+/// fixture when `THERMOPRINT_UPDATE_SNAPSHOTS` is set.
+fn assert_snapshot(name: &str, bytes: &[u8]) {
+    let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "tests", "snapshots"]
+        .iter()
+        .collect::<PathBuf>()
+        .join(format!("{}.bin", name));
+
+    if std::env::var_os("THERMOPRINT_UPDATE_SNAPSHOTS").is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, bytes).unwrap();
+        return;
+    }
+
+    let expected = std::fs::read(&path).unwrap_or_else(|e| {
+        panic!(
+            "snapshot {} unreadable ({}); run with THERMOPRINT_UPDATE_SNAPSHOTS=1 to create it",
+            path.display(),
+            e
+        )
+    });
+    if expected == bytes {
+        return;
+    }
+
+    // Point at the first differing token rather than dumping both streams
+    let (want, got) = (tokenize(&expected), tokenize(bytes));
+    let i = want
+        .iter()
+        .zip(&got)
+        .position(|(w, g)| w != g)
+        .unwrap_or(want.len().min(got.len()));
+    panic!(
+        "snapshot {} differs at token {}:\n  expected: {:?}\n       got: {:?}\n\
+         ({} expected bytes, {} actual); rerun with THERMOPRINT_UPDATE_SNAPSHOTS=1 if intended",
+        name,
+        i,
+        want.get(i),
+        got.get(i),
+        expected.len(),
+        bytes.len()
+    );
+}
+
+fn sale(width: PrintWidth, language: Language) -> ReceiptBuilder {
+    ReceiptBuilder::new(width)
+        .init()
+        .language(language)
+        .shop_header("Boutique Test", "+221 77 000 00 00", "Dakar, Sénégal")
+        .divider('=')
+        .item("Polo Ralph Lauren", 2, dec!(15_000), None)
+        .item("Jean Levis 501", 1, dec!(25_000), Some(dec!(2_000)))
+        .divider('-')
+        .subtotal_ht(dec!(53_000))
+        .taxes(&[TaxEntry::new("TVA 18%", dec!(9_540), false)])
+        .total(dec!(62_540))
+        .received(dec!(70_000))
+        .change(dec!(7_460))
+}
+
+#[test]
+fn full_80mm_receipt() {
+    let bytes = sale(PrintWidth::Mm80, Language::Fr)
+        .total_in_words(dec!(62_540))
+        .served_by("Awa")
+        .divider('=')
+        .barcode_code128("ORD-2024-001")
+        .qr_code("https://example.com/r/2024-001", 6)
+        .thank_you("Boutique Test")
+        .feed(3)
+        .cut()
+        .build();
+    assert_snapshot("full_80mm", &bytes);
+}
+
+#[test]
+fn each_language() {
+    for (language, name) in [
+        (Language::Fr, "fr"),
+        (Language::En, "en"),
+        (Language::Es, "es"),
+        (Language::Pt, "pt"),
+        (Language::Ar, "ar"),
+        (Language::Wo, "wo"),
+    ] {
+        let bytes = sale(PrintWidth::Mm58, language)
+            .total_in_words(dec!(62_540))
+            .served_by("Awa")
+            .thank_you("Boutique Test")
+            .cut()
+            .build();
+        assert_snapshot(&format!("language_{}", name), &bytes);
+    }
+}
+
+#[test]
+fn barcodes() {
+    let code128 = ReceiptBuilder::new(PrintWidth::Mm80)
+        .barcode_code128("ORD-2024-001")
+        .build();
+    assert_snapshot("barcode_code128", &code128);
+
+    let ean13 = ReceiptBuilder::new(PrintWidth::Mm80)
+        .barcode_ean13("590123412345")
+        .build();
+    assert_snapshot("barcode_ean13", &ean13);
+
+    let ean8 = ReceiptBuilder::new(PrintWidth::Mm58)
+        .barcode(Symbology::Ean8, "9638507", 3, 80)
+        .unwrap()
+        .build();
+    assert_snapshot("barcode_ean8", &ean8);
+}
+
+#[test]
+fn qr_codes() {
+    let qr = ReceiptBuilder::new(PrintWidth::Mm80)
+        .qr_code("https://example.com/r/2024-001", 6)
+        .build();
+    assert_snapshot("qr_code", &qr);
+
+    let captioned = ReceiptBuilder::new(PrintWidth::Mm58)
+        .qr_with_caption("https://example.com/avis", 4, "Notez-nous")
+        .build();
+    assert_snapshot("qr_captioned", &captioned);
+}